@@ -1,15 +1,28 @@
 /// TODO: Add --no-bg flag to present command to allow users to disable background color
-use clap::{Parser, Subcommand};
-use lantern_core::validator::{validate_slides, validate_theme_file};
-use lantern_core::{parser::parse_slides_with_meta, term::Terminal as SlideTerminal, theme::ThemeRegistry};
+use clap::{Args, Parser, Subcommand};
+use lantern_core::validator::{ValidationResult, validate_slides, validate_theme_file, validate_theme_name};
+use lantern_core::{
+    html::export_html_with_css,
+    metadata::{EffectiveMeta, HeadingPrefixes, Meta, MetaOverrides},
+    parser::{parse_slides_with_meta, parse_slides_with_overrides},
+    printer::PrintOptions,
+    roff::export_roff,
+    slide::Slide,
+    term::{self, Terminal as SlideTerminal},
+    theme::{Theme, ThemeRegistry},
+};
 use lantern_ui::App;
 use owo_colors::OwoColorize;
-use ratatui::{Terminal, backend::CrosstermBackend};
+use ratatui::{
+    Terminal,
+    backend::{CrosstermBackend, TestBackend},
+};
 use std::{
     io,
     path::{Path, PathBuf},
 };
 use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
 
 /// A modern terminal-based presentation tool
 #[derive(Parser, Debug)]
@@ -24,6 +37,20 @@ struct ArgParser {
     command: Commands,
 }
 
+/// `present` and `print` flags that adjust the loaded theme
+#[derive(Args, Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ThemeFlags {
+    /// Fail on an unknown theme name or a custom admonition shadowing a built-in, instead of warning
+    #[arg(long)]
+    strict_theme: bool,
+    /// Lighten or darken low-contrast body text until it is readable (same as `ensure_contrast: true`)
+    #[arg(long)]
+    a11y: bool,
+    /// Draw admonition icons as ASCII (`[i]`, `[!]`, ...) for terminals without emoji (same as `ascii_icons: true`)
+    #[arg(long)]
+    ascii_icons: bool,
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Present slides in interactive TUI mode
@@ -33,6 +60,20 @@ enum Commands {
         /// Theme to use for presentation
         #[arg(short, long)]
         theme: Option<String>,
+        /// Wrap from the last slide to the first and back
+        #[arg(long = "loop")]
+        loop_slides: bool,
+        #[command(flatten)]
+        flags: ThemeFlags,
+        /// Render every slide once off-screen and exit, reporting slides that fail (for CI smoke tests)
+        #[arg(long)]
+        once: bool,
+        /// Write the current slide, next slide, timer and notes to this file on every slide change
+        #[arg(long, value_name = "PATH")]
+        speaker_file: Option<PathBuf>,
+        /// Draw in the normal screen buffer instead of the alternate screen, keeping slides in scrollback
+        #[arg(long)]
+        no_alt_screen: bool,
     },
 
     /// Print slides to stdout with formatting
@@ -42,9 +83,69 @@ enum Commands {
         /// Maximum width for output (in characters)
         #[arg(short, long, default_value = "80")]
         width: usize,
-        /// Theme to use for coloring
+        /// Theme to use for coloring; a comma-separated list prints the deck once per theme, for comparing them
         #[arg(short, long)]
         theme: Option<String>,
+        /// Prefix each slide with a `[#slug]` anchor derived from its title
+        #[arg(long)]
+        anchors: bool,
+        /// Wrap content to at most this many columns, centered within the output width
+        #[arg(long)]
+        max_width: Option<usize>,
+        /// Print code blocks without syntax highlighting
+        #[arg(long)]
+        no_highlight: bool,
+        /// Only print these slides, 1-based and inclusive: `5-8`, `5-`, `-8` or `5`
+        #[arg(long)]
+        range: Option<SlideRange>,
+        /// Print each slide's speaker notes beneath it, for handouts
+        #[arg(long)]
+        with_notes: bool,
+        /// Print only slide numbers, titles, and speaker notes, as a script to rehearse from
+        #[arg(long, conflicts_with = "with_notes")]
+        notes_only: bool,
+        #[command(flatten)]
+        flags: ThemeFlags,
+    },
+
+    /// Print a one-line-per-slide outline with titles and word counts
+    Outline {
+        /// Path to the markdown file
+        file: PathBuf,
+    },
+
+    /// Export slides to a standalone HTML page
+    Export {
+        /// Path to the markdown file
+        file: PathBuf,
+        /// File to write the export to; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Theme to use for colors
+        #[arg(short, long)]
+        theme: Option<String>,
+        /// Output format: a standalone HTML page, or roff source for a man page
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Html)]
+        format: ExportFormat,
+    },
+
+    /// Write every code block with a language to `slide-<n>-<lang>.<ext>` files
+    Tangle {
+        /// Path to the markdown file
+        file: PathBuf,
+        /// Directory to write the code files to
+        #[arg(short, long, default_value = ".")]
+        out_dir: PathBuf,
+    },
+
+    /// List the built-in themes, or preview one on a sample slide
+    Themes {
+        /// Print a sample slide covering every block kind in this theme
+        #[arg(long, value_name = "NAME")]
+        preview: Option<String>,
+        /// Maximum width for the preview (in characters)
+        #[arg(short, long, default_value = "80")]
+        width: usize,
     },
 
     /// Initialize a new slide deck with example content
@@ -58,6 +159,8 @@ enum Commands {
     },
 
     /// Check slides for errors and lint issues
+    ///
+    /// Exits with 0 when clean, 1 on errors, and 2 when warnings are denied.
     Check {
         /// Path to the markdown file
         file: PathBuf,
@@ -67,6 +170,15 @@ enum Commands {
         /// Validate file as a theme instead of slides
         #[arg(short, long)]
         theme: bool,
+        /// Exit with a non-zero code when there are warnings
+        #[arg(long)]
+        deny_warnings: bool,
+        /// Only print errors; the exit code is unchanged
+        #[arg(short, long)]
+        quiet: bool,
+        /// Warn about image files in the deck's asset directories that no slide references
+        #[arg(long)]
+        unused_assets: bool,
     },
 }
 
@@ -83,6 +195,7 @@ fn main() {
 
         tracing_subscriber::fmt()
             .with_max_level(cli.log_level)
+            .with_span_events(FmtSpan::CLOSE)
             .with_writer(std::sync::Mutex::new(log_file))
             .with_ansi(false)
             .init();
@@ -95,14 +208,52 @@ fn main() {
     }
 
     match cli.command {
-        Commands::Present { file, theme } => {
-            if let Err(e) = run_present(&file, theme) {
+        Commands::Present { file, theme, loop_slides, flags, once, speaker_file, no_alt_screen } => {
+            let options = PresentOptions { loop_slides, flags, once, speaker_file, no_alt_screen };
+            if let Err(e) = run_present(&file, theme, options) {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Print {
+            file,
+            width,
+            theme,
+            anchors,
+            max_width,
+            no_highlight,
+            range,
+            with_notes,
+            notes_only,
+            flags,
+        } => {
+            let options =
+                PrintOptions { anchors, content_max_width: max_width, no_highlight, notes: with_notes, notes_only };
+            if let Err(e) = run_print(&file, width, theme, options, range, flags) {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Outline { file } => {
+            if let Err(e) = run_outline(&file) {
                 eprintln!("Error: {e}");
                 std::process::exit(1);
             }
         }
-        Commands::Print { file, width, theme } => {
-            if let Err(e) = run_print(&file, width, theme) {
+        Commands::Export { file, output, theme, format } => {
+            if let Err(e) = run_export(&file, output.as_deref(), theme, format) {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Tangle { file, out_dir } => {
+            if let Err(e) = run_tangle(&file, &out_dir) {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Themes { preview, width } => {
+            if let Err(e) = run_themes(preview.as_deref(), width) {
                 eprintln!("Error: {e}");
                 std::process::exit(1);
             }
@@ -111,37 +262,107 @@ fn main() {
             tracing::info!("Initializing new deck: {} in {}", name, path.display());
             eprintln!("Init command not yet implemented");
         }
-        Commands::Check { file, strict, theme } => {
-            if let Err(e) = run_check(&file, strict, theme) {
-                eprintln!("Error: {e}");
-                std::process::exit(1);
+        Commands::Check { file, strict, theme, deny_warnings, quiet, unused_assets } => {
+            let options = CheckOptions { strict, deny_warnings, quiet, unused_assets };
+            match run_check(&file, theme, options) {
+                Ok(EXIT_CLEAN) => {}
+                Ok(code) => {
+                    eprintln!("Error: warnings are denied");
+                    std::process::exit(code);
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(EXIT_ERRORS);
+                }
             }
         }
     }
 }
 
-fn run_present(file: &PathBuf, theme_arg: Option<String>) -> io::Result<()> {
+/// Look up a theme by name, warning on stderr when the name is unknown and the registry falls back
+///
+/// With `strict`, an unknown name is an error instead of a warning.
+fn lookup_theme(name: &str, strict: bool) -> io::Result<Theme> {
+    match theme_fallback_warning(name) {
+        Some(warning) if strict => Err(io::Error::new(io::ErrorKind::InvalidInput, warning)),
+        Some(warning) => {
+            tracing::warn!("{}", warning);
+            eprintln!("{} {}", "Warning:".yellow().bold(), warning);
+            Ok(ThemeRegistry::get(name))
+        }
+        None => Ok(ThemeRegistry::get(name)),
+    }
+}
+
+/// Warning for a theme name [ThemeRegistry::get] doesn't know, or `None` when the name is valid
+fn theme_fallback_warning(name: &str) -> Option<String> {
+    let err = validate_theme_name(&name.to_lowercase()).err()?;
+    Some(format!("{err}; falling back to nord"))
+}
+
+/// Warn on stderr about custom admonitions that a built-in type shadows, leaving their config unused
+///
+/// With `strict`, a shadowed admonition is an error instead of a warning.
+fn check_custom_admonitions(meta: &Meta, strict: bool) -> io::Result<()> {
+    for warning in meta.shadowed_admonition_warnings() {
+        if strict {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, warning));
+        }
+        tracing::warn!("{}", warning);
+        eprintln!("{} {}", "Warning:".yellow().bold(), warning);
+    }
+    Ok(())
+}
+
+/// `present` flags beyond the file and theme
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct PresentOptions {
+    loop_slides: bool,
+    flags: ThemeFlags,
+    /// Render every slide off-screen once instead of presenting
+    once: bool,
+    speaker_file: Option<PathBuf>,
+    /// Keep the presentation in the normal screen buffer
+    no_alt_screen: bool,
+}
+
+fn run_present(file: &PathBuf, theme_arg: Option<String>, options: PresentOptions) -> io::Result<()> {
     tracing::info!("Presenting slides from: {}", file.display());
 
     let markdown = std::fs::read_to_string(file)
         .map_err(|e| io::Error::new(e.kind(), format!("Failed to read file {}: {}", file.display(), e)))?;
 
-    let (meta, slides) = parse_slides_with_meta(&markdown)
+    let overrides = MetaOverrides { theme: theme_arg };
+    let (mut meta, effective, slides) = parse_slides_with_overrides(&markdown, &overrides)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Parse error: {e}")))?;
 
     if slides.is_empty() {
         return Err(io::Error::new(io::ErrorKind::InvalidData, "No slides found in file"));
     }
+    check_custom_admonitions(&meta, options.flags.strict_theme)?;
+    meta.loop_slides |= options.loop_slides;
+    meta.ensure_contrast |= options.flags.a11y;
+    meta.ascii_icons |= options.flags.ascii_icons;
 
-    let theme_name = theme_arg.clone().unwrap_or_else(|| meta.theme.clone());
+    let theme_name = effective.theme;
     tracing::info!(
-        "Theme selection: CLI arg={:?}, frontmatter={}, final={}",
-        theme_arg,
+        "Theme selection: overrides={:?}, frontmatter={}, final={}",
+        effective.overrides,
         meta.theme,
         theme_name
     );
 
-    let theme = ThemeRegistry::get(&theme_name);
+    let mut theme = lookup_theme(&theme_name, options.flags.strict_theme)?
+        .with_highlighting(meta.highlight)
+        .with_heading_prefixes(meta.heading_prefixes.as_ref().and_then(HeadingPrefixes::resolve))
+        .with_blockquote_style(meta.blockquote_style)
+        .with_ascii_icons(meta.ascii_icons);
+    if let Some(code_theme) = &meta.code_theme {
+        theme = theme.with_code_theme(code_theme);
+    }
+    if meta.ensure_contrast {
+        theme = theme.with_ensured_contrast();
+    }
 
     let filename = file
         .file_name()
@@ -149,7 +370,12 @@ fn run_present(file: &PathBuf, theme_arg: Option<String>) -> io::Result<()> {
         .unwrap_or("unknown")
         .to_string();
 
-    let mut slide_terminal = SlideTerminal::setup()?;
+    if options.once {
+        return run_self_test(slides, theme, filename, meta);
+    }
+
+    term::restore_on_panic(term::restore_terminal);
+    let mut slide_terminal = SlideTerminal::setup_with(!options.no_alt_screen)?;
 
     let result = (|| -> io::Result<()> {
         let stdout = io::stdout();
@@ -158,7 +384,14 @@ fn run_present(file: &PathBuf, theme_arg: Option<String>) -> io::Result<()> {
 
         terminal.clear()?;
 
+        let show_modified = meta.show_modified;
         let mut app = App::new(slides, theme, filename, meta);
+        if show_modified {
+            app.set_source_path(file);
+        }
+        if let Some(path) = options.speaker_file {
+            app.set_speaker_file(path);
+        }
         app.run(&mut terminal)?;
 
         Ok(())
@@ -169,61 +402,196 @@ fn run_present(file: &PathBuf, theme_arg: Option<String>) -> io::Result<()> {
     result
 }
 
-fn run_check(file: &Path, strict: bool, is_theme: bool) -> io::Result<()> {
-    if is_theme {
-        tracing::info!("Validating theme file: {}", file.display());
-        let result = validate_theme_file(file);
+/// Columns and rows of the off-screen terminal `present --once` renders into
+const SELF_TEST_SIZE: (u16, u16) = (80, 24);
 
-        if result.is_valid() {
-            println!("{} Theme is valid", "✓".green().bold());
-        } else {
-            println!("{} Theme validation failed", "✗".red().bold());
-        }
+/// Render every slide once to an off-screen terminal, failing with the list of slides that didn't render
+fn run_self_test(slides: Vec<Slide>, theme: Theme, filename: String, meta: Meta) -> io::Result<()> {
+    let count = slides.len();
+    let mut terminal = Terminal::new(TestBackend::new(SELF_TEST_SIZE.0, SELF_TEST_SIZE.1))?;
+    let failures = App::new(slides, theme, filename, meta).render_all(&mut terminal);
 
-        for error in &result.errors {
-            println!("  {} {}", "Error:".red().bold(), error);
-        }
+    if failures.is_empty() {
+        println!("Rendered {count} slides");
+        return Ok(());
+    }
+
+    let mut failed: Vec<usize> = failures.iter().map(|failure| failure.slide).collect();
+    failed.dedup();
+    let report: Vec<String> = failures.iter().map(|failure| format!("  {failure}")).collect();
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "{} of {count} slides failed to render:\n{}",
+            failed.len(),
+            report.join("\n")
+        ),
+    ))
+}
+
+/// `check` exit code when there are no errors and warnings are allowed
+const EXIT_CLEAN: i32 = 0;
+/// `check` exit code when validation reported errors
+const EXIT_ERRORS: i32 = 1;
+/// `check` exit code when `--deny-warnings` is set and warnings were reported
+const EXIT_WARNINGS_DENIED: i32 = 2;
+
+/// Exit code for a validation result
+fn check_exit_code(result: &ValidationResult, deny_warnings: bool) -> i32 {
+    if !result.is_valid() {
+        EXIT_ERRORS
+    } else if deny_warnings && !result.warnings.is_empty() {
+        EXIT_WARNINGS_DENIED
+    } else {
+        EXIT_CLEAN
+    }
+}
+
+/// Status line followed by the errors and warnings of a validation result
+///
+/// With `quiet`, warnings are left out and a passing result produces no output at all.
+fn check_report(result: &ValidationResult, status: String, quiet: bool) -> String {
+    if quiet && result.is_valid() {
+        return String::new();
+    }
+
+    let mut report = format!("{status}\n");
+    for error in &result.errors {
+        report.push_str(&format!("  {} {}\n", "Error:".red().bold(), error));
+    }
 
+    if !quiet {
         for warning in &result.warnings {
-            println!("  {} {}", "Warning:".yellow().bold(), warning);
+            report.push_str(&format!("  {} {}\n", "Warning:".yellow().bold(), warning));
         }
+    }
+    report
+}
+
+/// `check` flags beyond the file and `--theme`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CheckOptions {
+    strict: bool,
+    deny_warnings: bool,
+    quiet: bool,
+    /// Warn about unreferenced images next to the deck
+    unused_assets: bool,
+}
+
+/// Validate a deck or theme file, printing the findings
+///
+/// Errors are returned as `Err`; otherwise the result is the [check_exit_code], which is only non-zero when warnings
+/// are denied.
+fn run_check(file: &Path, is_theme: bool, options: CheckOptions) -> io::Result<i32> {
+    let CheckOptions { strict, deny_warnings, quiet, unused_assets } = options;
+    if is_theme {
+        tracing::info!("Validating theme file: {}", file.display());
+        let result = validate_theme_file(file);
+
+        let status = if result.is_valid() {
+            format!("{} Theme is valid", "✓".green().bold())
+        } else {
+            format!("{} Theme validation failed", "✗".red().bold())
+        };
+        print!("{}", check_report(&result, status, quiet));
 
         if !result.is_valid() {
             return Err(io::Error::new(io::ErrorKind::InvalidData, "Theme validation failed"));
         }
+
+        Ok(check_exit_code(&result, deny_warnings))
     } else {
         tracing::info!("Validating slides: {}", file.display());
         if strict {
             tracing::debug!("Strict mode enabled");
         }
 
-        let result = validate_slides(file, strict);
+        let mut result = validate_slides(file, strict);
+        if unused_assets && result.is_valid() {
+            let unused = lantern_core::validator::unused_assets(file)
+                .map_err(|e| io::Error::other(format!("Failed to scan for unused assets: {e}")))?;
+            for asset in unused {
+                result.add_warning(format!("Unused asset: {}", asset.display()));
+            }
+        }
 
-        if result.is_valid() && !result.has_issues() {
-            println!("{} Slides are valid", "✓".green().bold());
+        let status = if result.is_valid() && !result.has_issues() {
+            format!("{} Slides are valid", "✓".green().bold())
         } else if result.is_valid() {
-            println!("{} Slides are valid (with warnings)", "✓".yellow().bold());
+            format!("{} Slides are valid (with warnings)", "✓".yellow().bold())
         } else {
-            println!("{} Slide validation failed", "✗".red().bold());
-        }
+            format!("{} Slide validation failed", "✗".red().bold())
+        };
+        print!("{}", check_report(&result, status, quiet));
 
-        for error in &result.errors {
-            println!("  {} {}", "Error:".red().bold(), error);
+        if !result.is_valid() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Slide validation failed"));
         }
 
-        for warning in &result.warnings {
-            println!("  {} {}", "Warning:".yellow().bold(), warning);
-        }
+        Ok(check_exit_code(&result, deny_warnings))
+    }
+}
 
-        if !result.is_valid() {
-            return Err(io::Error::new(io::ErrorKind::InvalidData, "Slide validation failed"));
+/// Inclusive 1-based slide range for `print --range`; a missing bound is open-ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SlideRange {
+    start: Option<usize>,
+    end: Option<usize>,
+}
+
+impl std::str::FromStr for SlideRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bound = |part: &str| -> Result<Option<usize>, String> {
+            let part = part.trim();
+            if part.is_empty() {
+                return Ok(None);
+            }
+            match part.parse::<usize>() {
+                Ok(0) => Err("slide numbers start at 1".to_string()),
+                Ok(n) => Ok(Some(n)),
+                Err(_) => Err(format!("invalid slide number '{part}'")),
+            }
+        };
+
+        let range = match s.split_once('-') {
+            Some((start, end)) => Self { start: bound(start)?, end: bound(end)? },
+            None => {
+                let n = bound(s)?;
+                Self { start: n, end: n }
+            }
+        };
+
+        match range {
+            Self { start: None, end: None } => Err(format!("empty slide range '{s}'")),
+            Self { start: Some(start), end: Some(end) } if start > end => {
+                Err(format!("range start {start} is after end {end}"))
+            }
+            range => Ok(range),
         }
     }
+}
 
-    Ok(())
+impl SlideRange {
+    /// Select the slides in range, clamping the end to the deck length
+    fn select<'a>(&self, slides: &'a [Slide]) -> io::Result<&'a [Slide]> {
+        let start = self.start.unwrap_or(1);
+        if start > slides.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Range starts at slide {start} but the deck has {} slides", slides.len()),
+            ));
+        }
+        let end = self.end.map_or(slides.len(), |end| end.min(slides.len()));
+        Ok(&slides[start - 1..end])
+    }
 }
 
-fn run_print(file: &PathBuf, width: usize, theme_arg: Option<String>) -> io::Result<()> {
+fn run_print(
+    file: &PathBuf, width: usize, theme_arg: Option<String>, options: PrintOptions, range: Option<SlideRange>,
+    flags: ThemeFlags,
+) -> io::Result<()> {
     tracing::info!("Printing slides from: {} (width: {})", file.display(), width);
 
     let markdown = std::fs::read_to_string(file)
@@ -235,28 +603,243 @@ fn run_print(file: &PathBuf, width: usize, theme_arg: Option<String>) -> io::Res
     if slides.is_empty() {
         return Err(io::Error::new(io::ErrorKind::InvalidData, "No slides found in file"));
     }
+    check_custom_admonitions(&meta, flags.strict_theme)?;
+
+    let mut themes = Vec::new();
+    for theme_arg in split_theme_list(theme_arg) {
+        let theme_name = EffectiveMeta::resolve(&meta, &MetaOverrides { theme: theme_arg }).theme;
+        tracing::debug!("Using theme: {}", theme_name);
+
+        let theme = print_theme(&meta, &theme_name, flags)?;
+        themes.push((theme_name, theme));
+    }
+
+    let slides = match range {
+        Some(range) => range.select(&slides)?,
+        None => &slides,
+    };
 
-    let theme_name = theme_arg.unwrap_or_else(|| meta.theme.clone());
-    tracing::debug!("Using theme: {}", theme_name);
+    let mut handle = io::BufWriter::new(io::stdout().lock());
+    print_theme_sections(&mut handle, slides, &themes, width, &options)?;
+    io::Write::flush(&mut handle)
+}
 
-    let theme = ThemeRegistry::get(&theme_name);
+/// Split a `--theme` value on commas into one override per theme; no value keeps the frontmatter theme
+fn split_theme_list(theme_arg: Option<String>) -> Vec<Option<String>> {
+    match theme_arg {
+        Some(list) if list.contains(',') => list
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| Some(name.to_string()))
+            .collect(),
+        theme_arg => vec![theme_arg],
+    }
+}
 
-    lantern_core::printer::print_slides_to_stdout(&slides, &theme, width)?;
+/// Load `theme_name` with the deck's highlighting, heading prefix, blockquote, icon, code theme and contrast settings
+fn print_theme(meta: &Meta, theme_name: &str, flags: ThemeFlags) -> io::Result<Theme> {
+    let mut theme = lookup_theme(theme_name, flags.strict_theme)?
+        .with_highlighting(meta.highlight)
+        .with_heading_prefixes(meta.heading_prefixes.as_ref().and_then(HeadingPrefixes::resolve))
+        .with_blockquote_style(meta.blockquote_style)
+        .with_ascii_icons(meta.ascii_icons || flags.ascii_icons);
+    if let Some(code_theme) = &meta.code_theme {
+        theme = theme.with_code_theme(code_theme);
+    }
+    if meta.ensure_contrast || flags.a11y {
+        theme = theme.with_ensured_contrast();
+    }
+    Ok(theme)
+}
+
+/// Print `slides` once per theme, each run headed by a labeled separator when comparing more than one theme
+fn print_theme_sections<W: io::Write>(
+    writer: &mut W, slides: &[Slide], themes: &[(String, Theme)], width: usize, options: &PrintOptions,
+) -> io::Result<()> {
+    for (name, theme) in themes {
+        if themes.len() > 1 {
+            writeln!(writer, "{:═^width$}", format!(" Theme: {name} "))?;
+            writeln!(writer)?;
+        }
+        lantern_core::printer::print_slides_with_options(writer, slides, theme, width, options)?;
+    }
 
     Ok(())
 }
 
+/// Print the built-in theme names, or a sample slide rendered in the theme named by `preview`
+fn run_themes(preview: Option<&str>, width: usize) -> io::Result<()> {
+    match preview {
+        Some(name) => print!("{}", format_theme_preview(name, width)?),
+        None => ThemeRegistry::available_themes()
+            .iter()
+            .for_each(|name| println!("{name}")),
+    }
+
+    Ok(())
+}
+
+/// The printer's theme preview for `name`, rejecting names the registry doesn't know
+fn format_theme_preview(name: &str, width: usize) -> io::Result<String> {
+    validate_theme_name(&name.to_lowercase())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+    Ok(lantern_core::printer::preview_theme(&ThemeRegistry::get(name), width))
+}
+
+fn run_outline(file: &Path) -> io::Result<()> {
+    tracing::info!("Outlining slides from: {}", file.display());
+
+    let markdown = std::fs::read_to_string(file)
+        .map_err(|e| io::Error::new(e.kind(), format!("Failed to read file {}: {}", file.display(), e)))?;
+
+    let (_, slides) = parse_slides_with_meta(&markdown)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Parse error: {e}")))?;
+
+    print!("{}", format_outline(&slides));
+
+    Ok(())
+}
+
+/// One line per slide: `N. <title> (W words)`, indented two spaces per heading level below 1
+fn format_outline(slides: &[Slide]) -> String {
+    let mut outline = String::new();
+
+    for (idx, slide) in slides.iter().enumerate() {
+        let indent = "  ".repeat(slide.title_level().unwrap_or(1).saturating_sub(1) as usize);
+        let title = slide.title().unwrap_or_else(|| "Untitled".to_string());
+        let words = slide.word_count();
+        let unit = if words == 1 { "word" } else { "words" };
+        outline.push_str(&format!("{indent}{}. {title} ({words} {unit})\n", idx + 1));
+    }
+
+    outline
+}
+
+/// Document format written by `export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ExportFormat {
+    Html,
+    Roff,
+}
+
+fn run_export(file: &Path, output: Option<&Path>, theme_arg: Option<String>, format: ExportFormat) -> io::Result<()> {
+    tracing::info!("Exporting slides from: {} to {:?}", file.display(), format);
+
+    let markdown = std::fs::read_to_string(file)
+        .map_err(|e| io::Error::new(e.kind(), format!("Failed to read file {}: {}", file.display(), e)))?;
+
+    let overrides = MetaOverrides { theme: theme_arg };
+    let (meta, effective, slides) = parse_slides_with_overrides(&markdown, &overrides)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Parse error: {e}")))?;
+
+    let exported = match format {
+        ExportFormat::Html => {
+            let css = meta
+                .custom_css(file.parent().unwrap_or(Path::new("")))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+            export_html_with_css(&slides, &ThemeRegistry::get(&effective.theme), &css)
+        }
+        ExportFormat::Roff => export_roff(&slides),
+    };
+    match output {
+        Some(path) => std::fs::write(path, exported),
+        None => {
+            print!("{exported}");
+            Ok(())
+        }
+    }
+}
+
+fn run_tangle(file: &Path, out_dir: &Path) -> io::Result<()> {
+    tracing::info!("Tangling code from: {} into {}", file.display(), out_dir.display());
+
+    let markdown = std::fs::read_to_string(file)
+        .map_err(|e| io::Error::new(e.kind(), format!("Failed to read file {}: {}", file.display(), e)))?;
+
+    let (_, slides) = parse_slides_with_meta(&markdown)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Parse error: {e}")))?;
+
+    for path in tangle(&slides, out_dir)? {
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Write each code block that has a language to `out_dir`, returning the written paths
+///
+/// Files are named `slide-<n>-<lang>.<ext>`, with `<lang>` from [tangle_language]; further blocks of the same
+/// language on a slide get a `-2`, `-3`, ... suffix. Blocks without a language are skipped, and a name that would
+/// land outside `out_dir` is refused.
+fn tangle(slides: &[Slide], out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+
+    for (idx, slide) in slides.iter().enumerate() {
+        let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+
+        for code in slide.code_blocks() {
+            let Some(language) = code
+                .language
+                .as_deref()
+                .map(tangle_language)
+                .filter(|lang| !lang.is_empty())
+            else {
+                continue;
+            };
+
+            let count = seen.entry(language.clone()).or_insert(0);
+            *count += 1;
+            let suffix = if *count == 1 { String::new() } else { format!("-{count}") };
+
+            let path = out_dir.join(format!(
+                "slide-{}-{language}{suffix}.{}",
+                idx + 1,
+                code.file_extension()
+            ));
+            if path.parent() != Some(out_dir) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Refusing to write {} outside {}", path.display(), out_dir.display()),
+                ));
+            }
+            std::fs::write(&path, &code.code)?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+/// First word of a fence's info string, lowercased and cut down to `[a-z0-9+#_-]` so it is safe in a file name
+fn tangle_language(info: &str) -> String {
+    info.split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+        .chars()
+        .filter(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || "+#_-".contains(*ch))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lantern_core::slide::{Block, TextSpan};
 
     #[test]
     fn cli_present_command() {
         let cli = ArgParser::parse_from(["slides", "present", "test.md"]);
         match cli.command {
-            Commands::Present { file, theme } => {
+            Commands::Present { file, theme, loop_slides, flags, once, speaker_file, no_alt_screen } => {
                 assert_eq!(file, PathBuf::from("test.md"));
                 assert_eq!(theme, None);
+                assert!(!loop_slides);
+                assert_eq!(flags, ThemeFlags::default());
+                assert!(!once);
+                assert_eq!(speaker_file, None);
+                assert!(!no_alt_screen);
             }
             _ => panic!("Expected Present command"),
         }
@@ -266,7 +849,7 @@ mod tests {
     fn cli_present_with_theme() {
         let cli = ArgParser::parse_from(["slides", "present", "test.md", "--theme", "dark"]);
         match cli.command {
-            Commands::Present { file, theme } => {
+            Commands::Present { file, theme, .. } => {
                 assert_eq!(file, PathBuf::from("test.md"));
                 assert_eq!(theme, Some("dark".to_string()));
             }
@@ -274,19 +857,172 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_present_with_loop() {
+        let cli = ArgParser::parse_from(["slides", "present", "test.md", "--loop"]);
+        match cli.command {
+            Commands::Present { loop_slides, .. } => assert!(loop_slides),
+            _ => panic!("Expected Present command"),
+        }
+    }
+
+    #[test]
+    fn cli_present_without_alt_screen() {
+        let cli = ArgParser::parse_from(["slides", "present", "test.md", "--no-alt-screen"]);
+        match cli.command {
+            Commands::Present { no_alt_screen, .. } => assert!(no_alt_screen),
+            _ => panic!("Expected Present command"),
+        }
+    }
+
+    #[test]
+    fn cli_present_with_speaker_file() {
+        let cli = ArgParser::parse_from(["slides", "present", "test.md", "--speaker-file", "/tmp/speaker.txt"]);
+        match cli.command {
+            Commands::Present { speaker_file, .. } => assert_eq!(speaker_file, Some(PathBuf::from("/tmp/speaker.txt"))),
+            _ => panic!("Expected Present command"),
+        }
+    }
+
     #[test]
     fn cli_print_command() {
         let cli = ArgParser::parse_from(["slides", "print", "test.md", "-w", "100"]);
         match cli.command {
-            Commands::Print { file, width, theme } => {
+            Commands::Print {
+                file,
+                width,
+                theme,
+                anchors,
+                max_width,
+                no_highlight,
+                range,
+                with_notes,
+                notes_only,
+                flags,
+            } => {
                 assert_eq!(file, PathBuf::from("test.md"));
                 assert_eq!(width, 100);
                 assert_eq!(theme, None);
+                assert!(!anchors);
+                assert_eq!(max_width, None);
+                assert!(!no_highlight);
+                assert_eq!(range, None);
+                assert!(!with_notes);
+                assert!(!notes_only);
+                assert_eq!(flags, ThemeFlags::default());
             }
             _ => panic!("Expected Print command"),
         }
     }
 
+    #[test]
+    fn cli_theme_flags_shared_by_present_and_print() {
+        let expected = ThemeFlags { strict_theme: true, a11y: true, ascii_icons: true };
+        let args = ["--strict-theme", "--a11y", "--ascii-icons"];
+
+        let cli = ArgParser::parse_from(["slides", "present", "test.md"].into_iter().chain(args));
+        match cli.command {
+            Commands::Present { flags, .. } => assert_eq!(flags, expected),
+            _ => panic!("Expected Present command"),
+        }
+
+        let cli = ArgParser::parse_from(["slides", "print", "test.md"].into_iter().chain(args));
+        match cli.command {
+            Commands::Print { flags, .. } => assert_eq!(flags, expected),
+            _ => panic!("Expected Print command"),
+        }
+    }
+
+    #[test]
+    fn cli_print_with_notes() {
+        let cli = ArgParser::parse_from(["slides", "print", "test.md", "--with-notes"]);
+        match cli.command {
+            Commands::Print { with_notes, .. } => assert!(with_notes),
+            _ => panic!("Expected Print command"),
+        }
+    }
+
+    #[test]
+    fn cli_print_notes_only_conflicts_with_with_notes() {
+        let cli = ArgParser::parse_from(["slides", "print", "test.md", "--notes-only"]);
+        match cli.command {
+            Commands::Print { notes_only, with_notes, .. } => assert!(notes_only && !with_notes),
+            _ => panic!("Expected Print command"),
+        }
+        assert!(ArgParser::try_parse_from(["slides", "print", "test.md", "--notes-only", "--with-notes"]).is_err());
+    }
+
+    #[test]
+    fn unknown_theme_warns_and_falls_back() {
+        let warning = theme_fallback_warning("nrod").expect("unknown theme should warn");
+        assert!(warning.contains("Theme 'nrod' not found"));
+        assert!(warning.contains("falling back to nord"));
+
+        assert_eq!(theme_fallback_warning("nord"), None);
+        assert_eq!(theme_fallback_warning("Solarized-Dark"), None);
+        assert_eq!(theme_fallback_warning("auto"), None);
+
+        assert_eq!(lookup_theme("nrod", false).unwrap(), ThemeRegistry::get("nord"));
+        assert_eq!(
+            lookup_theme("nrod", true).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn shadowed_custom_admonition_warns_or_fails_strict() {
+        let (meta, _) =
+            Meta::extract_from_markdown("---\nadmonitions:\n  note:\n    icon: N\n    color: \"#ffffff\"\n---\n# Hi")
+                .unwrap();
+        assert!(check_custom_admonitions(&meta, false).is_ok());
+        assert_eq!(
+            check_custom_admonitions(&meta, true).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert!(check_custom_admonitions(&Meta::default(), true).is_ok());
+    }
+
+    #[test]
+    fn cli_print_with_range() {
+        let cli = ArgParser::parse_from(["slides", "print", "test.md", "--range", "5-8"]);
+        match cli.command {
+            Commands::Print { range, .. } => assert_eq!(range, Some(SlideRange { start: Some(5), end: Some(8) })),
+            _ => panic!("Expected Print command"),
+        }
+        assert!(ArgParser::try_parse_from(["slides", "print", "test.md", "--range", "8-5"]).is_err());
+    }
+
+    #[test]
+    fn slide_range_parses_closed_open_and_single_forms() {
+        assert_eq!("5-8".parse(), Ok(SlideRange { start: Some(5), end: Some(8) }));
+        assert_eq!("5-".parse(), Ok(SlideRange { start: Some(5), end: None }));
+        assert_eq!("-8".parse(), Ok(SlideRange { start: None, end: Some(8) }));
+        assert_eq!("3".parse(), Ok(SlideRange { start: Some(3), end: Some(3) }));
+    }
+
+    #[test]
+    fn slide_range_rejects_invalid_forms() {
+        for invalid in ["", "-", "0-3", "a-3", "3-b", "8-5", "1-2-3"] {
+            assert!(invalid.parse::<SlideRange>().is_err(), "{invalid:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn slide_range_selects_only_listed_slides() {
+        let heading = |n: usize| Block::Heading { level: 1, spans: vec![TextSpan::plain(format!("S{n}"))] };
+        let slides: Vec<Slide> = (1..=10).map(|n| Slide::with_blocks(vec![heading(n)])).collect();
+        let titles = |range: &str| -> Vec<String> {
+            let range: SlideRange = range.parse().unwrap();
+            range.select(&slides).unwrap().iter().filter_map(Slide::title).collect()
+        };
+
+        assert_eq!(titles("5-8"), ["S5", "S6", "S7", "S8"]);
+        assert_eq!(titles("9-"), ["S9", "S10"]);
+        assert_eq!(titles("-2"), ["S1", "S2"]);
+        assert_eq!(titles("7-20"), ["S7", "S8", "S9", "S10"]);
+        assert!("11-12".parse::<SlideRange>().unwrap().select(&slides).is_err());
+    }
+
     #[test]
     fn cli_init_command() {
         let cli = ArgParser::parse_from(["slides", "init", "--name", "my-deck.md"]);
@@ -303,7 +1039,7 @@ mod tests {
     fn cli_check_command() {
         let cli = ArgParser::parse_from(["slides", "check", "test.md", "--strict"]);
         match cli.command {
-            Commands::Check { file, strict, theme } => {
+            Commands::Check { file, strict, theme, .. } => {
                 assert_eq!(file, PathBuf::from("test.md"));
                 assert!(strict);
                 assert!(!theme);
@@ -316,7 +1052,7 @@ mod tests {
     fn cli_check_theme_command() {
         let cli = ArgParser::parse_from(["slides", "check", "theme.yml", "--theme"]);
         match cli.command {
-            Commands::Check { file, strict, theme } => {
+            Commands::Check { file, strict, theme, .. } => {
                 assert_eq!(file, PathBuf::from("theme.yml"));
                 assert!(!strict);
                 assert!(theme);
@@ -333,12 +1069,106 @@ mod tests {
         let content = "# Test Slide\n\nThis is a test paragraph.\n\n---\n\n# Second Slide\n\n- Item 1\n- Item 2";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_print(&test_file, 80, None);
+        let result = run_print(
+            &test_file,
+            80,
+            None,
+            PrintOptions::default(),
+            None,
+            ThemeFlags::default(),
+        );
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
     }
 
+    #[test]
+    fn print_theme_takes_ascii_icons_from_flag_or_frontmatter() {
+        let meta = Meta::default();
+        assert!(
+            !print_theme(&meta, "nord", ThemeFlags::default())
+                .unwrap()
+                .settings
+                .ascii_icons
+        );
+
+        let flags = ThemeFlags { ascii_icons: true, ..Default::default() };
+        assert!(print_theme(&meta, "nord", flags).unwrap().settings.ascii_icons);
+
+        let meta = Meta { ascii_icons: true, ..Meta::default() };
+        assert!(
+            print_theme(&meta, "nord", ThemeFlags::default())
+                .unwrap()
+                .settings
+                .ascii_icons
+        );
+    }
+
+    #[test]
+    fn print_theme_sections_labels_each_theme() {
+        let slides = parse_slides_with_meta("# Hello\n\nWorld").unwrap().1;
+        let themes: Vec<_> = split_theme_list(Some("nord, dracula".to_string()))
+            .into_iter()
+            .flatten()
+            .map(|name| {
+                let theme = ThemeRegistry::get(&name);
+                (name, theme)
+            })
+            .collect();
+
+        let mut output = Vec::new();
+        print_theme_sections(&mut output, &slides, &themes, 40, &PrintOptions::default()).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let nord = output.find(" Theme: nord ").expect("nord section");
+        let dracula = output.find(" Theme: dracula ").expect("dracula section");
+        assert!(nord < dracula);
+        assert_eq!(output.matches("Hello").count(), 2);
+        assert!(output[nord..dracula].contains("Hello"));
+        assert!(output[dracula..].contains("Hello"));
+    }
+
+    #[test]
+    fn print_theme_sections_omits_label_for_single_theme() {
+        let slides = parse_slides_with_meta("# Hello").unwrap().1;
+        assert_eq!(split_theme_list(Some("nord".to_string())), [Some("nord".to_string())]);
+        assert_eq!(split_theme_list(None), [None]);
+
+        let mut output = Vec::new();
+        let themes = [("nord".to_string(), ThemeRegistry::get("nord"))];
+        print_theme_sections(&mut output, &slides, &themes, 40, &PrintOptions::default()).unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("Theme: nord"));
+    }
+
+    #[test]
+    fn run_present_once_renders_every_slide() {
+        let test_file = std::env::temp_dir().join("test_once_slides.md");
+        let content = concat!(
+            "# Intro\n\nHello\n\n---\n\n",
+            "# Table\n\n| A | B |\n|---|---|\n| 1 | 2 |\n\n---\n\n",
+            "```rust\nfn main() {}\n```"
+        );
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = run_present(&test_file, None, PresentOptions { once: true, ..Default::default() });
+        std::fs::remove_file(&test_file).ok();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn run_present_once_reports_failing_slide() {
+        let test_file = std::env::temp_dir().join("test_once_broken_slides.md");
+        let content = "# Fine\n\n---\n\n# Broken\n\n![Logo](/nonexistent/logo.png)";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = run_present(&test_file, None, PresentOptions { once: true, ..Default::default() });
+        std::fs::remove_file(&test_file).ok();
+
+        let message = result.expect_err("missing image should fail the self-test").to_string();
+        assert!(message.starts_with("1 of 2 slides failed to render"));
+        assert!(message.contains("slide 2: /nonexistent/logo.png"));
+    }
+
     #[test]
     fn run_print_empty_file() {
         let temp_dir = std::env::temp_dir();
@@ -346,7 +1176,14 @@ mod tests {
 
         std::fs::write(&test_file, "").expect("Failed to write test file");
 
-        let result = run_print(&test_file, 80, None);
+        let result = run_print(
+            &test_file,
+            80,
+            None,
+            PrintOptions::default(),
+            None,
+            ThemeFlags::default(),
+        );
         assert!(result.is_err());
 
         std::fs::remove_file(&test_file).ok();
@@ -355,7 +1192,14 @@ mod tests {
     #[test]
     fn run_print_nonexistent_file() {
         let test_file = PathBuf::from("/nonexistent/file.md");
-        let result = run_print(&test_file, 80, None);
+        let result = run_print(
+            &test_file,
+            80,
+            None,
+            PrintOptions::default(),
+            None,
+            ThemeFlags::default(),
+        );
         assert!(result.is_err());
     }
 
@@ -367,7 +1211,14 @@ mod tests {
         let content = "---\ntheme: dark\n---\n# Test Slide\n\nThis is a test paragraph.";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_print(&test_file, 80, None);
+        let result = run_print(
+            &test_file,
+            80,
+            None,
+            PrintOptions::default(),
+            None,
+            ThemeFlags::default(),
+        );
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
@@ -381,7 +1232,15 @@ mod tests {
         let content = "---\ntheme: light\n---\n# Test Slide\n\nThis is a test paragraph.";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_print(&test_file, 80, Some("monokai".to_string()));
+        let theme = Some("monokai".to_string());
+        let result = run_print(
+            &test_file,
+            80,
+            theme,
+            PrintOptions::default(),
+            None,
+            ThemeFlags::default(),
+        );
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
@@ -394,7 +1253,7 @@ mod tests {
         let content = "# Test Slide\n\nThis is a test paragraph.";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, false, false);
+        let result = run_check(&test_file, false, CheckOptions::default());
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
@@ -407,7 +1266,7 @@ mod tests {
         let content = "";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, false, false);
+        let result = run_check(&test_file, false, CheckOptions::default());
         assert!(result.is_err());
 
         std::fs::remove_file(&test_file).ok();
@@ -416,7 +1275,7 @@ mod tests {
     #[test]
     fn run_check_nonexistent_file() {
         let test_file = PathBuf::from("/nonexistent/test_check.md");
-        let result = run_check(&test_file, false, false);
+        let result = run_check(&test_file, false, CheckOptions::default());
         assert!(result.is_err());
     }
 
@@ -427,12 +1286,105 @@ mod tests {
         let content = "---\ntheme: nonexistent-theme\n---\n# Slide 1\n\nContent";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, true, false);
+        let result = run_check(
+            &test_file,
+            false,
+            CheckOptions { strict: true, ..CheckOptions::default() },
+        );
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
     }
 
+    #[test]
+    fn run_check_deny_warnings() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_check_deny_warnings.md");
+        let content = "---\ntheme: nonexistent-theme\n---\n# Slide 1\n\nContent";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let strict = CheckOptions { strict: true, ..CheckOptions::default() };
+        assert_eq!(run_check(&test_file, false, strict).unwrap(), EXIT_CLEAN);
+        let deny = CheckOptions { deny_warnings: true, ..strict };
+        assert_eq!(run_check(&test_file, false, deny).unwrap(), EXIT_WARNINGS_DENIED);
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn run_check_unused_assets_warns() {
+        let deck_dir = std::env::temp_dir().join(format!("lantern_check_unused_assets_{}", std::process::id()));
+        std::fs::create_dir_all(&deck_dir).unwrap();
+        std::fs::write(deck_dir.join("used.png"), b"png").unwrap();
+        let deck = deck_dir.join("deck.md");
+        std::fs::write(&deck, "# Slide\n\n![Used](used.png)").unwrap();
+
+        let options = CheckOptions { deny_warnings: true, unused_assets: true, ..CheckOptions::default() };
+        assert_eq!(run_check(&deck, false, options).unwrap(), EXIT_CLEAN);
+        std::fs::write(deck_dir.join("orphan.png"), b"png").unwrap();
+        assert_eq!(run_check(&deck, false, options).unwrap(), EXIT_WARNINGS_DENIED);
+
+        std::fs::remove_dir_all(&deck_dir).ok();
+    }
+
+    #[test]
+    fn check_exit_code_paths() {
+        let mut result = ValidationResult::new();
+        assert_eq!(check_exit_code(&result, true), EXIT_CLEAN);
+
+        result.add_warning("unknown theme".to_string());
+        assert_eq!(check_exit_code(&result, false), EXIT_CLEAN);
+        assert_eq!(check_exit_code(&result, true), EXIT_WARNINGS_DENIED);
+
+        result.add_error("no slides".to_string());
+        assert_eq!(check_exit_code(&result, false), EXIT_ERRORS);
+        assert_eq!(check_exit_code(&result, true), EXIT_ERRORS);
+    }
+
+    #[test]
+    fn check_report_quiet_prints_only_errors() {
+        let mut result = ValidationResult::new();
+        assert_eq!(check_report(&result, "valid".to_string(), true), "");
+        assert_eq!(check_report(&result, "valid".to_string(), false), "valid\n");
+
+        result.add_warning("unknown theme".to_string());
+        assert_eq!(check_report(&result, "valid".to_string(), true), "");
+        assert!(check_report(&result, "valid".to_string(), false).contains("unknown theme"));
+
+        result.add_error("no slides".to_string());
+        let report = check_report(&result, "failed".to_string(), true);
+        assert!(report.starts_with("failed\n"));
+        assert!(report.contains("no slides"));
+        assert!(!report.contains("unknown theme"));
+    }
+
+    #[test]
+    fn cli_check_quiet_flag() {
+        let cli = ArgParser::parse_from(["slides", "check", "test.md", "--quiet"]);
+        match cli.command {
+            Commands::Check { quiet, .. } => assert!(quiet),
+            _ => panic!("Expected Check command"),
+        }
+    }
+
+    #[test]
+    fn cli_check_unused_assets_flag() {
+        let cli = ArgParser::parse_from(["slides", "check", "test.md", "--unused-assets"]);
+        match cli.command {
+            Commands::Check { unused_assets, .. } => assert!(unused_assets),
+            _ => panic!("Expected Check command"),
+        }
+    }
+
+    #[test]
+    fn cli_check_deny_warnings_flag() {
+        let cli = ArgParser::parse_from(["slides", "check", "test.md", "--deny-warnings"]);
+        match cli.command {
+            Commands::Check { deny_warnings, .. } => assert!(deny_warnings),
+            _ => panic!("Expected Check command"),
+        }
+    }
+
     #[test]
     fn run_check_valid_theme() {
         let temp_dir = std::env::temp_dir();
@@ -462,7 +1414,7 @@ palette:
 "###;
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, false, true);
+        let result = run_check(&test_file, true, CheckOptions::default());
         assert!(result.is_ok());
 
         std::fs::remove_file(&test_file).ok();
@@ -475,7 +1427,7 @@ palette:
         let content = "invalid: yaml: content: [unclosed";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, false, true);
+        let result = run_check(&test_file, true, CheckOptions::default());
         assert!(result.is_err());
 
         std::fs::remove_file(&test_file).ok();
@@ -488,9 +1440,179 @@ palette:
         let content = "---\ninvalid yaml: [unclosed\n---\n# Slide";
         std::fs::write(&test_file, content).expect("Failed to write test file");
 
-        let result = run_check(&test_file, false, false);
+        let result = run_check(&test_file, false, CheckOptions::default());
         assert!(result.is_err());
 
         std::fs::remove_file(&test_file).ok();
     }
+
+    #[test]
+    fn cli_outline_command() {
+        let cli = ArgParser::parse_from(["slides", "outline", "test.md"]);
+        match cli.command {
+            Commands::Outline { file } => assert_eq!(file, PathBuf::from("test.md")),
+            _ => panic!("Expected Outline command"),
+        }
+    }
+
+    #[test]
+    fn cli_themes_preview_command() {
+        let cli = ArgParser::parse_from(["slides", "themes", "--preview", "nord"]);
+        match cli.command {
+            Commands::Themes { preview, width } => {
+                assert_eq!(preview.as_deref(), Some("nord"));
+                assert_eq!(width, 80);
+            }
+            _ => panic!("Expected Themes command"),
+        }
+    }
+
+    #[test]
+    fn format_theme_preview_rejects_unknown_theme() {
+        assert!(format_theme_preview("nord", 60).unwrap().contains("Theme Preview"));
+        let err = format_theme_preview("bogus", 60).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn run_export_writes_html_file() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_export.md");
+        let output = temp_dir.join("test_export.html");
+        std::fs::write(&test_file, "# Title\n\n![Diagram](diagram.png)").expect("Failed to write test file");
+
+        run_export(&test_file, Some(&output), Some("nord".to_string()), ExportFormat::Html).unwrap();
+        let html = std::fs::read_to_string(&output).unwrap();
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("alt=\"Diagram\""));
+
+        std::fs::remove_file(&test_file).ok();
+        std::fs::remove_file(&output).ok();
+    }
+
+    #[test]
+    fn run_export_appends_frontmatter_css() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_export_css.md");
+        let output = temp_dir.join("test_export_css.html");
+        std::fs::write(&test_file, "---\ncss: \"h1 { color: hotpink; }\"\n---\n# Title").unwrap();
+
+        run_export(&test_file, Some(&output), None, ExportFormat::Html).unwrap();
+        assert!(
+            std::fs::read_to_string(&output)
+                .unwrap()
+                .contains("h1 { color: hotpink; }\n</style>")
+        );
+
+        std::fs::write(&test_file, "---\ncss_file: no-such-style.css\n---\n# Title").unwrap();
+        let err = run_export(&test_file, Some(&output), None, ExportFormat::Html).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("no-such-style.css"));
+
+        std::fs::remove_file(&test_file).ok();
+        std::fs::remove_file(&output).ok();
+    }
+
+    #[test]
+    fn run_export_writes_roff_with_format_flag() {
+        let cli = ArgParser::parse_from(["slides", "export", "test.md", "--format", "roff"]);
+        match cli.command {
+            Commands::Export { format, .. } => assert_eq!(format, ExportFormat::Roff),
+            _ => panic!("Expected Export command"),
+        }
+
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_export_roff.md");
+        let output = temp_dir.join("test_export_roff.7");
+        std::fs::write(
+            &test_file,
+            "# Usage\n\n- one\n- two\n\n```sh\nlantern present deck.md\n```",
+        )
+        .unwrap();
+
+        run_export(&test_file, Some(&output), None, ExportFormat::Roff).unwrap();
+        let roff = std::fs::read_to_string(&output).unwrap();
+        assert!(roff.contains(".SH \"Usage\"\n"));
+        assert_eq!(roff.matches(".IP ").count(), 2);
+        assert!(roff.contains(".nf\nlantern present deck.md\n.fi\n"));
+
+        std::fs::remove_file(&test_file).ok();
+        std::fs::remove_file(&output).ok();
+    }
+
+    #[test]
+    fn cli_tangle_command() {
+        let cli = ArgParser::parse_from(["slides", "tangle", "test.md", "--out-dir", "code"]);
+        match cli.command {
+            Commands::Tangle { file, out_dir } => {
+                assert_eq!(file, PathBuf::from("test.md"));
+                assert_eq!(out_dir, PathBuf::from("code"));
+            }
+            _ => panic!("Expected Tangle command"),
+        }
+    }
+
+    #[test]
+    fn tangle_writes_code_blocks_to_files() {
+        let markdown = concat!(
+            "# One\n\n```rust\nfn main() {}\n```\n\n```\nno language\n```\n\n```rust\nfn two() {}\n```\n\n",
+            "---\n\n# Two\n\n```Python\nprint(1)\n```"
+        );
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+        let out_dir = std::env::temp_dir().join(format!("lantern-tangle-{}", std::process::id()));
+
+        let written = tangle(&slides, &out_dir).unwrap();
+        let names: Vec<String> = written
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["slide-1-rust.rs", "slide-1-rust-2.rs", "slide-2-python.py"]);
+
+        assert_eq!(std::fs::read_to_string(&written[0]).unwrap(), "fn main() {}\n");
+        assert_eq!(std::fs::read_to_string(&written[1]).unwrap(), "fn two() {}\n");
+        assert_eq!(std::fs::read_to_string(&written[2]).unwrap(), "print(1)\n");
+        assert_eq!(std::fs::read_dir(&out_dir).unwrap().count(), 3);
+
+        std::fs::remove_dir_all(&out_dir).ok();
+    }
+
+    #[test]
+    fn tangle_names_files_from_the_first_word_of_the_info_string() {
+        let markdown = "# One\n\n```rust title=main.rs\nfn main() {}\n```\n\n```C++ {.numberLines}\nint x;\n```";
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+        let out_dir = std::env::temp_dir().join(format!("lantern-tangle-info-{}", std::process::id()));
+
+        let written = tangle(&slides, &out_dir).unwrap();
+        std::fs::remove_dir_all(&out_dir).ok();
+
+        assert_eq!(
+            written,
+            vec![out_dir.join("slide-1-rust.rs"), out_dir.join("slide-1-c++.cpp")]
+        );
+    }
+
+    #[test]
+    fn tangle_keeps_traversal_attempts_inside_out_dir() {
+        let markdown = "# Evil\n\n```x/../../../../tmp/evil\npwned\n```\n\n```../..\nnothing left\n```";
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+        let out_dir = std::env::temp_dir().join(format!("lantern-tangle-evil-{}", std::process::id()));
+
+        let written = tangle(&slides, &out_dir).unwrap();
+        std::fs::remove_dir_all(&out_dir).ok();
+
+        assert_eq!(written, vec![out_dir.join("slide-1-xtmpevil.txt")]);
+        assert_eq!(tangle_language("x/../../../../tmp/evil"), "xtmpevil");
+        assert_eq!(tangle_language("Rust title=main.rs"), "rust");
+    }
+
+    #[test]
+    fn format_outline_lists_titles_and_word_counts() {
+        let markdown = "# Intro\n\nHello there world\n\n---\n\n## Details\n\nOne\n\n---\n\nNo heading here";
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+
+        assert_eq!(
+            format_outline(&slides),
+            "1. Intro (4 words)\n  2. Details (2 words)\n3. Untitled (3 words)\n"
+        );
+    }
 }
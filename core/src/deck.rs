@@ -0,0 +1,203 @@
+use crate::error::Result;
+use crate::metadata::Meta;
+use crate::parser::{SourceSpan, parse_slides_with_meta, parse_slides_with_spans};
+use crate::slide::Slide;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// A parsed presentation: its frontmatter metadata and slides
+///
+/// Wraps [parse_slides_with_meta] for integrators who would rather not pass `(Meta, Vec<Slide>)` tuples around.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Deck {
+    pub meta: Meta,
+    pub slides: Vec<Slide>,
+}
+
+/// Summary counts for a [Deck]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeckStats {
+    /// Number of slides
+    pub slides: usize,
+    /// Prose words across all slides (see [Slide::word_count])
+    pub words: usize,
+    /// Code blocks across all slides, including nested ones
+    pub code_blocks: usize,
+    /// Slides that carry speaker notes
+    pub slides_with_notes: usize,
+    /// Sum of the `<!-- time: ... -->` targets of the slides that set one
+    pub target_duration: Duration,
+}
+
+impl Deck {
+    pub fn new(meta: Meta, slides: Vec<Slide>) -> Self {
+        Self { meta, slides }
+    }
+
+    /// Parse frontmatter and slides from markdown
+    pub fn from_markdown(markdown: &str) -> Result<Self> {
+        let (meta, slides) = parse_slides_with_meta(markdown)?;
+        Ok(Self { meta, slides })
+    }
+
+    /// Parse like [Deck::from_markdown], also returning the source lines of each slide, in slide order
+    pub fn from_markdown_with_spans(markdown: &str) -> Result<(Self, Vec<SourceSpan>)> {
+        let (meta, slides) = parse_slides_with_spans(markdown)?;
+        let (slides, spans) = slides.into_iter().unzip();
+        Ok((Self { meta, slides }, spans))
+    }
+
+    /// Read and parse a markdown file
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_markdown(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slides.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slides.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Slide> {
+        self.slides.iter()
+    }
+
+    /// Title of the first slide with a heading
+    pub fn title(&self) -> Option<String> {
+        self.slides.iter().find_map(Slide::title)
+    }
+
+    pub fn stats(&self) -> DeckStats {
+        self.slides.iter().fold(DeckStats::default(), |mut stats, slide| {
+            stats.slides += 1;
+            stats.words += slide.word_count();
+            stats.code_blocks += slide.code_blocks().len();
+            stats.slides_with_notes += usize::from(slide.notes.is_some());
+            stats.target_duration += slide.target_duration.unwrap_or_default();
+            stats
+        })
+    }
+
+    /// Number of code blocks per language tag, including nested blocks
+    ///
+    /// Blocks without a language are counted under the empty string.
+    pub fn languages(&self) -> HashMap<String, usize> {
+        let mut languages = HashMap::new();
+        for code in self.slides.iter().flat_map(Slide::code_blocks) {
+            *languages.entry(code.language.clone().unwrap_or_default()).or_insert(0) += 1;
+        }
+        languages
+    }
+
+    /// Split back into the `(Meta, Vec<Slide>)` pair the parser functions use
+    pub fn into_parts(self) -> (Meta, Vec<Slide>) {
+        (self.meta, self.slides)
+    }
+}
+
+impl From<(Meta, Vec<Slide>)> for Deck {
+    fn from((meta, slides): (Meta, Vec<Slide>)) -> Self {
+        Self { meta, slides }
+    }
+}
+
+impl<'a> IntoIterator for &'a Deck {
+    type Item = &'a Slide;
+    type IntoIter = std::slice::Iter<'a, Slide>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Deck {
+    type Item = Slide;
+    type IntoIter = std::vec::IntoIter<Slide>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slides.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECK: &str = "---\nauthor: Ada\npaging: \"%d\"\n---\n\n# Intro\n\nHello there world\n\n---\n\n\
+        ## Code\n\n```rust\nfn main() {}\n```\n\n::: notes\nSay hi\n:::\n\n<!-- time: 90s -->\n";
+
+    #[test]
+    fn deck_from_markdown_parses_meta_and_slides() {
+        let deck = Deck::from_markdown(DECK).unwrap();
+
+        assert_eq!(deck.len(), 2);
+        assert!(!deck.is_empty());
+        assert_eq!(deck.meta.author, vec!["Ada".to_string()]);
+        assert_eq!(deck.meta.paging, "%d");
+        assert_eq!(deck.title().as_deref(), Some("Intro"));
+        assert_eq!(
+            deck.iter().filter_map(Slide::title).collect::<Vec<_>>(),
+            ["Intro", "Code"]
+        );
+        assert_eq!(Deck::from(parse_slides_with_meta(DECK).unwrap()), deck);
+    }
+
+    #[test]
+    fn deck_from_markdown_with_spans_reports_source_lines() {
+        let (deck, spans) = Deck::from_markdown_with_spans(DECK).unwrap();
+
+        assert_eq!(deck, Deck::from_markdown(DECK).unwrap());
+        assert_eq!(
+            spans,
+            [
+                SourceSpan { start_line: 6, end_line: 8 },
+                SourceSpan { start_line: 12, end_line: 22 }
+            ]
+        );
+
+        let lines: Vec<&str> = DECK.lines().collect();
+        assert_eq!(lines[spans[1].start_line - 1], "## Code");
+        assert_eq!(lines[spans[1].end_line - 1], "<!-- time: 90s -->");
+    }
+
+    #[test]
+    fn deck_stats_summarize_slides() {
+        let stats = Deck::from_markdown(DECK).unwrap().stats();
+
+        assert_eq!(stats.slides, 2);
+        assert_eq!(stats.words, 5);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.slides_with_notes, 1);
+        assert_eq!(stats.target_duration, Duration::from_secs(90));
+    }
+
+    #[test]
+    fn deck_languages_count_code_blocks() {
+        let markdown = concat!(
+            "```rust\nfn a() {}\n```\n\n```python\nprint(1)\n```\n\n---\n\n",
+            "> ```rust\n> fn b() {}\n> ```\n\n```\nplain\n```\n"
+        );
+        let languages = Deck::from_markdown(markdown).unwrap().languages();
+
+        assert_eq!(languages.len(), 3);
+        assert_eq!(languages["rust"], 2);
+        assert_eq!(languages["python"], 1);
+        assert_eq!(languages[""], 1);
+        assert!(Deck::default().languages().is_empty());
+    }
+
+    #[test]
+    fn deck_from_path_reads_file() {
+        let path = std::env::temp_dir().join("lantern_deck_from_path.md");
+        std::fs::write(&path, DECK).unwrap();
+
+        let deck = Deck::from_path(&path).unwrap();
+        assert_eq!(deck.len(), 2);
+        assert!(Deck::from_path(path.with_extension("missing")).is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,317 @@
+use crate::highlighter::HighlightedToken;
+use crate::theme::{Color, Theme};
+use crate::width::display_width;
+
+/// Line separating the before and after halves of a `diff-split` code fence
+pub const DIFF_SPLIT_SEPARATOR: &str = "---";
+
+/// How a token differs between the before and after versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+impl DiffKind {
+    /// Theme color for the kind: `code` when unchanged, `admonition_success` for insertions, `admonition_danger`
+    /// for deletions
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            Self::Equal => theme.code,
+            Self::Insert => theme.admonition_success,
+            Self::Delete => theme.admonition_danger,
+        }
+    }
+}
+
+/// A run of text on one side of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffToken {
+    pub text: String,
+    pub kind: DiffKind,
+}
+
+/// Both sides of a word-level diff, with one token line per input line
+///
+/// `before` holds only [DiffKind::Equal] and [DiffKind::Delete] tokens, `after` only [DiffKind::Equal] and
+/// [DiffKind::Insert] ones, so each side can be drawn as its own pane of a `diff-split` code fence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeDiff {
+    pub before: Vec<Vec<DiffToken>>,
+    pub after: Vec<Vec<DiffToken>>,
+}
+
+impl CodeDiff {
+    /// Color both sides for display (see [DiffKind::color])
+    pub fn highlight(&self, theme: &Theme) -> (Vec<Vec<HighlightedToken>>, Vec<Vec<HighlightedToken>>) {
+        (
+            highlight_lines(&self.before, theme),
+            highlight_lines(&self.after, theme),
+        )
+    }
+
+    /// Both sides next to each other, one token line per row, for drawing a `diff-split` fence
+    ///
+    /// The before side is padded to its widest line and followed by a `│` gutter in the code fence color; a side
+    /// that runs out of lines is left blank.
+    pub fn side_by_side(&self, theme: &Theme) -> Vec<Vec<HighlightedToken>> {
+        let (before, after) = self.highlight(theme);
+        let line_width = |line: &[HighlightedToken]| line.iter().map(|token| display_width(&token.text)).sum::<usize>();
+        let pane_width = before.iter().map(|line| line_width(line)).max().unwrap_or(0);
+
+        (0..before.len().max(after.len()))
+            .map(|row| {
+                let mut line = before.get(row).cloned().unwrap_or_default();
+                let padding = " ".repeat(pane_width - line_width(&line));
+                line.push(HighlightedToken { text: format!("{padding} │ "), color: theme.code_fence });
+                line.extend(after.get(row).cloned().unwrap_or_default());
+                line
+            })
+            .collect()
+    }
+}
+
+/// Diff the halves of a `diff-split` fence body, which are split at its first [DIFF_SPLIT_SEPARATOR] line
+///
+/// A body without a separator is all "before", so every line shows as deleted.
+pub fn diff_split(source: &str) -> CodeDiff {
+    let mut lines = source.lines();
+    let before: Vec<&str> = lines
+        .by_ref()
+        .take_while(|line| line.trim() != DIFF_SPLIT_SEPARATOR)
+        .collect();
+    let after: Vec<&str> = lines.collect();
+    diff_code(&before.join("\n"), &after.join("\n"))
+}
+
+/// Diff two versions of some code line by line, then word by word within changed lines
+///
+/// Lines between unchanged ones are paired up in order and diffed by word; lines left over on either side are
+/// marked deleted or inserted whole. Words are identifier runs, whitespace runs, and single punctuation characters.
+pub fn diff_code(before: &str, after: &str) -> CodeDiff {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    let mut diff = CodeDiff::default();
+
+    let (mut i, mut j) = (0, 0);
+    for (old_idx, new_idx) in lcs(&old, &new).into_iter().chain([(old.len(), new.len())]) {
+        let removed = &old[i..old_idx];
+        let added = &new[j..new_idx];
+        for k in 0..removed.len().max(added.len()) {
+            match (removed.get(k), added.get(k)) {
+                (Some(old_line), Some(new_line)) => {
+                    let (before_line, after_line) = diff_words(old_line, new_line);
+                    diff.before.push(before_line);
+                    diff.after.push(after_line);
+                }
+                (Some(old_line), None) => diff.before.push(whole_line(old_line, DiffKind::Delete)),
+                (None, Some(new_line)) => diff.after.push(whole_line(new_line, DiffKind::Insert)),
+                (None, None) => {}
+            }
+        }
+
+        if let Some(line) = old.get(old_idx) {
+            diff.before.push(whole_line(line, DiffKind::Equal));
+            diff.after.push(whole_line(line, DiffKind::Equal));
+        }
+        i = old_idx + 1;
+        j = new_idx + 1;
+    }
+
+    diff
+}
+
+/// Word-level diff of a single pair of lines
+fn diff_words(before: &str, after: &str) -> (Vec<DiffToken>, Vec<DiffToken>) {
+    let old = words(before);
+    let new = words(after);
+    let (mut before_line, mut after_line) = (Vec::new(), Vec::new());
+
+    let (mut i, mut j) = (0, 0);
+    for (old_idx, new_idx) in lcs(&old, &new).into_iter().chain([(old.len(), new.len())]) {
+        for word in &old[i..old_idx] {
+            push_token(&mut before_line, word, DiffKind::Delete);
+        }
+        for word in &new[j..new_idx] {
+            push_token(&mut after_line, word, DiffKind::Insert);
+        }
+        if let Some(word) = old.get(old_idx) {
+            push_token(&mut before_line, word, DiffKind::Equal);
+            push_token(&mut after_line, word, DiffKind::Equal);
+        }
+        i = old_idx + 1;
+        j = new_idx + 1;
+    }
+
+    (before_line, after_line)
+}
+
+fn whole_line(line: &str, kind: DiffKind) -> Vec<DiffToken> {
+    let mut tokens = Vec::new();
+    push_token(&mut tokens, line, kind);
+    tokens
+}
+
+/// Append `text`, merging it into the last token when that has the same kind
+fn push_token(line: &mut Vec<DiffToken>, text: &str, kind: DiffKind) {
+    if text.is_empty() {
+        return;
+    }
+    match line.last_mut() {
+        Some(last) if last.kind == kind => last.text.push_str(text),
+        _ => line.push(DiffToken { text: text.to_string(), kind }),
+    }
+}
+
+/// Split a line into identifier runs, whitespace runs, and single punctuation characters
+fn words(line: &str) -> Vec<&str> {
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let end = idx + c.len_utf8();
+        let boundary = chars
+            .peek()
+            .is_none_or(|&(_, next)| class(c) == 2 || class(next) != class(c));
+        if boundary {
+            words.push(&line[start..end]);
+            start = end;
+        }
+    }
+    words
+}
+
+/// Index pairs of a longest common subsequence of `a` and `b`, in increasing order
+fn lcs<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let mut table = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, x) in a.iter().enumerate().rev() {
+        for (j, y) in b.iter().enumerate().rev() {
+            table[i][j] = if x == y { table[i + 1][j + 1] + 1 } else { table[i + 1][j].max(table[i][j + 1]) };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+fn highlight_lines(lines: &[Vec<DiffToken>], theme: &Theme) -> Vec<Vec<HighlightedToken>> {
+    lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|token| HighlightedToken { text: token.text.clone(), color: token.kind.color(theme) })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(line: &[DiffToken]) -> String {
+        line.iter()
+            .map(|token| match token.kind {
+                DiffKind::Equal => token.text.clone(),
+                DiffKind::Insert => format!("{{+{}+}}", token.text),
+                DiffKind::Delete => format!("[-{}-]", token.text),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_code_marks_changed_words() {
+        let before = "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+        let after = "fn add(a: i64, b: i64) -> i64 {\n    a + b\n}\n// done\n";
+        let diff = diff_code(before, after);
+
+        let before_lines: Vec<String> = diff.before.iter().map(|line| render(line)).collect();
+        let after_lines: Vec<String> = diff.after.iter().map(|line| render(line)).collect();
+        assert_eq!(
+            before_lines,
+            ["fn add(a: [-i32-], b: [-i32-]) -> [-i32-] {", "    a + b", "}"]
+        );
+        assert_eq!(
+            after_lines,
+            [
+                "fn add(a: {+i64+}, b: {+i64+}) -> {+i64+} {",
+                "    a + b",
+                "}",
+                "{+// done+}"
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_code_deletes_and_inserts_unpaired_lines() {
+        let diff = diff_code("let x = 1;\nlet y = 2;\nprint(x)", "let x = 1;\nprint(x)");
+
+        assert_eq!(diff.before.len(), 3);
+        assert_eq!(
+            diff.before[1],
+            vec![DiffToken { text: "let y = 2;".to_string(), kind: DiffKind::Delete }]
+        );
+        assert_eq!(diff.after.len(), 2);
+        assert!(diff.after.iter().flatten().all(|token| token.kind == DiffKind::Equal));
+        assert_eq!(diff_code("same\n", "same"), diff_code("same", "same"));
+    }
+
+    #[test]
+    fn diff_highlight_uses_success_and_danger_colors() {
+        let theme = Theme::test_default();
+        let (before, after) = diff_code("let a = 1;", "let a = 2;").highlight(&theme);
+
+        let colors = |line: &[HighlightedToken]| line.iter().map(|t| (t.text.clone(), t.color)).collect::<Vec<_>>();
+        let expected = [
+            ("let a = ".to_string(), theme.code),
+            ("1".to_string(), theme.admonition_danger),
+            (";".to_string(), theme.code),
+        ];
+        assert_eq!(colors(&before[0]), expected);
+        assert_eq!(colors(&after[0])[1], ("2".to_string(), theme.admonition_success));
+    }
+
+    #[test]
+    fn diff_split_lays_halves_side_by_side() {
+        let theme = Theme::test_default();
+        let diff = diff_split("let a = 1;\nlet b = 2;\n---\nlet a = 10;\n");
+        assert_eq!(diff, diff_code("let a = 1;\nlet b = 2;", "let a = 10;"));
+
+        let rows: Vec<String> = diff
+            .side_by_side(&theme)
+            .iter()
+            .map(|line| line.iter().map(|token| token.text.as_str()).collect())
+            .collect();
+        assert_eq!(rows, ["let a = 1; │ let a = 10;", "let b = 2; │ "]);
+        assert!(diff_split("only before").after.is_empty());
+    }
+
+    #[test]
+    fn words_split_identifiers_whitespace_and_punctuation() {
+        assert_eq!(words("foo_bar(x,  y)"), ["foo_bar", "(", "x", ",", "  ", "y", ")"]);
+        assert!(words("").is_empty());
+    }
+}
@@ -1,10 +1,10 @@
 use std::sync::OnceLock;
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Theme, ThemeSet};
+use syntect::highlighting::{Theme as SyntectTheme, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::LinesWithEndings;
 
-use crate::theme::{Color, ThemeColors};
+use crate::theme::{Color, Theme, ThemeColors};
 
 /// Global syntax set (lazy-initialized)
 static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
@@ -33,7 +33,20 @@ pub struct HighlightedToken {
 ///
 /// Returns a vector of lines, where each line is a vector of highlighted tokens.
 /// If the language is not recognized or highlighting fails, returns the code with default styling.
-pub fn highlight_code(code: &str, language: Option<&str>, theme_colors: &ThemeColors) -> Vec<Vec<HighlightedToken>> {
+/// With [crate::theme::ThemeSettings::highlight] off, syntect is skipped and each line is one token in the `code` color.
+///
+/// There is one entry per line of `code.lines()`: a trailing newline does not add an empty last line, empty input
+/// yields no lines, and token text never contains the line ending.
+pub fn highlight_code(code: &str, language: Option<&str>, theme_colors: &Theme) -> Vec<Vec<HighlightedToken>> {
+    let _span = tracing::debug_span!("highlight_code", language = language.unwrap_or("")).entered();
+
+    if !theme_colors.settings.highlight {
+        return code
+            .lines()
+            .map(|line| vec![HighlightedToken { text: line.to_string(), color: theme_colors.code }])
+            .collect();
+    }
+
     let ss = syntax_set();
 
     let syntax = language
@@ -45,10 +58,11 @@ pub fn highlight_code(code: &str, language: Option<&str>, theme_colors: &ThemeCo
     let mut highlighter = HighlightLines::new(syntax, syntect_theme);
     let mut result = Vec::new();
 
+    // Syntect is fed whole lines with their endings so multi-line constructs keep their state
     for line in LinesWithEndings::from(code) {
         let Ok(ranges) = highlighter.highlight_line(line, ss) else {
             result.push(vec![HighlightedToken {
-                text: line.to_string(),
+                text: strip_line_ending(line).to_string(),
                 color: theme_colors.code,
             }]);
             continue;
@@ -56,18 +70,54 @@ pub fn highlight_code(code: &str, language: Option<&str>, theme_colors: &ThemeCo
 
         let mut tokens = Vec::new();
         for (style, text) in ranges {
-            let color = Color::from_syntect(style.foreground);
-            tokens.push(HighlightedToken { text: text.to_string(), color });
+            let text = strip_line_ending(text);
+            if !text.is_empty() {
+                tokens.push(HighlightedToken { text: text.to_string(), color: Color::from_syntect(style.foreground) });
+            }
         }
         result.push(tokens);
     }
 
+    tracing::debug!(lines = result.len(), "highlighted code");
     result
 }
 
+/// `text` without a trailing `\n` or `\r\n`
+fn strip_line_ending(text: &str) -> &str {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    text.strip_suffix('\r').unwrap_or(text)
+}
+
+/// Check whether a syntect theme with the given name is bundled
+pub fn is_known_code_theme(name: &str) -> bool {
+    theme_set().themes.contains_key(name)
+}
+
+/// The bundled syntect theme key matching `name`, if there is one
+pub fn code_theme_key(name: &str) -> Option<&'static str> {
+    theme_set().themes.get_key_value(name).map(|(key, _)| key.as_str())
+}
+
+/// List all bundled syntect theme names
+pub fn available_code_themes() -> Vec<&'static str> {
+    theme_set().themes.keys().map(String::as_str).collect()
+}
+
 /// Get the appropriate syntect theme based on the current theme
-fn get_syntect_theme(theme_colors: &ThemeColors) -> &'static Theme {
+///
+/// An explicit `code_theme` override wins; otherwise a light or dark theme is picked from the body color.
+fn get_syntect_theme(theme_colors: &Theme) -> &'static SyntectTheme {
     let ts = theme_set();
+
+    if let Some(theme) = theme_colors
+        .settings
+        .code_theme
+        .as_ref()
+        .and_then(|name| ts.themes.get(name))
+    {
+        return theme;
+    }
+
     let is_dark = is_dark_theme(theme_colors);
 
     if is_dark {
@@ -92,6 +142,25 @@ fn is_dark_theme(theme_colors: &ThemeColors) -> bool {
     luminance > 128.0
 }
 
+impl Theme {
+    /// Override the syntect theme used for code blocks
+    ///
+    /// Unknown names are logged and ignored so highlighting falls back to the automatic selection.
+    pub fn with_code_theme(mut self, name: &str) -> Self {
+        match code_theme_key(name) {
+            Some(key) => self.settings.code_theme = Some(key.to_string()),
+            None => tracing::warn!("Unknown code theme '{}', falling back to automatic selection", name),
+        }
+        self
+    }
+
+    /// Enable or disable syntax highlighting of code blocks
+    pub fn with_highlighting(mut self, enabled: bool) -> Self {
+        self.settings.highlight = enabled;
+        self
+    }
+}
+
 impl Color {
     /// Create a Color from syntect's RGB color
     pub fn from_syntect(color: syntect::highlighting::Color) -> Self {
@@ -118,7 +187,7 @@ mod tests {
     #[test]
     fn highlight_code_with_rust_syntax() {
         let code = "fn main() {\n    println!(\"Hello\");\n}";
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, Some("rust"), &theme);
 
         assert_eq!(result.len(), 3);
@@ -130,7 +199,7 @@ mod tests {
     #[test]
     fn highlight_code_with_unknown_language() {
         let code = "some random text";
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, Some("unknown-lang-xyz"), &theme);
         assert_eq!(result.len(), 1);
         assert!(!result[0].is_empty());
@@ -139,7 +208,7 @@ mod tests {
     #[test]
     fn highlight_code_without_language() {
         let code = "plain text\nno highlighting";
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, None, &theme);
         assert_eq!(result.len(), 2);
         assert!(!result[0].is_empty());
@@ -148,7 +217,7 @@ mod tests {
 
     #[test]
     fn highlight_code_empty_string() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code("", Some("rust"), &theme);
         assert!(result.is_empty() || (result.len() == 1 && result[0].is_empty()));
     }
@@ -156,7 +225,7 @@ mod tests {
     #[test]
     fn highlight_code_with_python_syntax() {
         let code = "def hello():\n    print(\"world\")";
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, Some("python"), &theme);
 
         assert_eq!(result.len(), 2);
@@ -167,11 +236,34 @@ mod tests {
     #[test]
     fn highlight_code_preserves_line_count() {
         let code = "line1\nline2\nline3\nline4";
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, Some("rust"), &theme);
         assert_eq!(result.len(), 4);
     }
 
+    #[test]
+    fn highlight_code_ignores_trailing_newline() {
+        let theme = Theme::test_default();
+
+        for highlight in [true, false] {
+            let theme = theme.clone().with_highlighting(highlight);
+            let texts = |code: &str| -> Vec<Vec<String>> {
+                highlight_code(code, Some("rust"), &theme)
+                    .into_iter()
+                    .map(|line| line.into_iter().map(|token| token.text).collect())
+                    .collect()
+            };
+            let without = texts("let a = 1;\nlet b = 2;");
+
+            assert_eq!(without.len(), 2);
+            assert_eq!(texts("let a = 1;\nlet b = 2;\n"), without);
+            assert_eq!(texts("let a = 1;\r\nlet b = 2;\r\n"), without);
+            assert!(without.iter().flatten().all(|text| !text.contains(['\n', '\r'])));
+        }
+
+        assert_eq!(highlight_code("\n\n", Some("rust"), &theme).len(), 2);
+    }
+
     #[test]
     fn color_from_syntect_conversion() {
         let syntect_color = syntect::highlighting::Color { r: 255, g: 128, b: 64, a: 255 };
@@ -248,18 +340,49 @@ mod tests {
         assert!(!is_dark_theme(&light_theme));
     }
 
+    #[test]
+    fn highlight_disabled_returns_single_code_token_per_line() {
+        let theme = Theme::test_default().with_highlighting(false);
+        let code = "fn main() {\n    println!(\"Hello\");\n}";
+        let lines = highlight_code(code, Some("rust"), &theme);
+
+        assert_eq!(lines.len(), 3);
+        for (line, source) in lines.iter().zip(code.lines()) {
+            assert_eq!(line.len(), 1);
+            assert_eq!(line[0].text.trim_end_matches('\n'), source);
+            assert_eq!(line[0].color, theme.code);
+        }
+    }
+
     #[test]
     fn get_syntect_theme_returns_valid_theme() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let syntect_theme = get_syntect_theme(&theme);
         assert!(syntect_theme.settings.background.is_some() || syntect_theme.settings.foreground.is_some());
     }
 
+    #[test]
+    fn get_syntect_theme_uses_code_theme_override() {
+        let theme = Theme::test_default().with_code_theme("InspiredGitHub");
+        assert_eq!(theme.settings.code_theme.as_deref(), Some("InspiredGitHub"));
+
+        let syntect_theme = get_syntect_theme(&theme);
+        assert!(std::ptr::eq(syntect_theme, &theme_set().themes["InspiredGitHub"]));
+    }
+
+    #[test]
+    fn get_syntect_theme_unknown_code_theme_falls_back() {
+        let base = Theme::test_default();
+        let theme = base.clone().with_code_theme("not-a-real-theme");
+        assert_eq!(theme.settings.code_theme, None);
+        assert!(std::ptr::eq(get_syntect_theme(&theme), get_syntect_theme(&base)));
+    }
+
     #[test]
     fn highlight_code_handles_multiline_strings() {
         let code = r#"let s = "hello
 world";"#;
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let result = highlight_code(code, Some("rust"), &theme);
         assert_eq!(result.len(), 2);
     }
@@ -0,0 +1,328 @@
+use crate::slide::{Admonition, Alignment, Block, CodeBlock, Footnote, List, Slide, Table, TextSpan, image_label};
+use crate::theme::Theme;
+
+/// Font size of `<h1>` through `<h6>`, relative to the body text
+const HEADING_FONT_SIZES: [&str; 6] = ["2.5em", "2em", "1.6em", "1.3em", "1.1em", "1em"];
+
+/// Export slides as a standalone HTML document with one `<section>` per slide
+///
+/// Colors come from `theme` through an embedded stylesheet, so the page needs no external assets besides images.
+pub fn export_html(slides: &[Slide], theme: &Theme) -> String {
+    export_html_with_css(slides, theme, "")
+}
+
+/// Like [export_html], with `css` appended verbatim to the embedded stylesheet
+///
+/// Rules in `css` come after the theme's, so they override it at equal specificity.
+pub fn export_html_with_css(slides: &[Slide], theme: &Theme, css: &str) -> String {
+    let title = slides
+        .iter()
+        .find_map(Slide::title)
+        .unwrap_or_else(|| "Slides".to_string());
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape(&title)));
+    let mut styles = stylesheet(theme);
+    if !css.is_empty() {
+        styles.push_str(css);
+        if !css.ends_with('\n') {
+            styles.push('\n');
+        }
+    }
+    html.push_str(&format!("<style>\n{styles}</style>\n"));
+    html.push_str("</head>\n<body>\n");
+
+    for (idx, slide) in slides.iter().enumerate() {
+        html.push_str(&format!("<section class=\"slide\" id=\"slide-{}\">\n", idx + 1));
+        for block in &slide.blocks {
+            push_block(&mut html, block, theme);
+        }
+        push_footnotes(&mut html, &slide.footnotes);
+        html.push_str("</section>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Embedded CSS derived from the theme colors, with headings sized by [HEADING_FONT_SIZES]
+fn stylesheet(theme: &Theme) -> String {
+    let mut css = format!(
+        concat!(
+            "body {{ background: {background}; color: {body}; font-family: sans-serif; ",
+            "margin: 0 auto; max-width: 60em; }}\n",
+            ".slide {{ padding: 2em; border-bottom: 1px solid {border}; break-after: page; }}\n",
+            "h1, h2, h3, h4, h5, h6 {{ color: {heading}; }}\n",
+            "code {{ color: {code}; background: {code_bg}; }}\n",
+            "pre {{ background: {code_bg}; padding: 1em; overflow-x: auto; }}\n",
+            "blockquote {{ border-left: 3px solid {quote}; margin-left: 0; padding-left: 1em; }}\n",
+            "hr {{ border: 0; border-top: 1px solid {rule}; }}\n",
+            "table {{ border-collapse: collapse; }}\n",
+            "th, td {{ border: 1px solid {table}; padding: 0.25em 0.75em; }}\n",
+            ".admonition {{ border-left: 4px solid; padding: 0.5em 1em; margin: 1em 0; }}\n",
+            ".admonition-title {{ font-weight: bold; }}\n",
+            ".pagebreak {{ break-after: page; }}\n",
+            ".footnotes {{ font-size: 0.85em; border-top: 1px solid {rule}; padding-top: 0.5em; }}\n",
+            "img {{ max-width: 100%; }}\n",
+        ),
+        background = theme.ui_background.to_hex(),
+        body = theme.body.to_hex(),
+        border = theme.ui_border.to_hex(),
+        heading = theme.heading.to_hex(),
+        code = theme.code.to_hex(),
+        code_bg = theme.inline_code_bg.to_hex(),
+        quote = theme.blockquote_border.to_hex(),
+        rule = theme.rule.to_hex(),
+        table = theme.table_border.to_hex(),
+    );
+    for (idx, size) in HEADING_FONT_SIZES.iter().enumerate() {
+        css.push_str(&format!("h{} {{ font-size: {size}; }}\n", idx + 1));
+    }
+    css
+}
+
+fn push_block(html: &mut String, block: &Block, theme: &Theme) {
+    match block {
+        Block::Heading { level, spans } => {
+            let level = (*level).clamp(1, 6);
+            html.push_str(&format!("<h{level}>{}</h{level}>\n", spans_html(spans)));
+        }
+        Block::Paragraph { spans } => html.push_str(&format!("<p>{}</p>\n", spans_html(spans))),
+        Block::Code(code) => push_code(html, code),
+        Block::List(list) => push_list(html, list, theme),
+        Block::Rule => html.push_str("<hr>\n"),
+        Block::BlockQuote { blocks } => {
+            html.push_str("<blockquote>\n");
+            for block in blocks {
+                push_block(html, block, theme);
+            }
+            html.push_str("</blockquote>\n");
+        }
+        Block::Table(table) => push_table(html, table),
+        Block::Admonition(admonition) => push_admonition(html, admonition, theme),
+        Block::Callout { icon, spans } => html.push_str(&format!(
+            "<p class=\"callout\" style=\"color: {}\">{} {}</p>\n",
+            theme.admonition_tip.to_hex(),
+            escape(icon),
+            spans_html(spans)
+        )),
+        Block::Image { path, alt, .. } => {
+            html.push_str(&format!(
+                "<p><img src=\"{}\" alt=\"{}\"></p>\n",
+                escape(path),
+                escape(&image_label(path, alt))
+            ));
+        }
+        Block::PageBreak => html.push_str("<div class=\"pagebreak\"></div>\n"),
+    }
+}
+
+fn push_footnotes(html: &mut String, footnotes: &[Footnote]) {
+    if footnotes.is_empty() {
+        return;
+    }
+
+    html.push_str("<ol class=\"footnotes\">\n");
+    for footnote in footnotes {
+        html.push_str(&format!(
+            "<li value=\"{}\">{}</li>\n",
+            footnote.number,
+            spans_html(&footnote.spans)
+        ));
+    }
+    html.push_str("</ol>\n");
+}
+
+fn push_code(html: &mut String, code: &CodeBlock) {
+    match &code.language {
+        Some(language) => html.push_str(&format!("<pre><code class=\"language-{}\">", escape(language))),
+        None => html.push_str("<pre><code>"),
+    }
+    html.push_str(&escape(&code.code));
+    html.push_str("</code></pre>\n");
+}
+
+fn push_list(html: &mut String, list: &List, theme: &Theme) {
+    let tag = if list.ordered { "ol" } else { "ul" };
+    html.push_str(&format!("<{tag}>\n"));
+
+    for item in &list.items {
+        html.push_str(&format!("<li>{}", spans_html(&item.spans)));
+        for block in &item.blocks {
+            push_block(html, block, theme);
+        }
+        if let Some(nested) = &item.nested {
+            html.push('\n');
+            push_list(html, nested, theme);
+        }
+        html.push_str("</li>\n");
+    }
+
+    html.push_str(&format!("</{tag}>\n"));
+}
+
+fn push_table(html: &mut String, table: &Table) {
+    let align = |col: usize| match table.alignments.get(col) {
+        Some(Alignment::Center) => " style=\"text-align: center\"",
+        Some(Alignment::Right) => " style=\"text-align: right\"",
+        _ => "",
+    };
+
+    html.push_str("<table>\n");
+    if !table.headers.is_empty() {
+        html.push_str("<thead><tr>");
+        for (col, cell) in table.headers.iter().enumerate() {
+            html.push_str(&format!("<th{}>{}</th>", align(col), spans_html(cell)));
+        }
+        html.push_str("</tr></thead>\n");
+    }
+
+    html.push_str("<tbody>\n");
+    for row in &table.rows {
+        html.push_str("<tr>");
+        for (col, cell) in row.iter().enumerate() {
+            html.push_str(&format!("<td{}>{}</td>", align(col), spans_html(cell)));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n");
+}
+
+fn push_admonition(html: &mut String, admonition: &Admonition, theme: &Theme) {
+    let (icon, color, default_title) = admonition.presentation(theme);
+    let title = admonition.title.as_deref().unwrap_or(default_title);
+
+    html.push_str(&format!(
+        "<div class=\"admonition\" style=\"border-color: {}\">\n",
+        color.to_hex()
+    ));
+    html.push_str(&format!(
+        "<p class=\"admonition-title\">{} {}</p>\n",
+        escape(icon),
+        escape(title)
+    ));
+    for block in &admonition.blocks {
+        push_block(html, block, theme);
+    }
+    html.push_str("</div>\n");
+}
+
+/// Inline HTML for a run of styled spans
+fn spans_html(spans: &[TextSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| {
+            let mut text = escape(&span.text);
+            if span.style.code {
+                text = format!("<code>{text}</code>");
+            }
+            if span.style.strikethrough {
+                text = format!("<del>{text}</del>");
+            }
+            if span.style.italic {
+                text = format!("<em>{text}</em>");
+            }
+            if span.style.bold {
+                text = format!("<strong>{text}</strong>");
+            }
+            text
+        })
+        .collect()
+}
+
+/// Escape text for use in HTML content and double-quoted attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slide::TextStyle;
+
+    #[test]
+    fn export_wraps_slides_in_sections() {
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Intro")] }]),
+            Slide::with_blocks(vec![Block::Code(CodeBlock::with_language("rust", "if a < b {}"))]),
+        ];
+
+        let html = export_html(&slides, &Theme::test_default());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Intro</title>"));
+        assert!(html.contains("<section class=\"slide\" id=\"slide-1\">\n<h1>Intro</h1>"));
+        assert!(html.contains("<pre><code class=\"language-rust\">if a &lt; b {}</code></pre>"));
+        assert_eq!(html.matches("<section").count(), 2);
+    }
+
+    #[test]
+    fn export_sizes_headings_by_level() {
+        let heading = |level, text| Block::Heading { level, spans: vec![TextSpan::plain(text)] };
+        let slides = vec![Slide::with_blocks(vec![heading(1, "Title"), heading(3, "Detail")])];
+        let html = export_html(&slides, &Theme::test_default());
+
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("<h3>Detail</h3>"));
+        assert!(html.contains("h1 { font-size: 2.5em; }"));
+        assert!(html.contains("h3 { font-size: 1.6em; }"));
+        assert_ne!(HEADING_FONT_SIZES[0], HEADING_FONT_SIZES[2]);
+    }
+
+    #[test]
+    fn export_appends_custom_css_verbatim() {
+        let slides = vec![Slide::with_blocks(vec![Block::Rule])];
+        let css = ".slide > h1 { font-family: \"Fira Sans\", serif; }";
+        let theme = Theme::test_default();
+        let html = export_html_with_css(&slides, &theme, css);
+
+        let style = &html[html.find("<style>").unwrap()..html.find("</style>").unwrap()];
+        assert!(style.ends_with(&format!("{css}\n")));
+        assert_eq!(export_html_with_css(&slides, &theme, ""), export_html(&slides, &theme));
+    }
+
+    #[test]
+    fn export_lists_footnotes_after_slide_content() {
+        let mut slide = Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain("Claim¹")] }]);
+        slide.footnotes = vec![Footnote { number: 1, spans: vec![TextSpan::plain("A & B, 2024")] }];
+
+        let html = export_html(&[slide], &Theme::test_default());
+        assert!(html.contains("<p>Claim¹</p>\n<ol class=\"footnotes\">\n<li value=\"1\">A &amp; B, 2024</li>\n</ol>"));
+    }
+
+    #[test]
+    fn export_images_use_alt_or_path_fallback() {
+        let slides = vec![Slide::with_blocks(vec![
+            Block::Image { path: "img/arch.png".to_string(), alt: "System \"overview\"".to_string(), caption: None },
+            Block::Image { path: "img/chart.png".to_string(), alt: String::new(), caption: None },
+        ])];
+
+        let html = export_html(&slides, &Theme::test_default());
+        assert!(html.contains("<img src=\"img/arch.png\" alt=\"System &quot;overview&quot;\">"));
+        assert!(html.contains("<img src=\"img/chart.png\" alt=\"chart.png\">"));
+    }
+
+    #[test]
+    fn spans_html_nests_styles() {
+        let spans = vec![
+            TextSpan::plain("a "),
+            TextSpan { text: "b".to_string(), style: TextStyle { bold: true, italic: true, ..Default::default() } },
+            TextSpan { text: "<c>".to_string(), style: TextStyle { code: true, ..Default::default() } },
+        ];
+        assert_eq!(
+            spans_html(&spans),
+            "a <strong><em>b</em></strong><code>&lt;c&gt;</code>"
+        );
+    }
+}
@@ -1,9 +1,15 @@
+pub mod deck;
+pub mod diff;
 pub mod error;
 pub mod highlighter;
+pub mod html;
+pub mod math;
 pub mod metadata;
 pub mod parser;
 pub mod printer;
+pub mod roff;
 pub mod slide;
 pub mod term;
 pub mod theme;
 pub mod validator;
+pub mod width;
@@ -0,0 +1,301 @@
+/// Best-effort conversion of TeX math to Unicode for terminal display
+///
+/// Handles Greek letters, common operators and relations, and `^`/`_` scripts whose characters all have Unicode
+/// superscript or subscript forms (`x^2` → `x²`, `x_i` → `xᵢ`). Anything else, such as unknown macros or scripts
+/// without a Unicode form, is kept as literal TeX so it still reads correctly.
+pub fn tex_to_unicode(tex: &str) -> String {
+    let mut out = String::new();
+    let mut chars = tex.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek()
+                    && next.is_ascii_alphabetic()
+                {
+                    name.push(next);
+                    chars.next();
+                }
+
+                if name.is_empty() {
+                    match chars.next() {
+                        Some(escaped @ ('{' | '}' | '_' | '^' | '$' | '%' | '&' | '#')) => out.push(escaped),
+                        Some(other) => {
+                            out.push('\\');
+                            out.push(other);
+                        }
+                        None => out.push('\\'),
+                    }
+                } else if let Some(symbol) = macro_symbol(&name) {
+                    out.push_str(symbol);
+                } else {
+                    out.push('\\');
+                    out.push_str(&name);
+                }
+            }
+            '^' | '_' => {
+                let superscript = ch == '^';
+                let Some((braced, content)) = read_group(&mut chars) else {
+                    out.push(ch);
+                    continue;
+                };
+
+                let content = tex_to_unicode(&content);
+                match content
+                    .chars()
+                    .map(|c| script_char(c, superscript))
+                    .collect::<Option<String>>()
+                {
+                    Some(script) => out.push_str(&script),
+                    None => {
+                        out.push(ch);
+                        out.push_str(&if braced { format!("{{{content}}}") } else { content });
+                    }
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
+}
+
+/// Read a script argument: a `{...}` group (nesting allowed), a macro, or a single character
+///
+/// Returns whether the argument was braced and its content without the outer braces.
+fn read_group(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<(bool, String)> {
+    let first = chars.next()?;
+    if first != '{' {
+        let mut content = first.to_string();
+        while first == '\\'
+            && let Some(&next) = chars.peek()
+            && next.is_ascii_alphabetic()
+        {
+            content.push(next);
+            chars.next();
+        }
+        return Some((false, content));
+    }
+
+    let mut depth = 1;
+    let mut content = String::new();
+    for ch in chars.by_ref() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((true, content));
+                }
+            }
+            _ => {}
+        }
+        content.push(ch);
+    }
+
+    Some((true, content))
+}
+
+/// Unicode symbol for a TeX macro name (without the backslash)
+fn macro_symbol(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "alpha" => "α",
+        "beta" => "β",
+        "gamma" => "γ",
+        "delta" => "δ",
+        "epsilon" => "ϵ",
+        "varepsilon" => "ε",
+        "zeta" => "ζ",
+        "eta" => "η",
+        "theta" => "θ",
+        "iota" => "ι",
+        "kappa" => "κ",
+        "lambda" => "λ",
+        "mu" => "μ",
+        "nu" => "ν",
+        "xi" => "ξ",
+        "pi" => "π",
+        "rho" => "ρ",
+        "sigma" => "σ",
+        "tau" => "τ",
+        "upsilon" => "υ",
+        "phi" => "ϕ",
+        "varphi" => "φ",
+        "chi" => "χ",
+        "psi" => "ψ",
+        "omega" => "ω",
+        "Gamma" => "Γ",
+        "Delta" => "Δ",
+        "Theta" => "Θ",
+        "Lambda" => "Λ",
+        "Xi" => "Ξ",
+        "Pi" => "Π",
+        "Sigma" => "Σ",
+        "Phi" => "Φ",
+        "Psi" => "Ψ",
+        "Omega" => "Ω",
+        "sum" => "∑",
+        "prod" => "∏",
+        "int" => "∫",
+        "oint" => "∮",
+        "partial" => "∂",
+        "nabla" => "∇",
+        "infty" => "∞",
+        "sqrt" => "√",
+        "pm" => "±",
+        "mp" => "∓",
+        "times" => "×",
+        "div" => "÷",
+        "cdot" => "·",
+        "leq" | "le" => "≤",
+        "geq" | "ge" => "≥",
+        "neq" | "ne" => "≠",
+        "approx" => "≈",
+        "equiv" => "≡",
+        "sim" => "∼",
+        "propto" => "∝",
+        "to" | "rightarrow" => "→",
+        "leftarrow" | "gets" => "←",
+        "Rightarrow" | "implies" => "⇒",
+        "Leftrightarrow" | "iff" => "⇔",
+        "mapsto" => "↦",
+        "in" => "∈",
+        "notin" => "∉",
+        "subset" => "⊂",
+        "subseteq" => "⊆",
+        "cup" => "∪",
+        "cap" => "∩",
+        "emptyset" => "∅",
+        "forall" => "∀",
+        "exists" => "∃",
+        "neg" | "lnot" => "¬",
+        "land" | "wedge" => "∧",
+        "lor" | "vee" => "∨",
+        "ldots" | "dots" => "…",
+        "cdots" => "⋯",
+        "circ" => "∘",
+        "degree" => "°",
+        _ => return None,
+    })
+}
+
+/// Unicode superscript or subscript form of `ch`, if one exists
+fn script_char(ch: char, superscript: bool) -> Option<char> {
+    if superscript {
+        Some(match ch {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '+' => '⁺',
+            '-' => '⁻',
+            '=' => '⁼',
+            '(' => '⁽',
+            ')' => '⁾',
+            'a' => 'ᵃ',
+            'b' => 'ᵇ',
+            'c' => 'ᶜ',
+            'd' => 'ᵈ',
+            'e' => 'ᵉ',
+            'f' => 'ᶠ',
+            'g' => 'ᵍ',
+            'h' => 'ʰ',
+            'i' => 'ⁱ',
+            'j' => 'ʲ',
+            'k' => 'ᵏ',
+            'l' => 'ˡ',
+            'm' => 'ᵐ',
+            'n' => 'ⁿ',
+            'o' => 'ᵒ',
+            'p' => 'ᵖ',
+            'r' => 'ʳ',
+            's' => 'ˢ',
+            't' => 'ᵗ',
+            'u' => 'ᵘ',
+            'v' => 'ᵛ',
+            'w' => 'ʷ',
+            'x' => 'ˣ',
+            'y' => 'ʸ',
+            'z' => 'ᶻ',
+            'T' => 'ᵀ',
+            _ => return None,
+        })
+    } else {
+        Some(match ch {
+            '0' => '₀',
+            '1' => '₁',
+            '2' => '₂',
+            '3' => '₃',
+            '4' => '₄',
+            '5' => '₅',
+            '6' => '₆',
+            '7' => '₇',
+            '8' => '₈',
+            '9' => '₉',
+            '+' => '₊',
+            '-' => '₋',
+            '=' => '₌',
+            '(' => '₍',
+            ')' => '₎',
+            'a' => 'ₐ',
+            'e' => 'ₑ',
+            'h' => 'ₕ',
+            'i' => 'ᵢ',
+            'j' => 'ⱼ',
+            'k' => 'ₖ',
+            'l' => 'ₗ',
+            'm' => 'ₘ',
+            'n' => 'ₙ',
+            'o' => 'ₒ',
+            'p' => 'ₚ',
+            'r' => 'ᵣ',
+            's' => 'ₛ',
+            't' => 'ₜ',
+            'u' => 'ᵤ',
+            'v' => 'ᵥ',
+            'x' => 'ₓ',
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_greek_letters_and_operators() {
+        assert_eq!(tex_to_unicode(r"\alpha + \beta \leq \Omega"), "α + β ≤ Ω");
+        assert_eq!(tex_to_unicode(r"\forall x \in A"), "∀ x ∈ A");
+    }
+
+    #[test]
+    fn converts_scripts() {
+        assert_eq!(tex_to_unicode("x^2 + y^{10}"), "x² + y¹⁰");
+        assert_eq!(tex_to_unicode("x_i + a_{n-1}"), "xᵢ + aₙ₋₁");
+        assert_eq!(tex_to_unicode(r"\sum_{i=1}^{n} x_i"), "∑ᵢ₌₁ⁿ xᵢ");
+        assert_eq!(tex_to_unicode(r"\int_0^1 f(x) dx"), "∫₀¹ f(x) dx");
+    }
+
+    #[test]
+    fn keeps_scripts_without_unicode_form() {
+        assert_eq!(tex_to_unicode("x^q"), "x^q");
+        assert_eq!(tex_to_unicode(r"e^{\pi}"), "e^{π}");
+        assert_eq!(tex_to_unicode("x_{y}"), "x_{y}");
+        assert_eq!(tex_to_unicode("x^"), "x^");
+    }
+
+    #[test]
+    fn leaves_unknown_macros_literal() {
+        assert_eq!(tex_to_unicode(r"\frac{a}{b}"), r"\frac{a}{b}");
+        assert_eq!(tex_to_unicode(r"\mathbb{R} \to \mathbb{R}"), r"\mathbb{R} → \mathbb{R}");
+        assert_eq!(tex_to_unicode(r"100\% \{x\}"), "100% {x}");
+    }
+}
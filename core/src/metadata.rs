@@ -1,19 +1,79 @@
 use crate::error::{Result, SlideError};
-use serde::{Deserialize, Serialize};
+use crate::slide::{AdmonitionType, CustomAdmonition};
+use crate::theme::{BlockquoteStyle, ThemeRegistry, detect_is_dark};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::env;
+use std::path::Path;
 use std::time::SystemTime;
 
 /// Slide deck metadata from YAML frontmatter
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Meta {
+    /// Theme name, `auto`, or a `{ light, dark }` pair picked by terminal background
     #[serde(default = "Meta::default_theme")]
-    pub theme: String,
-    #[serde(default = "Meta::default_author")]
-    pub author: String,
+    pub theme: ThemeSetting,
+    /// One or more authors; accepts a single string or a sequence
+    #[serde(default = "Meta::default_author", deserialize_with = "deserialize_authors")]
+    pub author: Vec<String>,
     #[serde(default = "Meta::default_date")]
     pub date: String,
     #[serde(default = "Meta::default_paging")]
     pub paging: String,
+    /// Syntect theme name used for code blocks instead of the automatic light/dark pick
+    #[serde(default)]
+    pub code_theme: Option<String>,
+    /// Syntax-highlight code blocks; `false` shows them in the plain code color
+    #[serde(default = "Meta::default_highlight")]
+    pub highlight: bool,
+    /// How images are scaled into their slide area
+    #[serde(default)]
+    pub image_fit: ImageFit,
+    /// Cap on the image region below the text, as rows (`12`) or a share of the slide (`"40%"`)
+    #[serde(default)]
+    pub image_max_height: Option<ImageMaxHeight>,
+    /// Draw `current/total` in the bottom-right corner of each slide
+    #[serde(default)]
+    pub show_slide_number: bool,
+    /// Show an end screen when advancing past the last slide
+    #[serde(default)]
+    pub end_slide: bool,
+    /// Wrap navigation from the last slide to the first and back
+    #[serde(default, rename = "loop")]
+    pub loop_slides: bool,
+    /// Show the source file's last modification time in the status bar
+    #[serde(default)]
+    pub show_modified: bool,
+    /// Format speaker notes as markdown in the notes panel instead of showing their raw text
+    #[serde(default)]
+    pub notes_markdown: bool,
+    /// Parse `$...$` and `$$...$$` as math and show it as Unicode; off so prose like prices keeps its dollar signs
+    #[serde(default)]
+    pub math: bool,
+    /// Nudge body text toward readable when it contrasts less than [crate::theme::MIN_CONTRAST] with the background
+    #[serde(default)]
+    pub ensure_contrast: bool,
+    /// Heading prefix glyphs per level, or `false` to drop them
+    #[serde(default)]
+    pub heading_prefixes: Option<HeadingPrefixes>,
+    /// `border` (default), `indent`, or `background`
+    #[serde(default)]
+    pub blockquote_style: BlockquoteStyle,
+    /// Use ASCII admonition icons (`[i]`, `[!]`, `[x]`, ...) instead of emoji and symbols
+    #[serde(default)]
+    pub ascii_icons: bool,
+    /// Custom admonition types keyed by name, e.g. `glossary` for `:::glossary`
+    #[serde(default)]
+    pub admonitions: BTreeMap<String, CustomAdmonition>,
+    /// Seconds without input before the presenter dims the screen; the next key restores it
+    #[serde(default)]
+    pub idle_dim: Option<u64>,
+    /// Extra CSS appended to the stylesheet of exported HTML
+    #[serde(default)]
+    pub css: Option<String>,
+    /// File of extra CSS for exported HTML, relative to the deck; appended after `css`
+    #[serde(default)]
+    pub css_file: Option<String>,
 }
 
 impl Default for Meta {
@@ -23,6 +83,24 @@ impl Default for Meta {
             author: Self::default_author(),
             date: Self::default_date(),
             paging: Self::default_paging(),
+            code_theme: None,
+            highlight: Self::default_highlight(),
+            image_fit: ImageFit::default(),
+            image_max_height: None,
+            show_slide_number: false,
+            end_slide: false,
+            loop_slides: false,
+            show_modified: false,
+            notes_markdown: false,
+            math: false,
+            ensure_contrast: false,
+            heading_prefixes: None,
+            blockquote_style: BlockquoteStyle::default(),
+            ascii_icons: false,
+            admonitions: BTreeMap::new(),
+            idle_dim: None,
+            css: None,
+            css_file: None,
         }
     }
 }
@@ -47,16 +125,90 @@ impl Meta {
                 Ok(meta) => Ok(meta),
                 Err(e) => Err(SlideError::front_matter(format!("Failed to parse TOML: {e}"))),
             },
+            FrontmatterFormat::Json => match serde_json::from_str(header) {
+                Ok(meta) => Ok(meta),
+                Err(e) => Err(SlideError::front_matter(format!("Failed to parse JSON: {e}"))),
+            },
+        }
+    }
+
+    /// Fill fields still at their default value from `other`
+    ///
+    /// A field set in `self` (different from `defaults`) is kept; custom admonitions are unioned, with
+    /// entries already in `self` winning on name clashes.
+    pub fn merge_from(&mut self, other: &Meta, defaults: &Meta) {
+        if self.theme == defaults.theme {
+            self.theme = other.theme.clone();
+        }
+        if self.author == defaults.author {
+            self.author = other.author.clone();
+        }
+        if self.date == defaults.date {
+            self.date = other.date.clone();
+        }
+        if self.paging == defaults.paging {
+            self.paging = other.paging.clone();
+        }
+        if self.code_theme == defaults.code_theme {
+            self.code_theme = other.code_theme.clone();
+        }
+        if self.highlight == defaults.highlight {
+            self.highlight = other.highlight;
+        }
+        if self.image_fit == defaults.image_fit {
+            self.image_fit = other.image_fit;
+        }
+        if self.image_max_height == defaults.image_max_height {
+            self.image_max_height = other.image_max_height;
+        }
+        if self.show_slide_number == defaults.show_slide_number {
+            self.show_slide_number = other.show_slide_number;
+        }
+        if self.end_slide == defaults.end_slide {
+            self.end_slide = other.end_slide;
+        }
+        if self.loop_slides == defaults.loop_slides {
+            self.loop_slides = other.loop_slides;
+        }
+        if self.show_modified == defaults.show_modified {
+            self.show_modified = other.show_modified;
+        }
+        if self.notes_markdown == defaults.notes_markdown {
+            self.notes_markdown = other.notes_markdown;
+        }
+        if self.math == defaults.math {
+            self.math = other.math;
+        }
+        if self.ensure_contrast == defaults.ensure_contrast {
+            self.ensure_contrast = other.ensure_contrast;
+        }
+        if self.heading_prefixes == defaults.heading_prefixes {
+            self.heading_prefixes = other.heading_prefixes.clone();
+        }
+        if self.blockquote_style == defaults.blockquote_style {
+            self.blockquote_style = other.blockquote_style;
+        }
+        if self.ascii_icons == defaults.ascii_icons {
+            self.ascii_icons = other.ascii_icons;
+        }
+        if self.idle_dim == defaults.idle_dim {
+            self.idle_dim = other.idle_dim;
+        }
+        if self.css == defaults.css {
+            self.css = other.css.clone();
+        }
+        if self.css_file == defaults.css_file {
+            self.css_file = other.css_file.clone();
+        }
+        for (name, custom) in &other.admonitions {
+            self.admonitions.entry(name.clone()).or_insert_with(|| custom.clone());
         }
     }
 
     /// Extract frontmatter block with the given delimiter and format
     fn extract_frontmatter(rest: &str, delimiter: &str, format: FrontmatterFormat) -> Result<(Self, String)> {
-        match rest.find(&format!("\n{delimiter}")) {
-            Some(end_pos) => Ok((
-                Self::parse(&rest[..end_pos], format)?,
-                rest[end_pos + delimiter.len() + 1..].to_string(),
-            )),
+        match rest.split_once(&format!("\n{delimiter}")) {
+            Some((header, content)) => Ok((Self::parse(header, format)?, content.to_string())),
             None => Err(SlideError::front_matter(format!(
                 "Unclosed {format} frontmatter block (missing closing {delimiter})"
             ))),
@@ -64,25 +216,77 @@ impl Meta {
     }
 
     /// Extract metadata and content from markdown
+    ///
+    /// CRLF line endings are normalized to LF first so no stray `\r` ends up in frontmatter values or content.
+    /// Never panics: malformed or truncated input yields a frontmatter error instead.
     pub fn extract_from_markdown(markdown: &str) -> Result<(Self, String)> {
-        let trimmed = markdown.trim_start();
-        match trimmed.chars().take(3).collect::<String>().as_str() {
-            "---" => Self::extract_frontmatter(&trimmed[3..], "---", FrontmatterFormat::Yaml),
-            "+++" => Self::extract_frontmatter(&trimmed[3..], "+++", FrontmatterFormat::Toml),
-            _ => Ok((Self::default(), markdown.to_string())),
+        let normalized = markdown.replace("\r\n", "\n");
+        let trimmed = normalized.trim_start();
+
+        for (delimiter, format) in [
+            ("---", FrontmatterFormat::Yaml),
+            ("+++", FrontmatterFormat::Toml),
+            (";;;", FrontmatterFormat::Json),
+        ] {
+            if let Some(rest) = trimmed.strip_prefix(delimiter) {
+                return Self::extract_frontmatter(rest, delimiter, format);
+            }
         }
+
+        Ok((Self::default(), normalized))
+    }
+
+    /// Get theme from `LANTERN_THEME`, then `SLIDES_THEME`, or return "oxocarbon-dark"
+    ///
+    /// Only used when the frontmatter has no `theme`, so the precedence is CLI > frontmatter > env > default.
+    fn default_theme() -> ThemeSetting {
+        Self::default_theme_from(|var| env::var(var).ok())
+    }
+
+    /// [Meta::default_theme] with environment variables read through `lookup`
+    fn default_theme_from(lookup: impl Fn(&str) -> Option<String>) -> ThemeSetting {
+        let from_env = ["LANTERN_THEME", "SLIDES_THEME"]
+            .into_iter()
+            .find_map(|var| lookup(var).filter(|name| !name.trim().is_empty()));
+        ThemeSetting::Name(from_env.unwrap_or_else(|| "oxocarbon-dark".to_string()))
     }
 
-    /// Get theme from environment variable or return "oxocarbon-dark"
-    fn default_theme() -> String {
-        env::var("SLIDES_THEME").unwrap_or_else(|_| "oxocarbon-dark".to_string())
+    /// Warnings for `admonitions` entries named like a built-in type or alias, which wins so the entry is never used
+    pub fn shadowed_admonition_warnings(&self) -> Vec<String> {
+        self.admonitions
+            .keys()
+            .filter(|name| name.parse::<AdmonitionType>().is_ok())
+            .map(|name| format!("Custom admonition '{name}' is ignored because it shadows a built-in admonition type"))
+            .collect()
+    }
+
+    /// Extra CSS for HTML export: `css` followed by the contents of `css_file`, resolved against `base_dir`
+    pub fn custom_css(&self, base_dir: &Path) -> Result<String> {
+        let mut css = self.css.clone().unwrap_or_default();
+        if let Some(file) = &self.css_file {
+            let path = base_dir.join(file);
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| SlideError::front_matter(format!("css_file {}: {e}", path.display())))?;
+            if !css.is_empty() && !css.ends_with('\n') {
+                css.push('\n');
+            }
+            css.push_str(&contents);
+        }
+        Ok(css)
+    }
+
+    /// Authors joined with commas for display
+    pub fn author_display(&self) -> String {
+        self.author.join(", ")
     }
 
     /// Get current system user's name
-    fn default_author() -> String {
-        env::var("USER")
-            .or_else(|_| env::var("USERNAME"))
-            .unwrap_or_else(|_| "Unknown".to_string())
+    fn default_author() -> Vec<String> {
+        vec![
+            env::var("USER")
+                .or_else(|_| env::var("USERNAME"))
+                .unwrap_or_else(|_| "Unknown".to_string()),
+        ]
     }
 
     /// Get current date in YYYY-MM-DD format
@@ -102,17 +306,226 @@ impl Meta {
         }
     }
 
+    fn default_highlight() -> bool {
+        true
+    }
+
     /// Default paging format
     fn default_paging() -> String {
         "Slide %d / %d".to_string()
     }
 }
 
+/// Values supplied outside the deck, such as CLI flags, that take precedence over frontmatter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetaOverrides {
+    /// Theme name, e.g. from `--theme`; `auto` is resolved like the frontmatter value
+    pub theme: Option<String>,
+}
+
+/// Metadata as actually used for presenting, after theme resolution and overrides
+///
+/// Kept separate from [Meta], which always mirrors the frontmatter as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveMeta {
+    /// Concrete theme name to load from the registry
+    pub theme: String,
+    pub authors: Vec<String>,
+    /// Fields replaced by an override, as `(field, value)` pairs
+    pub overrides: Vec<(String, String)>,
+}
+
+impl EffectiveMeta {
+    /// Resolve `meta` with `overrides` applied, using the (cached) terminal background detection
+    pub fn resolve(meta: &Meta, overrides: &MetaOverrides) -> Self {
+        Self::resolve_with(meta, overrides, detect_is_dark())
+    }
+
+    /// Resolve `meta` with `overrides` applied for a dark or light background
+    pub fn resolve_with(meta: &Meta, overrides: &MetaOverrides, is_dark: bool) -> Self {
+        let mut applied = Vec::new();
+
+        let theme = match &overrides.theme {
+            Some(name) => {
+                applied.push(("theme".to_string(), name.clone()));
+                ThemeSetting::from(name.as_str()).resolve_with(is_dark)
+            }
+            None => meta.theme.resolve_with(is_dark),
+        };
+
+        Self { theme, authors: meta.author.clone(), overrides: applied }
+    }
+}
+
+/// Frontmatter `theme` value: a single theme name or a light/dark pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeSetting {
+    /// A theme name; `auto` and `default` pick oxocarbon-light or oxocarbon-dark, `random` any built-in theme
+    Name(String),
+    /// Themes for light and dark terminal backgrounds
+    Adaptive { light: String, dark: String },
+}
+
+impl ThemeSetting {
+    /// Resolve to a concrete theme name, querying the (cached) terminal background only for `auto` settings
+    pub fn resolve(&self) -> String {
+        self.resolve_by(detect_is_dark)
+    }
+
+    /// Resolve to a concrete theme name for a dark or light background
+    pub fn resolve_with(&self, is_dark: bool) -> String {
+        self.resolve_by(|| is_dark)
+    }
+
+    /// Resolve, calling `is_dark` only when the setting depends on the background
+    fn resolve_by(&self, is_dark: impl FnOnce() -> bool) -> String {
+        match self {
+            Self::Name(name) if name.eq_ignore_ascii_case("auto") || name.eq_ignore_ascii_case("default") => {
+                if is_dark() { "oxocarbon-dark" } else { "oxocarbon-light" }.to_string()
+            }
+            Self::Name(name) if name.eq_ignore_ascii_case("random") => ThemeRegistry::random_name().to_string(),
+            Self::Name(name) => name.clone(),
+            Self::Adaptive { light, dark } => if is_dark() { dark } else { light }.clone(),
+        }
+    }
+
+    /// Every theme name this setting may resolve to
+    pub fn names(&self) -> Vec<&str> {
+        match self {
+            Self::Name(name) => vec![name.as_str()],
+            Self::Adaptive { light, dark } => vec![light.as_str(), dark.as_str()],
+        }
+    }
+}
+
+impl From<&str> for ThemeSetting {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl std::fmt::Display for ThemeSetting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Adaptive { light, dark } => write!(f, "{light}/{dark}"),
+        }
+    }
+}
+
+/// Deserialize `author` from either a single string or a sequence of strings
+fn deserialize_authors<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(author) => vec![author],
+        OneOrMany::Many(authors) => authors,
+    })
+}
+
+/// Image scaling mode selected with the `image_fit` frontmatter key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFit {
+    /// Scale down to fit the area but never beyond the image's native size
+    Contain,
+    /// Scale up or down to fill the area, preserving aspect ratio
+    #[default]
+    Fit,
+    /// Draw at native size, cropped to the area
+    Native,
+}
+
+/// Maximum height of a slide's image region, selected with the `image_max_height` frontmatter key
+///
+/// Written as a row count (`12`) or a percentage of the slide's content height (`"40%"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawImageMaxHeight", into = "RawImageMaxHeight")]
+pub enum ImageMaxHeight {
+    Rows(u16),
+    Percent(u8),
+}
+
+impl ImageMaxHeight {
+    /// Resolve the cap to rows for a content area `available` rows tall
+    pub fn rows(self, available: u16) -> u16 {
+        match self {
+            Self::Rows(rows) => rows.min(available),
+            Self::Percent(percent) => (u32::from(available) * u32::from(percent) / 100) as u16,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawImageMaxHeight {
+    Rows(u16),
+    Text(String),
+}
+
+impl TryFrom<RawImageMaxHeight> for ImageMaxHeight {
+    type Error = String;
+
+    fn try_from(raw: RawImageMaxHeight) -> std::result::Result<Self, Self::Error> {
+        let text = match raw {
+            RawImageMaxHeight::Rows(rows) => return Ok(Self::Rows(rows)),
+            RawImageMaxHeight::Text(text) => text,
+        };
+
+        let invalid = || format!("invalid image_max_height '{text}', expected rows (12) or a percentage (\"40%\")");
+        match text.trim().strip_suffix('%') {
+            Some(percent) => match percent.trim().parse::<u8>() {
+                Ok(percent) if percent <= 100 => Ok(Self::Percent(percent)),
+                _ => Err(invalid()),
+            },
+            None => text.trim().parse().map(Self::Rows).map_err(|_| invalid()),
+        }
+    }
+}
+
+impl From<ImageMaxHeight> for RawImageMaxHeight {
+    fn from(cap: ImageMaxHeight) -> Self {
+        match cap {
+            ImageMaxHeight::Rows(rows) => Self::Rows(rows),
+            ImageMaxHeight::Percent(percent) => Self::Text(format!("{percent}%")),
+        }
+    }
+}
+
+/// Heading prefixes selected with the `heading_prefixes` frontmatter key
+///
+/// Accepts a list such as `["# ", "## "]` (deeper levels reuse the last entry) or a boolean, where `false` removes the
+/// prefixes and `true` keeps the built-in glyphs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum HeadingPrefixes {
+    Enabled(bool),
+    Custom(Vec<String>),
+}
+
+impl HeadingPrefixes {
+    /// Prefix list for [crate::theme::Theme::with_heading_prefixes]; `None` keeps the theme defaults
+    pub fn resolve(&self) -> Option<Vec<String>> {
+        match self {
+            Self::Enabled(true) => None,
+            Self::Enabled(false) => Some(Vec::new()),
+            Self::Custom(prefixes) => Some(prefixes.clone()),
+        }
+    }
+}
+
 /// Frontmatter format type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum FrontmatterFormat {
     Yaml,
     Toml,
+    Json,
 }
 
 impl std::fmt::Display for FrontmatterFormat {
@@ -123,6 +536,7 @@ impl std::fmt::Display for FrontmatterFormat {
             match self {
                 FrontmatterFormat::Yaml => "YAML",
                 FrontmatterFormat::Toml => "TOML",
+                FrontmatterFormat::Json => "JSON",
             }
         )
     }
@@ -131,12 +545,13 @@ impl std::fmt::Display for FrontmatterFormat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::theme::background_from;
 
     #[test]
     fn meta_default() {
         let meta = Meta::default();
         assert_eq!(meta.paging, "Slide %d / %d");
-        assert!(!meta.theme.is_empty());
+        assert!(!meta.theme.names().is_empty());
     }
 
     #[test]
@@ -149,8 +564,8 @@ mod tests {
     fn meta_parse_yaml_partial() {
         let yaml = "theme: dark\nauthor: Test Author";
         let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
-        assert_eq!(meta.theme, "dark");
-        assert_eq!(meta.author, "Test Author");
+        assert_eq!(meta.theme, ThemeSetting::from("dark"));
+        assert_eq!(meta.author, vec!["Test Author"]);
         assert_eq!(meta.paging, "Slide %d / %d");
     }
 
@@ -163,8 +578,8 @@ date: 2024-01-15
 paging: "Page %d of %d"
         "#;
         let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
-        assert_eq!(meta.theme, "monokai");
-        assert_eq!(meta.author, "John Doe");
+        assert_eq!(meta.theme, ThemeSetting::from("monokai"));
+        assert_eq!(meta.author, vec!["John Doe"]);
         assert_eq!(meta.date, "2024-01-15");
         assert_eq!(meta.paging, "Page %d of %d");
     }
@@ -178,8 +593,8 @@ date = "2024-01-20"
 paging = "Slide %d of %d"
         "#;
         let meta = Meta::parse(toml, FrontmatterFormat::Toml).unwrap();
-        assert_eq!(meta.theme, "dracula");
-        assert_eq!(meta.author, "Jane Doe");
+        assert_eq!(meta.theme, ThemeSetting::from("dracula"));
+        assert_eq!(meta.author, vec!["Jane Doe"]);
         assert_eq!(meta.date, "2024-01-20");
         assert_eq!(meta.paging, "Slide %d of %d");
     }
@@ -194,8 +609,8 @@ author: Test
 Content here"#;
 
         let (meta, content) = Meta::extract_from_markdown(markdown).unwrap();
-        assert_eq!(meta.theme, "dark");
-        assert_eq!(meta.author, "Test");
+        assert_eq!(meta.theme, ThemeSetting::from("dark"));
+        assert_eq!(meta.author, vec!["Test"]);
         assert!(content.contains("# First Slide"));
     }
 
@@ -224,8 +639,8 @@ author = "Test"
 Content here"#;
 
         let (meta, content) = Meta::extract_from_markdown(markdown).unwrap();
-        assert_eq!(meta.theme, "dark");
-        assert_eq!(meta.author, "Test");
+        assert_eq!(meta.theme, ThemeSetting::from("dark"));
+        assert_eq!(meta.author, vec!["Test"]);
         assert!(content.contains("# First Slide"));
     }
 
@@ -235,4 +650,339 @@ Content here"#;
         let result = Meta::extract_from_markdown(markdown);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn extract_crlf_frontmatter() {
+        let markdown = "---\r\ntheme: nord\r\nauthor: Test\r\n---\r\n# First Slide\r\nContent";
+        let (meta, content) = Meta::extract_from_markdown(markdown).unwrap();
+        assert_eq!(meta.theme, ThemeSetting::from("nord"));
+        assert_eq!(meta.author, vec!["Test"]);
+        assert!(!content.contains('\r'));
+
+        let markdown = "+++\r\ntheme = \"nord\"\r\n+++\r\n# First Slide";
+        let (meta, _) = Meta::extract_from_markdown(markdown).unwrap();
+        assert_eq!(meta.theme, ThemeSetting::from("nord"));
+    }
+
+    #[test]
+    fn extract_never_panics_on_truncated_or_multibyte_input() {
+        let documents = [
+            "---\ntheme: dark\nauthor: Zoë\n---\n# Café ☕\n",
+            "+++\ntheme = \"dark\"\n+++\n# 日本語",
+            ";;;\n{\"theme\": \"dark\"}\n;;;\n# Ünïcödé",
+            "—--\n# em dash prefix",
+            "é---\ntheme: dark\n---",
+            "🦀+++\n",
+            "-",
+            "--",
+            "---",
+            "---\n",
+            "---\n---",
+            "+++\n+++",
+            ";;;",
+            "\u{feff}---\ntheme: dark\n---",
+            "\r\n---\r",
+        ];
+
+        for doc in documents {
+            for (end, _) in doc.char_indices().chain([(doc.len(), ' ')]) {
+                let input = &doc[..end];
+                match Meta::extract_from_markdown(input) {
+                    Ok(_) => {}
+                    Err(e) => assert!(
+                        e.to_string().contains("frontmatter"),
+                        "unexpected error for {input:?}: {e}"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extract_json_frontmatter() {
+        let markdown = r#";;;
+{
+  "theme": "dark",
+  "author": ["Ada", "Grace"],
+  "image_fit": "native"
+}
+;;;
+# First Slide
+Content here"#;
+
+        let (meta, content) = Meta::extract_from_markdown(markdown).unwrap();
+        assert_eq!(meta.theme, ThemeSetting::from("dark"));
+        assert_eq!(meta.author, vec!["Ada", "Grace"]);
+        assert_eq!(meta.image_fit, ImageFit::Native);
+        assert!(content.contains("# First Slide"));
+    }
+
+    #[test]
+    fn extract_unclosed_json_frontmatter() {
+        let markdown = ";;;\n{\"theme\": \"dark\"}\n# Slide";
+        let err = Meta::extract_from_markdown(markdown).unwrap_err();
+        assert!(err.to_string().contains("Unclosed JSON frontmatter"));
+    }
+
+    #[test]
+    fn effective_meta_prefers_override_theme() {
+        let meta = Meta { theme: ThemeSetting::from("dracula"), author: vec!["Ada".into()], ..Meta::default() };
+
+        let effective = EffectiveMeta::resolve_with(&meta, &MetaOverrides::default(), true);
+        assert_eq!(effective.theme, "dracula");
+        assert_eq!(effective.authors, vec!["Ada"]);
+        assert!(effective.overrides.is_empty());
+
+        let overrides = MetaOverrides { theme: Some("nord".into()) };
+        let effective = EffectiveMeta::resolve_with(&meta, &overrides, true);
+        assert_eq!(effective.theme, "nord");
+        assert_eq!(effective.overrides, vec![("theme".to_string(), "nord".to_string())]);
+        assert_eq!(meta.theme, ThemeSetting::from("dracula"));
+
+        let overrides = MetaOverrides { theme: Some("auto".into()) };
+        assert_eq!(
+            EffectiveMeta::resolve_with(&meta, &overrides, false).theme,
+            "oxocarbon-light"
+        );
+    }
+
+    #[test]
+    fn meta_parse_loop() {
+        let meta = Meta::parse("loop: true", FrontmatterFormat::Yaml).unwrap();
+        assert!(meta.loop_slides);
+        assert!(!Meta::default().loop_slides);
+    }
+
+    #[test]
+    fn meta_parse_notes_markdown() {
+        let meta = Meta::parse("notes_markdown = true", FrontmatterFormat::Toml).unwrap();
+        assert!(meta.notes_markdown);
+        assert!(!Meta::default().notes_markdown);
+    }
+
+    #[test]
+    fn meta_parse_math() {
+        let meta = Meta::parse("math: true", FrontmatterFormat::Yaml).unwrap();
+        assert!(meta.math);
+        assert!(!Meta::default().math);
+    }
+
+    #[test]
+    fn meta_parse_ensure_contrast() {
+        let meta = Meta::parse("ensure_contrast: true", FrontmatterFormat::Yaml).unwrap();
+        assert!(meta.ensure_contrast);
+        assert!(!Meta::default().ensure_contrast);
+    }
+
+    #[test]
+    fn meta_parse_show_modified() {
+        let meta = Meta::parse("show_modified: true", FrontmatterFormat::Yaml).unwrap();
+        assert!(meta.show_modified);
+        assert!(!Meta::default().show_modified);
+    }
+
+    #[test]
+    fn meta_custom_css_appends_css_file() {
+        let dir = std::env::temp_dir().join(format!("lantern-css-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("extra.css"), "h1 { letter-spacing: 2px; }\n").unwrap();
+
+        let yaml = "css: \"body { font-size: 20px; }\"\ncss_file: extra.css";
+        let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(
+            meta.custom_css(&dir).unwrap(),
+            "body { font-size: 20px; }\nh1 { letter-spacing: 2px; }\n"
+        );
+        assert_eq!(Meta::default().custom_css(&dir).unwrap(), "");
+
+        let missing = Meta { css_file: Some("missing.css".to_string()), ..Meta::default() };
+        let err = missing.custom_css(&dir).unwrap_err().to_string();
+        assert!(err.contains("css_file"), "{err}");
+        assert!(err.contains("missing.css"), "{err}");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn meta_parse_idle_dim() {
+        let meta = Meta::parse("idle_dim: 120", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.idle_dim, Some(120));
+        assert_eq!(Meta::default().idle_dim, None);
+    }
+
+    #[test]
+    fn meta_parse_ascii_icons() {
+        let meta = Meta::parse("ascii_icons: true", FrontmatterFormat::Yaml).unwrap();
+        assert!(meta.ascii_icons);
+        assert!(!Meta::default().ascii_icons);
+    }
+
+    #[test]
+    fn meta_parse_blockquote_style() {
+        let meta = Meta::parse("blockquote_style: background", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.blockquote_style, BlockquoteStyle::Background);
+        assert_eq!(Meta::default().blockquote_style, BlockquoteStyle::Border);
+        assert!(Meta::parse("blockquote_style: fancy", FrontmatterFormat::Yaml).is_err());
+    }
+
+    #[test]
+    fn meta_parse_heading_prefixes() {
+        let meta = Meta::parse("heading_prefixes: [\"# \", \"## \"]", FrontmatterFormat::Yaml).unwrap();
+        let prefixes = meta.heading_prefixes.unwrap();
+        assert_eq!(prefixes.resolve(), Some(vec!["# ".to_string(), "## ".to_string()]));
+
+        let meta = Meta::parse("heading_prefixes: false", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.heading_prefixes.unwrap().resolve(), Some(Vec::new()));
+
+        let meta = Meta::parse("heading_prefixes: true", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.heading_prefixes.unwrap().resolve(), None);
+        assert_eq!(Meta::default().heading_prefixes, None);
+    }
+
+    #[test]
+    fn meta_parse_highlight() {
+        assert!(Meta::default().highlight);
+        let meta = Meta::parse("highlight: false", FrontmatterFormat::Yaml).unwrap();
+        assert!(!meta.highlight);
+    }
+
+    #[test]
+    fn meta_parse_image_fit() {
+        let meta = Meta::parse("image_fit: native", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.image_fit, ImageFit::Native);
+
+        let meta = Meta::parse("image_fit = \"contain\"", FrontmatterFormat::Toml).unwrap();
+        assert_eq!(meta.image_fit, ImageFit::Contain);
+
+        assert_eq!(Meta::default().image_fit, ImageFit::Fit);
+    }
+
+    #[test]
+    fn meta_parse_image_max_height() {
+        let meta = Meta::parse("image_max_height: 12", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.image_max_height, Some(ImageMaxHeight::Rows(12)));
+
+        let meta = Meta::parse("image_max_height = \"40%\"", FrontmatterFormat::Toml).unwrap();
+        assert_eq!(meta.image_max_height, Some(ImageMaxHeight::Percent(40)));
+
+        assert!(Meta::parse("image_max_height: \"150%\"", FrontmatterFormat::Yaml).is_err());
+        assert_eq!(Meta::default().image_max_height, None);
+
+        assert_eq!(ImageMaxHeight::Percent(40).rows(30), 12);
+        assert_eq!(ImageMaxHeight::Rows(50).rows(30), 30);
+    }
+
+    #[test]
+    fn meta_parse_author_list() {
+        let yaml = "author:\n  - Ada Lovelace\n  - Grace Hopper";
+        let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.author, vec!["Ada Lovelace", "Grace Hopper"]);
+        assert_eq!(meta.author_display(), "Ada Lovelace, Grace Hopper");
+
+        let toml = "author = [\"Ada Lovelace\", \"Grace Hopper\"]";
+        let meta = Meta::parse(toml, FrontmatterFormat::Toml).unwrap();
+        assert_eq!(meta.author, vec!["Ada Lovelace", "Grace Hopper"]);
+    }
+
+    #[test]
+    fn meta_parse_author_scalar() {
+        let meta = Meta::parse("author: Ada Lovelace", FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(meta.author, vec!["Ada Lovelace"]);
+        assert_eq!(meta.author_display(), "Ada Lovelace");
+    }
+
+    #[test]
+    fn meta_parse_custom_admonitions() {
+        let yaml = r##"
+admonitions:
+  glossary:
+    icon: "📖"
+    color: "#88aaff"
+    title: Glossary
+"##;
+        let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
+        let glossary = &meta.admonitions["glossary"];
+        assert_eq!(glossary.icon, "📖");
+        assert_eq!(glossary.color, "#88aaff");
+        assert_eq!(glossary.title.as_deref(), Some("Glossary"));
+        assert!(meta.shadowed_admonition_warnings().is_empty());
+    }
+
+    #[test]
+    fn meta_custom_admonition_shadowing_builtin_warns() {
+        let yaml = "admonitions:\n  Tip:\n    icon: T\n    color: \"#00ff00\"\n  glossary:\n    icon: G\n    color: \"#88aaff\"";
+        let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
+        let warnings = meta.shadowed_admonition_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'Tip'"));
+    }
+
+    #[test]
+    fn meta_parse_theme_pair() {
+        let yaml = "theme:\n  light: nord-light\n  dark: nord";
+        let meta = Meta::parse(yaml, FrontmatterFormat::Yaml).unwrap();
+        assert_eq!(
+            meta.theme,
+            ThemeSetting::Adaptive { light: "nord-light".into(), dark: "nord".into() }
+        );
+        assert_eq!(meta.theme.resolve_with(true), "nord");
+        assert_eq!(meta.theme.resolve_with(false), "nord-light");
+
+        let toml = "theme = { light = \"solarized-light\", dark = \"solarized-dark\" }";
+        let meta = Meta::parse(toml, FrontmatterFormat::Toml).unwrap();
+        assert_eq!(meta.theme.names(), vec!["solarized-light", "solarized-dark"]);
+    }
+
+    #[test]
+    fn theme_precedence_cli_frontmatter_env_default() {
+        let env = |var: &str| (var == "LANTERN_THEME").then(|| "gruvbox-material-dark".to_string());
+        let from_env = Meta { theme: Meta::default_theme_from(env), ..Meta::default() };
+        let from_frontmatter = Meta::parse("theme: dracula", FrontmatterFormat::Yaml).unwrap();
+
+        let none = MetaOverrides::default();
+        let cli = MetaOverrides { theme: Some("nord".into()) };
+
+        assert_eq!(
+            EffectiveMeta::resolve_with(&from_env, &none, true).theme,
+            "gruvbox-material-dark"
+        );
+        assert_eq!(
+            EffectiveMeta::resolve_with(&from_frontmatter, &none, true).theme,
+            "dracula"
+        );
+        assert_eq!(EffectiveMeta::resolve_with(&from_env, &cli, true).theme, "nord");
+        assert_eq!(EffectiveMeta::resolve_with(&from_frontmatter, &cli, true).theme, "nord");
+    }
+
+    #[test]
+    fn default_theme_prefers_lantern_theme_over_slides_theme() {
+        let both = |var: &str| Some(if var == "LANTERN_THEME" { "nord" } else { "dracula" }.to_string());
+        assert_eq!(Meta::default_theme_from(both), ThemeSetting::from("nord"));
+
+        let legacy = |var: &str| Some(if var == "LANTERN_THEME" { " " } else { "dracula" }.to_string());
+        assert_eq!(Meta::default_theme_from(legacy), ThemeSetting::from("dracula"));
+
+        assert_eq!(Meta::default_theme_from(|_| None), ThemeSetting::from("oxocarbon-dark"));
+    }
+
+    #[test]
+    fn theme_setting_auto_uses_background_override() {
+        let pair = ThemeSetting::Adaptive { light: "nord-light".into(), dark: "nord".into() };
+        let auto = ThemeSetting::from("auto");
+
+        let light = background_from("light").unwrap();
+        assert_eq!(pair.resolve_with(light), "nord-light");
+        assert_eq!(auto.resolve_with(light), "oxocarbon-light");
+
+        let dark = background_from(" DARK ").unwrap();
+        assert_eq!(pair.resolve_with(dark), "nord");
+        assert_eq!(auto.resolve_with(dark), "oxocarbon-dark");
+
+        assert_eq!(background_from("solarized"), None);
+    }
+
+    #[test]
+    fn theme_setting_named_resolves_without_background() {
+        assert_eq!(ThemeSetting::from("dracula").resolve(), "dracula");
+    }
 }
@@ -1,28 +1,139 @@
 use crate::error::Result;
-use crate::metadata::Meta;
+use crate::math::tex_to_unicode;
+use crate::metadata::{EffectiveMeta, Meta, MetaOverrides};
 use crate::slide::*;
-use pulldown_cmark::{Alignment as PulldownAlignment, Event, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment as PulldownAlignment, BlockQuoteKind, Event, Options, Parser, Tag, TagEnd};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Options controlling how slide markdown is parsed
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Use pulldown-cmark's native GFM alert handling for `> [!NOTE]` blocks instead of string preprocessing
+    ///
+    /// Fence admonitions (`:::note`) are still preprocessed either way.
+    pub native_alerts: bool,
+    /// Extra admonition types accepted beyond [AdmonitionType], keyed by lowercase name
+    pub custom_admonitions: BTreeMap<String, CustomAdmonition>,
+    /// Parse `$...$` and `$$...$$` as math, rendered through [tex_to_unicode]; otherwise dollar signs stay literal
+    pub math: bool,
+}
+
+impl ParseOptions {
+    /// Parse options derived from deck frontmatter
+    pub fn from_meta(meta: &Meta) -> Self {
+        let custom_admonitions = meta
+            .admonitions
+            .iter()
+            .map(|(name, custom)| (name.to_lowercase(), custom.clone()))
+            .collect();
+        Self { custom_admonitions, math: meta.math, ..Self::default() }
+    }
+}
 
 /// Parse markdown content into metadata and slides
 ///
 /// Extracts frontmatter metadata, then splits content on `---` separators.
 pub fn parse_slides_with_meta(markdown: &str) -> Result<(Meta, Vec<Slide>)> {
     let (meta, content) = Meta::extract_from_markdown(markdown)?;
-    let slides = parse_slides(&content)?;
+    let slides = parse_slides_with_options(&content, &ParseOptions::from_meta(&meta))?;
+    Ok((meta, slides))
+}
+
+/// 1-based, inclusive range of source lines a slide was parsed from, first to last non-blank line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// Like [parse_slides_with_meta], pairing each slide with its [SourceSpan] in `markdown`
+///
+/// Line numbers count from the top of `markdown`, so they include the frontmatter block.
+pub fn parse_slides_with_spans(markdown: &str) -> Result<(Meta, Vec<(Slide, SourceSpan)>)> {
+    let (meta, content) = Meta::extract_from_markdown(markdown)?;
+    let normalized = markdown.replace("\r\n", "\n");
+    let frontmatter_lines = normalized[..normalized.len() - content.len()].matches('\n').count();
+
+    let options = ParseOptions::from_meta(&meta);
+    let slides = SlideSections::new(&content)
+        .map(|(section, span)| {
+            let span = SourceSpan {
+                start_line: span.start_line + frontmatter_lines,
+                end_line: span.end_line + frontmatter_lines,
+            };
+            Ok((parse_slide(section, &options)?, span))
+        })
+        .collect::<Result<Vec<_>>>()?;
     Ok((meta, slides))
 }
 
+/// Parse markdown into raw metadata, the effective metadata after `overrides`, and slides
+pub fn parse_slides_with_overrides(
+    markdown: &str, overrides: &MetaOverrides,
+) -> Result<(Meta, EffectiveMeta, Vec<Slide>)> {
+    let (meta, slides) = parse_slides_with_meta(markdown)?;
+    let effective = EffectiveMeta::resolve(&meta, overrides);
+    Ok((meta, effective, slides))
+}
+
+/// Concatenate several parsed decks into one
+///
+/// Slides keep their input order. For each metadata field the first deck that sets it to a non-default
+/// value wins (see [Meta::merge_from]).
+pub fn merge_decks(decks: Vec<(Meta, Vec<Slide>)>) -> (Meta, Vec<Slide>) {
+    let defaults = Meta::default();
+    let mut meta = defaults.clone();
+    let mut slides = Vec::new();
+
+    for (deck_meta, deck_slides) in decks {
+        meta.merge_from(&deck_meta, &defaults);
+        slides.extend(deck_slides);
+    }
+
+    (meta, slides)
+}
+
 /// Parse markdown content into a vector of slides
 pub fn parse_slides(markdown: &str) -> Result<Vec<Slide>> {
+    parse_slides_with_options(markdown, &ParseOptions::default())
+}
+
+/// Parse markdown content into a vector of slides using the given [ParseOptions]
+pub fn parse_slides_with_options(markdown: &str, options: &ParseOptions) -> Result<Vec<Slide>> {
+    let _span = tracing::debug_span!("parse_slides", bytes = markdown.len()).entered();
     let sections = split_slides(markdown);
-    sections.into_iter().map(parse_slide).collect()
+    let slides = sections
+        .into_iter()
+        .map(|section| parse_slide(section, options))
+        .collect::<Result<Vec<_>>>()?;
+
+    tracing::debug!(slides = slides.len(), "parsed slides");
+    Ok(slides)
+}
+
+/// Lazily split and parse slides, one per iteration
+///
+/// Useful for very large decks where the first slide should be available before the rest are parsed.
+pub fn parse_slides_streaming(markdown: &str) -> impl Iterator<Item = Result<Slide>> + '_ {
+    parse_sections(
+        SlideSections::new(markdown).map(|(section, _)| section),
+        ParseOptions::default(),
+    )
+}
+
+fn parse_sections(
+    sections: impl Iterator<Item = String>, options: ParseOptions,
+) -> impl Iterator<Item = Result<Slide>> {
+    sections.map(move |section| parse_slide(section, &options))
 }
 
 /// Preprocess markdown to convert admonition syntax to a format we can parse
 ///
 /// Converts both GitHub/Obsidian syntax (`> [!NOTE]`) and fence syntax (`:::note`)
-/// into a special HTML-like format that we can detect in the event stream
-fn preprocess_admonitions(markdown: &str) -> String {
+/// into a special HTML-like format that we can detect in the event stream.
+/// When `blockquote_alerts` is false, blockquote alerts are left for pulldown-cmark's native handling.
+fn preprocess_admonitions(markdown: &str, blockquote_alerts: bool) -> String {
     let mut result = String::new();
     let lines: Vec<&str> = markdown.lines().collect();
     let mut i = 0;
@@ -48,32 +159,36 @@ fn preprocess_admonitions(markdown: &str) -> String {
             continue;
         }
 
-        if trimmed.starts_with('>') {
-            if let Some((admonition_type, title)) = parse_blockquote_admonition(trimmed) {
-                result.push_str(&format!("<admonition type=\"{admonition_type}\""));
-                if let Some(t) = title {
-                    result.push_str(&format!(" title=\"{t}\""));
-                }
-                result.push_str(">\n");
-                i += 1;
+        if blockquote_alerts
+            && trimmed.starts_with('>')
+            && let Some((admonition_type, title, collapsed)) = parse_blockquote_admonition(trimmed)
+        {
+            result.push_str(&format!("<admonition type=\"{admonition_type}\""));
+            if let Some(t) = title {
+                result.push_str(&format!(" title=\"{t}\""));
+            }
+            if collapsed {
+                result.push_str(" collapsed=\"true\"");
+            }
+            result.push_str(">\n");
+            i += 1;
 
-                while i < lines.len() {
-                    let next_line = lines[i];
-                    let next_trimmed = next_line.trim();
-                    if next_trimmed.starts_with('>') {
-                        let content = next_trimmed.strip_prefix('>').unwrap_or("").trim();
-                        if !content.is_empty() {
-                            result.push_str(content);
-                            result.push('\n');
-                        }
-                        i += 1;
-                    } else {
-                        break;
+            while i < lines.len() {
+                let next_line = lines[i];
+                let next_trimmed = next_line.trim();
+                if next_trimmed.starts_with('>') {
+                    let content = next_trimmed.strip_prefix('>').unwrap_or("").trim();
+                    if !content.is_empty() {
+                        result.push_str(content);
+                        result.push('\n');
                     }
+                    i += 1;
+                } else {
+                    break;
                 }
-                result.push_str("</admonition>\n");
-                continue;
             }
+            result.push_str("</admonition>\n");
+            continue;
         }
 
         result.push_str(line);
@@ -84,6 +199,114 @@ fn preprocess_admonitions(markdown: &str) -> String {
     result
 }
 
+/// Pull `::: notes` sections out of a slide's markdown
+///
+/// Returns the remaining markdown and the notes text, with several sections joined by a blank line. Fences inside
+/// code blocks are left alone and an unclosed section runs to the end of the slide.
+fn extract_notes(markdown: &str) -> (String, Option<String>) {
+    let mut content = String::new();
+    let mut sections = Vec::new();
+    let mut section: Option<Vec<&str>> = None;
+    let mut open_fence = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if let Some(lines) = section.as_mut() {
+            if trimmed == ":::" {
+                sections.push(lines.join("\n").trim().to_string());
+                section = None;
+            } else {
+                lines.push(line);
+            }
+            continue;
+        }
+
+        track_fence(&mut open_fence, trimmed);
+        if open_fence.is_none() && parse_fence_admonition(trimmed).as_deref() == Some("notes") {
+            section = Some(Vec::new());
+            continue;
+        }
+
+        content.push_str(line);
+        content.push('\n');
+    }
+    if let Some(lines) = section {
+        sections.push(lines.join("\n").trim().to_string());
+    }
+
+    let notes = sections
+        .into_iter()
+        .filter(|notes| !notes.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    (content, (!notes.is_empty()).then_some(notes))
+}
+
+/// Rewrite inline footnotes `^[text]` as `[^inline-N]` references with definitions appended to the slide
+///
+/// Brackets inside the note may nest; code blocks and inline code are left alone.
+fn expand_inline_footnotes(markdown: &str) -> String {
+    if !markdown.contains("^[") {
+        return markdown.to_string();
+    }
+
+    let mut body = String::new();
+    let mut definitions = String::new();
+    let mut count = 0;
+    let mut open_fence = None;
+
+    for line in markdown.lines() {
+        track_fence(&mut open_fence, line.trim());
+        let chars: Vec<char> = line.chars().collect();
+        let mut in_code = false;
+        let mut idx = 0;
+
+        while idx < chars.len() {
+            let ch = chars[idx];
+            if ch == '`' {
+                in_code = !in_code;
+            } else if open_fence.is_none()
+                && !in_code
+                && ch == '^'
+                && chars.get(idx + 1) == Some(&'[')
+                && let Some(end) = closing_bracket(&chars, idx + 1)
+            {
+                count += 1;
+                let text: String = chars[idx + 2..end].iter().collect();
+                body.push_str(&format!("[^inline-{count}]"));
+                definitions.push_str(&format!("\n[^inline-{count}]: {text}\n"));
+                idx = end + 1;
+                continue;
+            }
+            body.push(ch);
+            idx += 1;
+        }
+        body.push('\n');
+    }
+
+    body.push_str(&definitions);
+    body
+}
+
+/// Index of the `]` matching the `[` at `open`
+fn closing_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, ch) in chars.iter().enumerate().skip(open) {
+        match ch {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Parse fence-style admonition: `:::note` or `:::warning Title`
 fn parse_fence_admonition(line: &str) -> Option<String> {
     let trimmed = line.trim();
@@ -103,7 +326,10 @@ fn parse_fence_admonition(line: &str) -> Option<String> {
 }
 
 /// Parse blockquote-style admonition: `> [!NOTE]` or `> [!TIP] Custom Title`
-fn parse_blockquote_admonition(line: &str) -> Option<(String, Option<String>)> {
+///
+/// An Obsidian fold marker right after the bracket (`> [!NOTE]-` collapsed, `> [!NOTE]+` expanded) sets the
+/// returned collapsed flag.
+fn parse_blockquote_admonition(line: &str) -> Option<(String, Option<String>, bool)> {
     let content = line.trim().strip_prefix('>')?.trim();
 
     if !content.starts_with("[!") {
@@ -114,14 +340,25 @@ fn parse_blockquote_admonition(line: &str) -> Option<(String, Option<String>)> {
     let close_bracket = rest.find(']')?;
     let admonition_type = rest[..close_bracket].to_lowercase();
 
-    let title = rest[close_bracket + 1..].trim();
+    let after = &rest[close_bracket + 1..];
+    let (collapsed, after) = match after.chars().next() {
+        Some('-') => (true, &after[1..]),
+        Some('+') => (false, &after[1..]),
+        _ => (false, after),
+    };
+
+    let title = after.trim();
     let title = if title.is_empty() { None } else { Some(title.to_string()) };
 
-    Some((admonition_type, title))
+    Some((admonition_type, title, collapsed))
 }
 
-/// Parse HTML admonition tag: `<admonition type="note" title="Title">`
-fn parse_admonition_html_start(html: &str) -> Option<(AdmonitionType, Option<String>)> {
+/// Parse HTML admonition tag: `<admonition type="note" title="Title" collapsed="true">`
+///
+/// Types not known to [AdmonitionType] are looked up in `custom` and yield [AdmonitionType::Custom].
+fn parse_admonition_html_start(
+    html: &str, custom: &BTreeMap<String, CustomAdmonition>,
+) -> Option<(AdmonitionType, Option<String>, Option<CustomAdmonition>, bool)> {
     let html = html.trim();
     if !html.starts_with("<admonition") {
         return None;
@@ -131,7 +368,10 @@ fn parse_admonition_html_start(html: &str) -> Option<(AdmonitionType, Option<Str
     let type_value_start = type_start + 6;
     let type_end = html[type_value_start..].find('"')? + type_value_start;
     let admonition_type_str = &html[type_value_start..type_end];
-    let admonition_type = admonition_type_str.parse().ok()?;
+    let (admonition_type, custom) = match admonition_type_str.parse() {
+        Ok(admonition_type) => (admonition_type, None),
+        Err(_) => (AdmonitionType::Custom, Some(custom.get(admonition_type_str)?.clone())),
+    };
 
     let title = if let Some(title_start) = html.find("title=\"") {
         let title_value_start = title_start + 7;
@@ -141,57 +381,298 @@ fn parse_admonition_html_start(html: &str) -> Option<(AdmonitionType, Option<Str
         None
     };
 
-    Some((admonition_type, title))
+    let collapsed = html.contains("collapsed=\"true\"");
+
+    Some((admonition_type, title, custom, collapsed))
+}
+
+/// Parse a slide timing directive: `<!-- time: 90s -->`
+fn parse_time_directive(html: &str) -> Option<Duration> {
+    comment_directive(html, "time:").and_then(parse_duration)
+}
+
+/// Parse a slide background directive: `<!-- bg-image: path.png -->`
+fn parse_bg_image_directive(html: &str) -> Option<String> {
+    comment_directive(html, "bg-image:")
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+}
+
+/// Icon for a `!! text` callout that doesn't pick its own
+const CALLOUT_ICON: &str = "\u{1F4A1}";
+
+/// Turn a paragraph starting with `!!` into a [Block::Callout]
+///
+/// `!! text` uses the tip icon and `!!🚀 text` its own. A bare `!!`, or `!!` followed by ASCII such as `!!!` or a
+/// word, leaves the paragraph alone.
+fn paragraph_or_callout(mut spans: Vec<TextSpan>) -> Block {
+    let Some((icon, text)) = spans.first().and_then(|span| callout_prefix(&span.text)) else {
+        return Block::Paragraph { spans };
+    };
+    if text.is_empty() && spans[1..].iter().all(|span| span.text.trim().is_empty()) {
+        return Block::Paragraph { spans };
+    }
+
+    if text.is_empty() {
+        spans.remove(0);
+    } else {
+        spans[0].text = text;
+    }
+    Block::Callout { icon, spans }
+}
+
+/// Split `!! text` or `!!🚀 text` into the callout icon and the text after it
+fn callout_prefix(text: &str) -> Option<(String, String)> {
+    let rest = text.strip_prefix("!!")?;
+    if rest.starts_with(char::is_whitespace) {
+        return Some((CALLOUT_ICON.to_string(), rest.trim_start().to_string()));
+    }
+
+    let (icon, text) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    if icon.is_empty() || icon.chars().any(|c| c.is_ascii()) {
+        return None;
+    }
+    Some((icon.to_string(), text.trim_start().to_string()))
+}
+
+/// Parse a slide transition directive: `<!-- transition: up -->`
+fn parse_transition_directive(html: &str) -> Option<TransitionDirection> {
+    comment_directive(html, "transition:").and_then(TransitionDirection::parse)
+}
+
+/// Parse a caption directive for the next table or image: `<!-- caption: Quarterly revenue -->`
+fn parse_caption_directive(html: &str) -> Option<String> {
+    comment_directive(html, "caption:")
+        .filter(|caption| !caption.is_empty())
+        .map(str::to_string)
+}
+
+/// Parse a slide class directive: `<!-- class: dark center -->`, with or without leading dots
+fn parse_class_directive(html: &str) -> Option<Vec<String>> {
+    comment_directive(html, "class:")
+        .map(parse_classes)
+        .filter(|classes| !classes.is_empty())
+}
+
+/// Split a class list on whitespace, dropping leading dots
+fn parse_classes(classes: &str) -> Vec<String> {
+    classes
+        .split_whitespace()
+        .map(|class| class.trim_start_matches('.'))
+        .filter(|class| !class.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Delimiter after the number of the ordered list item that `source` starts with, such as `3)`
+fn list_delimiter(source: &str) -> ListDelimiter {
+    match source
+        .trim_start()
+        .trim_start_matches(|c: char| c.is_ascii_digit())
+        .chars()
+        .next()
+    {
+        Some(')') => ListDelimiter::Paren,
+        _ => ListDelimiter::Period,
+    }
+}
+
+/// Parse a table column width directive: `<!-- cols: 20% 60% 20% -->`
+///
+/// Returns `None` unless every hint is valid (see [ColumnWidth::parse]).
+fn parse_cols_directive(html: &str) -> Option<Vec<ColumnWidth>> {
+    comment_directive(html, "cols:").and_then(|hints| parse_all(hints, ColumnWidth::parse))
+}
+
+/// Parse an ordered list numbering directive: `<!-- list: lower-roman -->`
+///
+/// Accepts the style names and first markers [ListMarkerStyle::parse] does.
+fn parse_list_directive(html: &str) -> Option<ListMarkerStyle> {
+    comment_directive(html, "list:").and_then(ListMarkerStyle::parse)
+}
+
+/// Parse a table column alignment directive: `<!-- align: L C R -->`
+///
+/// Returns `None` unless every entry is valid (see [Alignment::parse]).
+fn parse_align_directive(html: &str) -> Option<Vec<Alignment>> {
+    comment_directive(html, "align:").and_then(|hints| parse_all(hints, Alignment::parse))
+}
+
+/// Parse every whitespace-separated hint with `parse`; `None` when there are none or any is invalid
+fn parse_all<T>(hints: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    let parsed: Option<Vec<T>> = hints.split_whitespace().map(parse).collect();
+    parsed.filter(|parsed| !parsed.is_empty())
+}
+
+/// Check for a manual page break directive: `<!-- pagebreak -->`
+fn is_pagebreak_directive(html: &str) -> bool {
+    comment_directive(html, "pagebreak").is_some_and(str::is_empty)
+}
+
+/// Trimmed text after `name` in an HTML comment `<!-- name ... -->`, or `None` for other HTML
+fn comment_directive<'a>(html: &'a str, name: &str) -> Option<&'a str> {
+    let inner = html.trim().strip_prefix("<!--")?.strip_suffix("-->")?.trim();
+    Some(inner.strip_prefix(name)?.trim())
+}
+
+/// Parse a duration such as `90s`, `2m`, `1m30s`, `1h`, or bare seconds (`90`); `None` when it overflows
+fn parse_duration(value: &str) -> Option<Duration> {
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let mut total: u64 = 0;
+    let mut number = String::new();
+    for ch in value.chars() {
+        match ch {
+            '0'..='9' => number.push(ch),
+            'h' | 'm' | 's' => {
+                let amount: u64 = number.parse().ok()?;
+                number.clear();
+                let seconds = match ch {
+                    'h' => amount.checked_mul(3600)?,
+                    'm' => amount.checked_mul(60)?,
+                    _ => amount,
+                };
+                total = total.checked_add(seconds)?;
+            }
+            _ => return None,
+        }
+    }
+
+    if number.is_empty() { Some(Duration::from_secs(total)) } else { None }
 }
 
 /// Split markdown content on `---` separators
 ///
 /// Ignores `---` inside fenced code blocks to avoid incorrect slide splits
 fn split_slides(markdown: &str) -> Vec<String> {
-    let mut slides = Vec::new();
-    let mut current = String::new();
-    let mut in_code_block = false;
+    SlideSections::new(markdown).map(|(section, _)| section).collect()
+}
 
-    for line in markdown.lines() {
-        let trimmed = line.trim();
+/// Iterator over the markdown of each slide and the lines it spans, split lazily on `---` separators
+struct SlideSections<'a> {
+    lines: std::str::Lines<'a>,
+    /// Character and length of the fence that opened the current code block
+    open_fence: Option<(char, usize)>,
+    /// Number of lines consumed so far
+    line_number: usize,
+}
+
+impl<'a> SlideSections<'a> {
+    fn new(markdown: &'a str) -> Self {
+        Self { lines: markdown.lines(), open_fence: None, line_number: 0 }
+    }
+}
 
-        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-            in_code_block = !in_code_block;
+/// Open or close a code block in `open_fence` if `trimmed` is a fence line
+///
+/// Follows CommonMark: a block only closes on a bare fence of the same character that is at least as long as the
+/// opening one, so ```` ``` ```` inside a `~~~` block (or vice versa) is treated as content.
+fn track_fence(open_fence: &mut Option<(char, usize)>, trimmed: &str) {
+    let Some(fence) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') else {
+        return;
+    };
+    let len = trimmed.chars().take_while(|c| *c == fence).count();
+    if len < 3 {
+        return;
+    }
+
+    match *open_fence {
+        None => *open_fence = Some((fence, len)),
+        Some((open, open_len)) if open == fence && len >= open_len && trimmed[len..].trim().is_empty() => {
+            *open_fence = None
         }
+        Some(_) => {}
+    }
+}
 
-        if trimmed == "---" && !in_code_block {
-            if !current.trim().is_empty() {
-                slides.push(current);
-                current = String::new();
+impl Iterator for SlideSections<'_> {
+    type Item = (String, SourceSpan);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = String::new();
+        let mut span: Option<SourceSpan> = None;
+
+        for line in self.lines.by_ref() {
+            self.line_number += 1;
+            let trimmed = line.trim();
+
+            track_fence(&mut self.open_fence, trimmed);
+
+            if trimmed == "---" && self.open_fence.is_none() {
+                if let Some(span) = span {
+                    return Some((current, span));
+                }
+                current.clear();
+            } else {
+                current.push_str(line);
+                current.push('\n');
+                if !trimmed.is_empty() {
+                    let start_line = span.map_or(self.line_number, |span| span.start_line);
+                    span = Some(SourceSpan { start_line, end_line: self.line_number });
+                }
             }
-        } else {
-            current.push_str(line);
-            current.push('\n');
         }
-    }
 
-    if !current.trim().is_empty() {
-        slides.push(current);
+        span.map(|span| (current, span))
     }
+}
 
-    slides
+/// Map a native GFM alert kind to the matching [AdmonitionType]
+///
+/// Mirrors [AdmonitionType::from_str] so both alert paths produce the same blocks.
+fn admonition_type_from_alert(kind: BlockQuoteKind) -> AdmonitionType {
+    match kind {
+        BlockQuoteKind::Note => AdmonitionType::Note,
+        BlockQuoteKind::Tip => AdmonitionType::Tip,
+        BlockQuoteKind::Important => AdmonitionType::Important,
+        BlockQuoteKind::Warning | BlockQuoteKind::Caution => AdmonitionType::Warning,
+    }
 }
 
 /// Parse a single slide from markdown
-fn parse_slide(markdown: String) -> Result<Slide> {
-    let preprocessed = preprocess_admonitions(&markdown);
+fn parse_slide(markdown: String, parse_options: &ParseOptions) -> Result<Slide> {
+    let _span = tracing::debug_span!("parse_slide", bytes = markdown.len()).entered();
+    let (markdown, notes) = extract_notes(&markdown);
+    let markdown = expand_inline_footnotes(&markdown);
+    let preprocessed = preprocess_admonitions(&markdown, !parse_options.native_alerts);
     let mut options = Options::empty();
+    options.insert(Options::ENABLE_FOOTNOTES);
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+    if parse_options.math {
+        options.insert(Options::ENABLE_MATH);
+    }
+    if parse_options.native_alerts {
+        options.insert(Options::ENABLE_GFM);
+    }
     let parser = Parser::new_ext(&preprocessed, options);
     let mut blocks = Vec::new();
     let mut block_stack: Vec<BlockBuilder> = Vec::new();
     let mut current_style = TextStyle::default();
-
-    for event in parser {
+    let mut target_duration = None;
+    let mut bg_image = None;
+    let mut transition = None;
+    let mut classes: Vec<String> = Vec::new();
+    let mut heading_seen = false;
+    let mut column_widths = Vec::new();
+    let mut caption = None;
+    let mut column_alignments: Vec<Alignment> = Vec::new();
+    let mut list_marker: Option<ListMarkerStyle> = None;
+    let mut footnote_order: Vec<String> = Vec::new();
+    let mut footnote_definitions: Vec<(String, Vec<TextSpan>)> = Vec::new();
+    let mut open_footnote: Option<(String, usize)> = None;
+
+    for (event, range) in parser.into_offset_iter() {
         match event {
             Event::Start(tag) => match tag {
-                Tag::Heading { level, .. } => {
+                Tag::Heading { level, classes: heading_classes, .. } => {
+                    if !heading_seen {
+                        classes.extend(heading_classes.iter().map(|class| class.to_string()));
+                        heading_seen = true;
+                    }
                     block_stack.push(BlockBuilder::Heading { level: level as u8, spans: Vec::new() });
                 }
                 Tag::Paragraph => {
@@ -213,16 +694,29 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                 Tag::List(first) => {
                     block_stack.push(BlockBuilder::List {
                         ordered: first.is_some(),
+                        marker: list_marker.take().filter(|_| first.is_some()),
+                        delimiter: list_delimiter(&preprocessed[range.start..]),
                         items: Vec::new(),
                         current_item: Vec::new(),
+                        current_blocks: Vec::new(),
                         pending_nested: None,
                     });
                 }
-                Tag::BlockQuote(_) => {
+                Tag::BlockQuote(Some(kind)) => {
+                    let admonition_type = admonition_type_from_alert(kind);
+                    block_stack.push(BlockBuilder::Admonition {
+                        admonition_type,
+                        title: None,
+                        blocks: Vec::new(),
+                        custom: None,
+                        collapsed: false,
+                    });
+                }
+                Tag::BlockQuote(None) => {
                     block_stack.push(BlockBuilder::BlockQuote { blocks: Vec::new() });
                 }
                 Tag::Table(alignments) => {
-                    let converted_alignments = alignments
+                    let mut converted_alignments: Vec<Alignment> = alignments
                         .iter()
                         .map(|a| match a {
                             PulldownAlignment::None | PulldownAlignment::Left => Alignment::Left,
@@ -230,12 +724,17 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                             PulldownAlignment::Right => Alignment::Right,
                         })
                         .collect();
+                    for (alignment, directive) in converted_alignments.iter_mut().zip(column_alignments.drain(..)) {
+                        *alignment = directive;
+                    }
                     block_stack.push(BlockBuilder::Table {
                         headers: Vec::new(),
                         rows: Vec::new(),
                         current_row: Vec::new(),
                         current_cell: Vec::new(),
                         alignments: converted_alignments,
+                        widths: std::mem::take(&mut column_widths),
+                        caption: caption.take(),
                         in_header: false,
                     });
                 }
@@ -257,7 +756,14 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                     current_style.strikethrough = true;
                 }
                 Tag::Image { dest_url, .. } => {
-                    block_stack.push(BlockBuilder::Image { path: dest_url.to_string(), alt: String::new() });
+                    block_stack.push(BlockBuilder::Image {
+                        path: dest_url.to_string(),
+                        alt: String::new(),
+                        caption: caption.take(),
+                    });
+                }
+                Tag::FootnoteDefinition(label) => {
+                    open_footnote = Some((label.to_string(), blocks.len()));
                 }
                 _ => {}
             },
@@ -265,12 +771,7 @@ fn parse_slide(markdown: String) -> Result<Slide> {
             Event::End(tag_end) => match tag_end {
                 TagEnd::Heading(_) | TagEnd::Paragraph | TagEnd::CodeBlock => {
                     if let Some(builder) = block_stack.pop() {
-                        let block = builder.build();
-                        if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut() {
-                            adm_blocks.push(block);
-                        } else {
-                            blocks.push(block);
-                        }
+                        attach_block(&mut block_stack, &mut blocks, builder.build());
                     }
                 }
                 TagEnd::List(_) => {
@@ -281,32 +782,19 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                             if let Block::List(list) = block {
                                 *pending_nested = Some(list);
                             }
-                        } else if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut()
-                        {
-                            adm_blocks.push(block);
                         } else {
-                            blocks.push(block);
+                            attach_block(&mut block_stack, &mut blocks, block);
                         }
                     }
                 }
                 TagEnd::BlockQuote(_) => {
                     if let Some(builder) = block_stack.pop() {
-                        let block = builder.build();
-                        if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut() {
-                            adm_blocks.push(block);
-                        } else {
-                            blocks.push(block);
-                        }
+                        attach_block(&mut block_stack, &mut blocks, builder.build());
                     }
                 }
                 TagEnd::Table => {
                     if let Some(builder) = block_stack.pop() {
-                        let block = builder.build();
-                        if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut() {
-                            adm_blocks.push(block);
-                        } else {
-                            blocks.push(block);
-                        }
+                        attach_block(&mut block_stack, &mut blocks, builder.build());
                     }
                 }
                 TagEnd::TableHead => {
@@ -318,10 +806,10 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                     }
                 }
                 TagEnd::TableRow => {
-                    if let Some(BlockBuilder::Table { current_row, rows, .. }) = block_stack.last_mut() {
-                        if !current_row.is_empty() {
-                            rows.push(std::mem::take(current_row));
-                        }
+                    if let Some(BlockBuilder::Table { current_row, rows, .. }) = block_stack.last_mut()
+                        && !current_row.is_empty()
+                    {
+                        rows.push(std::mem::take(current_row));
                     }
                 }
                 TagEnd::TableCell => {
@@ -330,12 +818,13 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                     }
                 }
                 TagEnd::Item => {
-                    if let Some(BlockBuilder::List { current_item, items, pending_nested, .. }) = block_stack.last_mut()
+                    if let Some(BlockBuilder::List { current_item, current_blocks, items, pending_nested, .. }) =
+                        block_stack.last_mut()
+                        && (!current_item.is_empty() || !current_blocks.is_empty())
                     {
-                        if !current_item.is_empty() {
-                            let nested = pending_nested.take().map(Box::new);
-                            items.push(ListItem { spans: std::mem::take(current_item), nested });
-                        }
+                        let nested = pending_nested.take().map(Box::new);
+                        let blocks = std::mem::take(current_blocks);
+                        items.push(ListItem { spans: std::mem::take(current_item), nested, blocks });
                     }
                 }
                 TagEnd::Emphasis => {
@@ -349,12 +838,21 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                 }
                 TagEnd::Image => {
                     if let Some(builder) = block_stack.pop() {
-                        let block = builder.build();
-                        if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut() {
-                            adm_blocks.push(block);
-                        } else {
-                            blocks.push(block);
+                        attach_block(&mut block_stack, &mut blocks, builder.build());
+                    }
+                }
+                TagEnd::FootnoteDefinition => {
+                    if let Some((label, start)) = open_footnote.take() {
+                        let mut spans = Vec::new();
+                        for block in blocks.drain(start.min(blocks.len())..) {
+                            if let Block::Paragraph { spans: paragraph } = block {
+                                if !spans.is_empty() {
+                                    spans.push(TextSpan::plain(" "));
+                                }
+                                spans.extend(paragraph);
+                            }
                         }
+                        footnote_definitions.push((label, spans));
                     }
                 }
                 _ => {}
@@ -372,6 +870,25 @@ fn parse_slide(markdown: String) -> Result<Slide> {
                 }
             }
 
+            Event::InlineMath(math) | Event::DisplayMath(math) => {
+                if let Some(builder) = block_stack.last_mut() {
+                    builder.add_text(tex_to_unicode(&math), &current_style);
+                }
+            }
+
+            Event::FootnoteReference(label) => {
+                let number = match footnote_order.iter().position(|seen| *seen == *label) {
+                    Some(idx) => idx + 1,
+                    None => {
+                        footnote_order.push(label.to_string());
+                        footnote_order.len()
+                    }
+                };
+                if let Some(builder) = block_stack.last_mut() {
+                    builder.add_text(footnote_marker(number), &TextStyle::default());
+                }
+            }
+
             Event::SoftBreak | Event::HardBreak => {
                 if let Some(builder) = block_stack.last_mut() {
                     builder.add_text(" ".to_string(), &current_style);
@@ -383,37 +900,63 @@ fn parse_slide(markdown: String) -> Result<Slide> {
             }
 
             Event::Html(html) => {
-                if let Some((admonition_type, title)) = parse_admonition_html_start(&html) {
-                    block_stack.push(BlockBuilder::Admonition { admonition_type, title, blocks: Vec::new() });
+                if let Some(duration) = parse_time_directive(&html) {
+                    target_duration = Some(duration);
+                } else if let Some(path) = parse_bg_image_directive(&html) {
+                    bg_image = Some(path);
+                } else if let Some(direction) = parse_transition_directive(&html) {
+                    transition = Some(direction);
+                } else if let Some(directive) = parse_class_directive(&html) {
+                    classes.extend(directive);
+                } else if let Some(text) = parse_caption_directive(&html) {
+                    caption = Some(text);
+                } else if let Some(widths) = parse_cols_directive(&html) {
+                    column_widths = widths;
+                } else if let Some(alignments) = parse_align_directive(&html) {
+                    column_alignments = alignments;
+                } else if let Some(style) = parse_list_directive(&html) {
+                    list_marker = Some(style);
+                } else if is_pagebreak_directive(&html) {
+                    blocks.push(Block::PageBreak);
+                } else if let Some((admonition_type, title, custom, collapsed)) =
+                    parse_admonition_html_start(&html, &parse_options.custom_admonitions)
+                {
+                    block_stack.push(BlockBuilder::Admonition {
+                        admonition_type,
+                        title,
+                        blocks: Vec::new(),
+                        custom,
+                        collapsed,
+                    });
                 } else if html.trim().starts_with("</admonition>") {
                     if let Some(builder) = block_stack.pop() {
                         blocks.push(builder.build());
                     }
-                } else if !block_stack.is_empty() {
-                    if let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut() {
-                        let inner_markdown = html.to_string();
-                        let inner_options = Options::empty();
-                        let inner_parser = Parser::new_ext(&inner_markdown, inner_options);
-                        let mut inner_block_stack: Vec<BlockBuilder> = Vec::new();
-                        let inner_style = TextStyle::default();
-
-                        for inner_event in inner_parser {
-                            match inner_event {
-                                Event::Start(Tag::Paragraph) => {
-                                    inner_block_stack.push(BlockBuilder::Paragraph { spans: Vec::new() });
-                                }
-                                Event::Text(text) => {
-                                    if let Some(builder) = inner_block_stack.last_mut() {
-                                        builder.add_text(text.to_string(), &inner_style);
-                                    }
+                } else if !block_stack.is_empty()
+                    && let Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) = block_stack.last_mut()
+                {
+                    let inner_markdown = html.to_string();
+                    let inner_options = Options::empty();
+                    let inner_parser = Parser::new_ext(&inner_markdown, inner_options);
+                    let mut inner_block_stack: Vec<BlockBuilder> = Vec::new();
+                    let inner_style = TextStyle::default();
+
+                    for inner_event in inner_parser {
+                        match inner_event {
+                            Event::Start(Tag::Paragraph) => {
+                                inner_block_stack.push(BlockBuilder::Paragraph { spans: Vec::new() });
+                            }
+                            Event::Text(text) => {
+                                if let Some(builder) = inner_block_stack.last_mut() {
+                                    builder.add_text(text.to_string(), &inner_style);
                                 }
-                                Event::End(TagEnd::Paragraph) => {
-                                    if let Some(builder) = inner_block_stack.pop() {
-                                        adm_blocks.push(builder.build());
-                                    }
+                            }
+                            Event::End(TagEnd::Paragraph) => {
+                                if let Some(builder) = inner_block_stack.pop() {
+                                    adm_blocks.push(builder.build());
                                 }
-                                _ => {}
                             }
+                            _ => {}
                         }
                     }
                 }
@@ -423,7 +966,36 @@ fn parse_slide(markdown: String) -> Result<Slide> {
         }
     }
 
-    Ok(Slide::with_blocks(blocks))
+    let mut slide = Slide::with_blocks(blocks);
+    slide.target_duration = target_duration;
+    slide.bg_image = bg_image;
+    slide.transition = transition;
+    slide.classes = classes;
+    slide.notes = notes;
+    slide.footnotes = footnote_order
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, label)| {
+            let (_, spans) = footnote_definitions.iter().find(|(defined, _)| defined == label)?;
+            Some(Footnote { number: idx + 1, spans: spans.clone() })
+        })
+        .collect();
+    tracing::debug!(blocks = slide.blocks.len(), "parsed slide");
+    Ok(slide)
+}
+
+/// Add a finished block to the innermost open container, or to the slide when there is none
+///
+/// A list item's first paragraph becomes the item text, matching tight lists; later blocks sit under the item.
+fn attach_block(block_stack: &mut [BlockBuilder], blocks: &mut Vec<Block>, block: Block) {
+    match block_stack.last_mut() {
+        Some(BlockBuilder::Admonition { blocks: adm_blocks, .. }) => adm_blocks.push(block),
+        Some(BlockBuilder::List { current_item, current_blocks, .. }) => match block {
+            Block::Paragraph { spans } if current_item.is_empty() && current_blocks.is_empty() => *current_item = spans,
+            block => current_blocks.push(block),
+        },
+        _ => blocks.push(block),
+    }
 }
 
 /// Helper to build blocks while parsing
@@ -441,8 +1013,11 @@ enum BlockBuilder {
     },
     List {
         ordered: bool,
+        marker: Option<ListMarkerStyle>,
+        delimiter: ListDelimiter,
         items: Vec<ListItem>,
         current_item: Vec<TextSpan>,
+        current_blocks: Vec<Block>,
         pending_nested: Option<List>,
     },
     BlockQuote {
@@ -454,39 +1029,38 @@ enum BlockBuilder {
         current_row: Vec<Vec<TextSpan>>,
         current_cell: Vec<TextSpan>,
         alignments: Vec<Alignment>,
+        widths: Vec<ColumnWidth>,
+        caption: Option<String>,
         in_header: bool,
     },
     Admonition {
         admonition_type: AdmonitionType,
         title: Option<String>,
         blocks: Vec<Block>,
+        custom: Option<CustomAdmonition>,
+        collapsed: bool,
     },
     Image {
         path: String,
         alt: String,
+        caption: Option<String>,
     },
 }
 
 impl BlockBuilder {
     fn add_text(&mut self, text: String, current_style: &TextStyle) {
         match self {
-            Self::Heading { spans, .. } | Self::Paragraph { spans, .. } => {
-                if !text.is_empty() {
-                    spans.push(TextSpan { text, style: current_style.clone() });
-                }
+            Self::Heading { spans, .. } | Self::Paragraph { spans, .. } if !text.is_empty() => {
+                spans.push(TextSpan { text, style: current_style.clone() });
             }
             Self::Code { code, .. } => {
                 code.push_str(&text);
             }
-            Self::List { current_item, .. } => {
-                if !text.is_empty() {
-                    current_item.push(TextSpan { text, style: current_style.clone() });
-                }
+            Self::List { current_item, .. } if !text.is_empty() => {
+                current_item.push(TextSpan { text, style: current_style.clone() });
             }
-            Self::Table { current_cell, .. } => {
-                if !text.is_empty() {
-                    current_cell.push(TextSpan { text, style: current_style.clone() });
-                }
+            Self::Table { current_cell, .. } if !text.is_empty() => {
+                current_cell.push(TextSpan { text, style: current_style.clone() });
             }
             Self::Image { alt, .. } => {
                 alt.push_str(&text);
@@ -515,15 +1089,19 @@ impl BlockBuilder {
     fn build(self) -> Block {
         match self {
             Self::Heading { level, spans } => Block::Heading { level, spans },
-            Self::Paragraph { spans } => Block::Paragraph { spans },
+            Self::Paragraph { spans } => paragraph_or_callout(spans),
             Self::Code { language, code } => Block::Code(CodeBlock { language, code }),
-            Self::List { ordered, items, .. } => Block::List(List { ordered, items }),
+            Self::List { ordered, marker, delimiter, items, .. } => {
+                Block::List(List { ordered, items, marker, delimiter })
+            }
             Self::BlockQuote { blocks } => Block::BlockQuote { blocks },
-            Self::Table { headers, rows, alignments, .. } => Block::Table(Table { headers, rows, alignments }),
-            Self::Admonition { admonition_type, title, blocks } => {
-                Block::Admonition(Admonition { admonition_type, title, blocks })
+            Self::Table { headers, rows, alignments, widths, caption, .. } => {
+                Block::Table(Table { headers, rows, alignments, widths, caption })
+            }
+            Self::Admonition { admonition_type, title, blocks, custom, collapsed } => {
+                Block::Admonition(Admonition { admonition_type, title, blocks, custom, collapsed })
             }
-            Self::Image { path, alt } => Block::Image { path, alt },
+            Self::Image { path, alt, caption } => Block::Image { path, alt, caption },
         }
     }
 }
@@ -531,6 +1109,7 @@ impl BlockBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::metadata::ThemeSetting;
 
     #[test]
     fn split_slides_basic() {
@@ -555,6 +1134,17 @@ mod tests {
         assert_eq!(slides.len(), 1);
     }
 
+    #[test]
+    fn split_slides_matches_fence_character_and_length() {
+        let markdown = "# Slide\n\n~~~markdown\n```rust\n---\n```\n---\n~~~\n\nAfter";
+        let slides = split_slides(markdown);
+        assert_eq!(slides.len(), 1);
+        assert!(slides[0].contains("After"));
+
+        let markdown = "````\n```\n---\n````\n---\n# Two";
+        assert_eq!(split_slides(markdown).len(), 2);
+    }
+
     #[test]
     fn split_slides_ignores_separator_in_code_block() {
         let markdown = r#"# Slide 1
@@ -718,6 +1308,85 @@ Content after code block
         }
     }
 
+    #[test]
+    fn parse_list_item_with_code_block() {
+        let markdown = "- Install it:\n\n  ```sh\n  cargo install lantern\n  ```\n\n- Done";
+        let slides = parse_slides(markdown).unwrap();
+        assert_eq!(slides[0].blocks.len(), 1);
+
+        match &slides[0].blocks[0] {
+            Block::List(list) => {
+                assert_eq!(list.items.len(), 2);
+                assert_eq!(list.items[0].spans[0].text, "Install it:");
+                match &list.items[0].blocks[..] {
+                    [Block::Code(code)] => {
+                        assert_eq!(code.language.as_deref(), Some("sh"));
+                        assert!(code.code.contains("cargo install lantern"));
+                    }
+                    other => panic!("Expected a code block under the item, got {other:?}"),
+                }
+                assert_eq!(list.items[1].spans[0].text, "Done");
+                assert!(list.items[1].blocks.is_empty());
+            }
+            _ => panic!("Expected list"),
+        }
+    }
+
+    #[test]
+    fn parse_speaker_notes() {
+        let markdown = "# Title\n\nVisible\n\n::: notes\nSay hello.\nThen wave.\n:::\n\nAfter";
+        let slides = parse_slides(markdown).unwrap();
+
+        assert_eq!(slides[0].notes.as_deref(), Some("Say hello.\nThen wave."));
+        assert_eq!(slides[0].blocks.len(), 3);
+        assert!(
+            !slides[0]
+                .blocks
+                .iter()
+                .any(|block| matches!(block, Block::Admonition(_)))
+        );
+
+        let in_code = parse_slides("```md\n::: notes\nnot notes\n:::\n```").unwrap();
+        assert_eq!(in_code[0].notes, None);
+    }
+
+    #[test]
+    fn parse_inline_footnote() {
+        let slides = parse_slides("Adoption doubled^[Source: 2024 *survey*] last year.").unwrap();
+        let slide = &slides[0];
+
+        assert_eq!(slide.blocks.len(), 1);
+        match &slide.blocks[0] {
+            Block::Paragraph { spans } => {
+                let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+                assert_eq!(text, "Adoption doubled¹ last year.");
+            }
+            _ => panic!("Expected paragraph"),
+        }
+        assert_eq!(
+            slide.footnotes,
+            vec![Footnote { number: 1, spans: vec![TextSpan::plain("Source: 2024 "), TextSpan::italic("survey")] }]
+        );
+    }
+
+    #[test]
+    fn parse_footnotes_numbered_by_first_reference() {
+        let markdown = "First[^a], second^[Inline [nested] note], again[^a].\n\n`^[code]`\n\n[^a]: Reference note";
+        let slides = parse_slides(markdown).unwrap();
+        let slide = &slides[0];
+
+        let text: String = match &slide.blocks[0] {
+            Block::Paragraph { spans } => spans.iter().map(|span| span.text.as_str()).collect(),
+            _ => panic!("Expected paragraph"),
+        };
+        assert_eq!(text, "First¹, second², again¹.");
+        assert_eq!(slide.blocks.len(), 2);
+        assert_eq!(slide.footnotes.len(), 2);
+        assert_eq!(slide.footnotes[0].spans, vec![TextSpan::plain("Reference note")]);
+        assert_eq!(slide.footnotes[1].marker(), "²");
+        assert!(matches!(&slide.blocks[1], Block::Paragraph { spans } if spans[0].text == "^[code]"));
+    }
+
     #[test]
     fn parse_multiple_slides() {
         let markdown = "# Slide 1\nContent 1\n---\n# Slide 2\nContent 2";
@@ -738,8 +1407,8 @@ Content here
 More content"#;
 
         let (meta, slides) = parse_slides_with_meta(markdown).unwrap();
-        assert_eq!(meta.theme, "dark");
-        assert_eq!(meta.author, "Test Author");
+        assert_eq!(meta.theme, ThemeSetting::from("dark"));
+        assert_eq!(meta.author, vec!["Test Author"]);
         assert_eq!(slides.len(), 2);
     }
 
@@ -753,11 +1422,26 @@ author = "Jane Doe"
 Test content"#;
 
         let (meta, slides) = parse_slides_with_meta(markdown).unwrap();
-        assert_eq!(meta.theme, "monokai");
-        assert_eq!(meta.author, "Jane Doe");
+        assert_eq!(meta.theme, ThemeSetting::from("monokai"));
+        assert_eq!(meta.author, vec!["Jane Doe"]);
         assert_eq!(slides.len(), 1);
     }
 
+    #[test]
+    fn parse_with_overrides_reports_effective_theme() {
+        let markdown = "---\ntheme: dracula\nauthor: Ada\n---\n# Slide";
+
+        let overrides = MetaOverrides { theme: Some("nord".to_string()) };
+        let (meta, effective, slides) = parse_slides_with_overrides(markdown, &overrides).unwrap();
+        assert_eq!(meta.theme, ThemeSetting::from("dracula"));
+        assert_eq!(effective.theme, "nord");
+        assert_eq!(effective.authors, vec!["Ada"]);
+        assert_eq!(slides.len(), 1);
+
+        let (_, effective, _) = parse_slides_with_overrides(markdown, &MetaOverrides::default()).unwrap();
+        assert_eq!(effective.theme, "dracula");
+    }
+
     #[test]
     fn parse_without_metadata() {
         let markdown = "# Slide\nContent";
@@ -766,6 +1450,33 @@ Test content"#;
         assert_eq!(slides.len(), 1);
     }
 
+    #[test]
+    fn merge_decks_concatenates_slides_in_order() {
+        let first = parse_slides_with_meta("# One\n---\n# Two").unwrap();
+        let second = parse_slides_with_meta("# Three").unwrap();
+
+        let (_, slides) = merge_decks(vec![first, second]);
+        let titles: Vec<_> = slides.iter().filter_map(Slide::title).collect();
+        assert_eq!(titles, vec!["One", "Two", "Three"]);
+    }
+
+    #[test]
+    fn merge_decks_first_non_default_field_wins() {
+        let plain = parse_slides_with_meta("# Intro").unwrap();
+        let nord = parse_slides_with_meta("---\ntheme: nord\n---\n# A").unwrap();
+        let dracula = parse_slides_with_meta("---\ntheme: dracula\nshow_slide_number: true\n---\n# B").unwrap();
+
+        let (meta, slides) = merge_decks(vec![plain, nord, dracula]);
+        assert_eq!(meta.theme, ThemeSetting::from("nord"));
+        assert!(meta.show_slide_number);
+        assert_eq!(meta.author, Meta::default().author);
+        assert_eq!(slides.len(), 3);
+
+        let (meta, slides) = merge_decks(vec![]);
+        assert_eq!(meta, Meta::default());
+        assert!(slides.is_empty());
+    }
+
     #[test]
     fn parse_table() {
         let markdown = r#"| Name | Age |
@@ -828,7 +1539,7 @@ Test content"#;
     fn preprocess_github_admonition() {
         let markdown = r#"> [!NOTE]
 > This is a note"#;
-        let preprocessed = preprocess_admonitions(markdown);
+        let preprocessed = preprocess_admonitions(markdown, true);
         assert!(preprocessed.contains("<admonition"));
         assert!(preprocessed.contains("type=\"note\""));
         assert!(preprocessed.contains("</admonition>"));
@@ -876,6 +1587,29 @@ Test content"#;
         }
     }
 
+    #[test]
+    fn parse_github_admonition_fold_markers() {
+        let slides = parse_slides("> [!NOTE]-\n> Hidden until expanded").unwrap();
+        match &slides[0].blocks[0] {
+            Block::Admonition(admonition) => {
+                assert_eq!(admonition.admonition_type, AdmonitionType::Note);
+                assert!(admonition.collapsed);
+                assert_eq!(admonition.title, None);
+                assert_eq!(admonition.blocks.len(), 1);
+            }
+            _ => panic!("Expected admonition"),
+        }
+
+        let slides = parse_slides("> [!TIP]+ Open by default\n> Shown").unwrap();
+        match &slides[0].blocks[0] {
+            Block::Admonition(admonition) => {
+                assert!(!admonition.collapsed);
+                assert_eq!(admonition.title, Some("Open by default".to_string()));
+            }
+            _ => panic!("Expected admonition"),
+        }
+    }
+
     #[test]
     fn parse_fence_admonition() {
         let markdown = r#":::tip
@@ -939,7 +1673,7 @@ This is a helpful tip
         assert_eq!(slides.len(), 1);
 
         match &slides[0].blocks[0] {
-            Block::Image { path, alt } => {
+            Block::Image { path, alt, .. } => {
                 assert_eq!(path, "path/to/image.png");
                 assert_eq!(alt, "Test image");
             }
@@ -953,7 +1687,7 @@ This is a helpful tip
         let slides = parse_slides(markdown).unwrap();
 
         match &slides[0].blocks[0] {
-            Block::Image { path, alt } => {
+            Block::Image { path, alt, .. } => {
                 assert_eq!(path, "image.jpg");
                 assert_eq!(alt, "");
             }
@@ -967,7 +1701,7 @@ This is a helpful tip
         let slides = parse_slides(markdown).unwrap();
 
         match &slides[0].blocks[0] {
-            Block::Image { path, alt } => {
+            Block::Image { path, alt, .. } => {
                 assert_eq!(path, "/home/user/diagram.svg");
                 assert_eq!(alt, "Diagram");
             }
@@ -989,7 +1723,7 @@ This is a helpful tip
         assert_eq!(image_blocks.len(), 2);
 
         match image_blocks[0] {
-            Block::Image { path, alt } => {
+            Block::Image { path, alt, .. } => {
                 assert_eq!(path, "image1.png");
                 assert_eq!(alt, "First");
             }
@@ -997,11 +1731,396 @@ This is a helpful tip
         }
 
         match image_blocks[1] {
-            Block::Image { path, alt } => {
+            Block::Image { path, alt, .. } => {
                 assert_eq!(path, "image2.png");
                 assert_eq!(alt, "Second");
             }
             _ => panic!("Expected image block"),
         }
     }
+
+    #[test]
+    fn parse_native_alerts_map_to_admonitions() {
+        let options = ParseOptions { native_alerts: true, ..Default::default() };
+        let cases = [
+            ("NOTE", AdmonitionType::Note),
+            ("TIP", AdmonitionType::Tip),
+            ("IMPORTANT", AdmonitionType::Important),
+            ("WARNING", AdmonitionType::Warning),
+            ("CAUTION", AdmonitionType::Warning),
+        ];
+
+        for (marker, expected) in cases {
+            let markdown = format!("> [!{marker}]\n> Alert body");
+            let slides = parse_slides_with_options(&markdown, &options).unwrap();
+
+            match &slides[0].blocks[0] {
+                Block::Admonition(admonition) => {
+                    assert_eq!(admonition.admonition_type, expected);
+                    assert_eq!(admonition.title, None);
+                    assert_eq!(admonition.blocks.len(), 1);
+                }
+                other => panic!("Expected admonition for {marker}, got: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_native_alerts_keeps_fence_admonitions() {
+        let options = ParseOptions { native_alerts: true, ..Default::default() };
+        let markdown = ":::tip\nThis is a helpful tip\n:::";
+        let slides = parse_slides_with_options(markdown, &options).unwrap();
+
+        match &slides[0].blocks[0] {
+            Block::Admonition(admonition) => assert_eq!(admonition.admonition_type, AdmonitionType::Tip),
+            other => panic!("Expected admonition, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_native_alerts_leaves_plain_blockquotes() {
+        let options = ParseOptions { native_alerts: true, ..Default::default() };
+        let markdown = "> Just a quote\n> [!NOTE] mentioned inline";
+        let slides = parse_slides_with_options(markdown, &options).unwrap();
+
+        assert!(!slides[0].blocks.iter().any(|b| matches!(b, Block::Admonition(_))));
+        assert!(slides[0].blocks.iter().any(|b| matches!(b, Block::BlockQuote { .. })));
+    }
+
+    #[test]
+    fn parse_custom_admonition_from_meta() {
+        let markdown = r##"---
+admonitions:
+  glossary:
+    icon: "📖"
+    color: "#88aaff"
+    title: Glossary
+---
+:::glossary
+A term and its meaning
+:::"##;
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+
+        match &slides[0].blocks[0] {
+            Block::Admonition(admonition) => {
+                assert_eq!(admonition.admonition_type, AdmonitionType::Custom);
+                assert_eq!(admonition.blocks.len(), 1);
+                let custom = admonition.custom.as_ref().expect("custom presentation");
+                assert_eq!(custom.icon, "📖");
+                assert_eq!(custom.title.as_deref(), Some("Glossary"));
+            }
+            other => panic!("Expected admonition, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_admonition_without_config() {
+        assert!("glossary".parse::<AdmonitionType>().is_err());
+
+        let slides = parse_slides(":::glossary\nA term\n:::").unwrap();
+        assert!(!slides[0].blocks.iter().any(|b| matches!(b, Block::Admonition(_))));
+    }
+
+    #[test]
+    fn comment_directive_reads_named_comments_only() {
+        assert_eq!(comment_directive("  <!--  time: 90s  -->\n", "time:"), Some("90s"));
+        assert_eq!(comment_directive("<!-- pagebreak -->", "pagebreak"), Some(""));
+        assert_eq!(comment_directive("<!-- caption: x -->", "time:"), None);
+        assert_eq!(comment_directive("<div>time: 90s</div>", "time:"), None);
+    }
+
+    #[test]
+    fn parse_time_directive_sets_target_duration() {
+        let slides = parse_slides("# Intro\n\n<!-- time: 90s -->\n\nBody\n---\n# Next").unwrap();
+        assert_eq!(slides[0].target_duration, Some(Duration::from_secs(90)));
+        assert_eq!(slides[0].blocks.len(), 2);
+        assert_eq!(slides[1].target_duration, None);
+    }
+
+    #[test]
+    fn parse_bg_image_directive_sets_background() {
+        let slides = parse_slides("<!-- bg-image: images/brand.png -->\n\n# Section\n---\n# Next").unwrap();
+        assert_eq!(slides[0].bg_image.as_deref(), Some("images/brand.png"));
+        assert_eq!(slides[0].blocks.len(), 1);
+        assert_eq!(slides[1].bg_image, None);
+        assert_eq!(parse_bg_image_directive("<!-- bg-image: -->"), None);
+    }
+
+    #[test]
+    fn parse_slide_classes_from_heading_and_directive() {
+        let markdown = "# Title {.dark .center}\n\n## Later {.light}\n---\n<!-- class: .wide -->\n# Plain";
+        let slides = parse_slides(markdown).unwrap();
+        assert_eq!(slides[0].classes, vec!["dark", "center"]);
+        assert!(matches!(&slides[0].blocks[0], Block::Heading { spans, .. } if spans[0].text == "Title"));
+        assert_eq!(slides[1].classes, vec!["wide"]);
+        assert_eq!(parse_class_directive("<!-- class: -->"), None);
+    }
+
+    #[test]
+    fn parse_transition_directive_sets_direction() {
+        let slides = parse_slides("<!-- transition: up -->\n\n## Detail\n---\n# Next").unwrap();
+        assert_eq!(slides[0].transition, Some(TransitionDirection::Up));
+        assert_eq!(slides[0].transition_axis(), TransitionAxis::Vertical);
+        assert_eq!(slides[0].blocks.len(), 1);
+        assert_eq!(slides[1].transition, None);
+        assert_eq!(parse_transition_directive("<!-- transition: sideways -->"), None);
+    }
+
+    #[test]
+    fn parse_callout_paragraphs() {
+        let slides = parse_slides("!! Save early\n\n!!🚀 **Ship** it\n\n!!! Loud\n\n!!").unwrap();
+        let blocks = &slides[0].blocks;
+
+        assert_eq!(
+            blocks[0],
+            Block::Callout { icon: CALLOUT_ICON.to_string(), spans: vec![TextSpan::plain("Save early")] }
+        );
+        assert_eq!(
+            blocks[1],
+            Block::Callout { icon: "🚀".to_string(), spans: vec![TextSpan::bold("Ship"), TextSpan::plain(" it")] }
+        );
+        assert!(matches!(&blocks[2], Block::Paragraph { spans } if spans[0].text == "!!! Loud"));
+        assert!(matches!(&blocks[3], Block::Paragraph { .. }));
+        assert_eq!(slides[0].word_count(), 7);
+    }
+
+    #[test]
+    fn parse_pagebreak_directive() {
+        let slides = parse_slides("# Long\n\nFirst half\n\n<!-- pagebreak -->\n\nSecond half").unwrap();
+        assert_eq!(slides.len(), 1);
+        assert_eq!(slides[0].blocks.len(), 4);
+        assert_eq!(slides[0].blocks[2], Block::PageBreak);
+        assert_eq!(slides[0].pages().len(), 2);
+        assert!(!is_pagebreak_directive("<!-- pagebreaks -->"));
+    }
+
+    #[test]
+    fn parse_align_directive_sets_table_alignments() {
+        let markdown = concat!(
+            "<!-- align: L c Right -->\n| A | B | C |\n|:---:|---|---|\n| 1 | 2 | 3 |\n\n",
+            "| D | E |\n|---|--:|\n| 4 | 5 |"
+        );
+        let slides = parse_slides(markdown).unwrap();
+        let alignments: Vec<_> = slides[0]
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Table(table) => Some(table.alignments.clone()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            alignments,
+            vec![
+                vec![Alignment::Left, Alignment::Center, Alignment::Right],
+                vec![Alignment::Left, Alignment::Right]
+            ]
+        );
+        assert_eq!(parse_align_directive("<!-- align: L X -->"), None);
+        assert_eq!(parse_align_directive("<!-- align: -->"), None);
+    }
+
+    #[test]
+    fn parse_list_directive_sets_marker_style() {
+        let markdown = "<!-- list: upper-roman -->\n1. One\n2. Two\n\n<!-- list: a -->\n- Bullet\n\n1. Plain\n";
+        let slides = parse_slides(markdown).unwrap();
+        let markers: Vec<_> = slides[0]
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::List(list) => Some(list.marker),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(markers, vec![Some(ListMarkerStyle::UpperRoman), None, None]);
+        assert_eq!(
+            parse_list_directive("<!-- list: i -->"),
+            Some(ListMarkerStyle::LowerRoman)
+        );
+        assert_eq!(parse_list_directive("<!-- list: bogus -->"), None);
+    }
+
+    #[test]
+    fn parse_ordered_list_delimiter() {
+        let slides = parse_slides("1) One\n2) Two\n   1. Nested\n\ntext\n\n10. Ten\n").unwrap();
+        let lists: Vec<&List> = slides[0]
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::List(list) => Some(list),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists[0].delimiter, ListDelimiter::Paren);
+        assert_eq!(
+            lists[0].items[1].nested.as_ref().unwrap().delimiter,
+            ListDelimiter::Period
+        );
+        assert_eq!(lists[1].delimiter, ListDelimiter::Period);
+        assert_eq!(list_delimiter("  12) twelve"), ListDelimiter::Paren);
+        assert_eq!(list_delimiter("- bullet"), ListDelimiter::Period);
+    }
+
+    #[test]
+    fn parse_cols_directive_sets_table_widths() {
+        let markdown = concat!(
+            "<!-- cols: 20% 60% * 12 -->\n| A | B | C | D |\n|---|---|---|---|\n| 1 | 2 | 3 | 4 |\n\n",
+            "| E |\n|---|\n| 5 |"
+        );
+        let slides = parse_slides(markdown).unwrap();
+        let widths: Vec<_> = slides[0]
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Table(table) => Some(table.widths.clone()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            widths,
+            vec![
+                vec![
+                    ColumnWidth::Percent(20),
+                    ColumnWidth::Percent(60),
+                    ColumnWidth::Auto,
+                    ColumnWidth::Fixed(12)
+                ],
+                vec![],
+            ]
+        );
+        assert_eq!(parse_cols_directive("<!-- cols: 20% wide -->"), None);
+        assert_eq!(parse_cols_directive("<!-- cols: 120% -->"), None);
+        assert_eq!(parse_cols_directive("<!-- cols: -->"), None);
+    }
+
+    #[test]
+    fn parse_caption_directive_captures_onto_table_and_image() {
+        let markdown = concat!(
+            "<!-- caption: Quarterly revenue -->\n| Q | Revenue |\n|---|---|\n| 1 | 10 |\n\n",
+            "<!-- caption: System overview -->\n![Diagram](arch.png)\n\n",
+            "![Plain](plain.png)"
+        );
+        let slides = parse_slides(markdown).unwrap();
+        let captions: Vec<Option<&str>> = slides[0]
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Table(table) => Some(table.caption.as_deref()),
+                Block::Image { caption, .. } => Some(caption.as_deref()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(captions, vec![Some("Quarterly revenue"), Some("System overview"), None]);
+        assert_eq!(parse_caption_directive("<!-- caption:   -->"), None);
+    }
+
+    #[test]
+    fn parse_math_as_unicode() {
+        let markdown =
+            "---\nmath: true\n---\n# Math\n\nEnergy $E = mc^2$ and $\\frac{a}{b}$\n\n$$\\sum_{i=1}^{n} x_i$$";
+        let (_, slides) = parse_slides_with_meta(markdown).unwrap();
+        let text = |block: &Block| match block {
+            Block::Paragraph { spans } => spans.iter().map(|s| s.text.as_str()).collect::<String>(),
+            other => panic!("Expected paragraph, got {other:?}"),
+        };
+
+        assert_eq!(text(&slides[0].blocks[1]), "Energy E = mc² and \\frac{a}{b}");
+        assert_eq!(text(&slides[0].blocks[2]), "∑ᵢ₌₁ⁿ xᵢ");
+    }
+
+    #[test]
+    fn parse_dollars_literal_without_math() {
+        let slides = parse_slides("# Prices\n\nIt costs $5 and $10, or $x_i$ in `$HOME`").unwrap();
+        match &slides[0].blocks[1] {
+            Block::Paragraph { spans } => {
+                let text: String = spans.iter().map(|s| s.text.as_str()).collect();
+                assert_eq!(text, "It costs $5 and $10, or $x_i$ in $HOME");
+            }
+            other => panic!("Expected paragraph, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_duration_formats() {
+        assert_eq!(parse_duration("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1m30s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1h"), Some(Duration::from_secs(3600)));
+        assert_eq!(parse_duration("90x"), None);
+        assert_eq!(parse_duration("1m30"), None);
+        assert_eq!(parse_duration("99999999999999999h"), None);
+        assert_eq!(parse_duration("18446744073709551615s1s"), None);
+        assert_eq!(parse_time_directive("<!-- time: 99999999999999999h -->"), None);
+        assert_eq!(parse_time_directive("<!-- not a directive -->"), None);
+    }
+
+    #[test]
+    fn parse_slides_streaming_yields_every_slide() {
+        let markdown = "# One\n---\n# Two\n---\n# Three";
+        let slides: Vec<_> = parse_slides_streaming(markdown).collect::<Result<_>>().unwrap();
+        assert_eq!(slides.len(), 3);
+        assert_eq!(slides, parse_slides(markdown).unwrap());
+    }
+
+    #[test]
+    fn parse_slides_streaming_is_lazy() {
+        let markdown = "# One\n---\n# Two\n---\n# Three";
+        let split_count = std::cell::Cell::new(0);
+        let sections = SlideSections::new(markdown)
+            .inspect(|_| split_count.set(split_count.get() + 1))
+            .map(|(section, _)| section);
+
+        let first = parse_sections(sections, ParseOptions::default())
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(&first.blocks[0], Block::Heading { spans, .. } if spans[0].text == "One"));
+        assert_eq!(split_count.get(), 1);
+    }
+
+    #[derive(Clone, Default)]
+    struct CapturedLog(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLog {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parse_slides_emits_tracing_spans() {
+        let log = CapturedLog::default();
+        let writer = log.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            parse_slides("# One\n\nText\n\n---\n\n# Two").unwrap();
+        });
+
+        let output = String::from_utf8(log.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("parsed slides slides=2"));
+        assert_eq!(output.matches("parsed slide blocks=").count(), 2);
+        assert!(output.contains("parse_slides{bytes="));
+        assert!(
+            output
+                .lines()
+                .any(|line| line.contains("parse_slides") && line.contains("close time.busy"))
+        );
+    }
 }
@@ -1,46 +1,273 @@
+use crate::diff;
 use crate::highlighter;
-use crate::slide::{Block, CodeBlock, List, Table, TextSpan, TextStyle};
-use crate::theme::ThemeColors;
+use crate::slide::{
+    Alignment, Block, CodeBlock, ColumnWidth, Footnote, List, Table, TextSpan, TextStyle, image_label, slugify,
+};
+use crate::theme::{BlockquoteStyle, Theme};
+use crate::width::{display_width, graphemes};
 use owo_colors::OwoColorize;
-use unicode_width::UnicodeWidthChar;
+use std::collections::HashMap;
+
+/// Options controlling printed output beyond width and theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrintOptions {
+    /// Prefix each slide with a `[#slug]` marker derived from its title
+    pub anchors: bool,
+    /// Wrap slide content to at most this many columns, centered within the full width
+    ///
+    /// Separators between slides and rules within them are capped to the same width so the framing lines up.
+    pub content_max_width: Option<usize>,
+    /// Print code blocks in the plain code color without syntax highlighting
+    pub no_highlight: bool,
+    /// Print each slide's speaker notes in a dimmed block after its content
+    pub notes: bool,
+    /// Print only slide numbers, titles, and speaker notes, as a rehearsal script
+    pub notes_only: bool,
+}
+
+impl PrintOptions {
+    /// Columns available to slide content, rules, and separators for a total `width`
+    pub fn content_width(&self, width: usize) -> usize {
+        self.content_max_width.map_or(width, |cap| cap.clamp(1, width.max(1)))
+    }
+}
 
 /// Print slides to stdout with formatted output
 ///
 /// Renders slides as plain text with ANSI colors and width constraints.
 pub fn print_slides_to_stdout(
-    slides: &[crate::slide::Slide], theme: &ThemeColors, width: usize,
+    slides: &[crate::slide::Slide], theme: &Theme, width: usize, options: &PrintOptions,
 ) -> std::io::Result<()> {
-    let stdout = std::io::stdout();
-    let mut handle = stdout.lock();
-    print_slides(&mut handle, slides, theme, width)
+    let mut handle = std::io::BufWriter::new(std::io::stdout().lock());
+    print_slides_with_options(&mut handle, slides, theme, width, options)?;
+    std::io::Write::flush(&mut handle)
+}
+
+/// Print slides into an in-memory buffer, the same bytes [print_slides] writes
+///
+/// Handy for benchmarks and tests that want the output without terminal I/O.
+pub fn print_slides_to_vec(slides: &[crate::slide::Slide], theme: &Theme, width: usize) -> Vec<u8> {
+    let mut output = Vec::new();
+    print_slides(&mut output, slides, theme, width).expect("writing to a Vec cannot fail");
+    output
+}
+
+/// Body of the theme preview sample slide; one admonition per type is appended by [preview_theme]
+const PREVIEW_SAMPLE: &str = "# Theme Preview\n\n\
+Body text with **strong**, *emphasis*, `inline code` and a [link](https://example.com).\n\n\
+- First item\n- Second item\n  1. Nested item\n\n\
+```rust\nfn main() {\n    println!(\"Hello, lantern!\");\n}\n```\n\n\
+| Block | Color |\n| ----- | ----- |\n| Heading | base0D |\n| Code | base0B |\n\n";
+
+/// Admonition types reachable from markdown, shown in the theme preview
+const PREVIEW_ADMONITIONS: [&str; 14] = [
+    "NOTE",
+    "TIP",
+    "IMPORTANT",
+    "WARNING",
+    "DANGER",
+    "INFO",
+    "SUCCESS",
+    "QUESTION",
+    "EXAMPLE",
+    "QUOTE",
+    "ABSTRACT",
+    "TODO",
+    "BUG",
+    "FAILURE",
+];
+
+/// Render a fixed sample slide under `theme` for previewing it
+///
+/// The sample covers a heading, body text, a list, a code block, a table, and each admonition type, printed exactly
+/// as [print_slides] would print them.
+pub fn preview_theme(theme: &Theme, width: usize) -> String {
+    let mut markdown = PREVIEW_SAMPLE.to_string();
+    for kind in PREVIEW_ADMONITIONS {
+        markdown.push_str(&format!("> [!{kind}]\n> Admonition body text.\n\n"));
+    }
+
+    let slides = crate::parser::parse_slides(&markdown).expect("theme preview sample is valid markdown");
+    String::from_utf8_lossy(&print_slides_to_vec(&slides, theme, width)).into_owned()
 }
 
 /// Print slides to any writer with formatted output
 pub fn print_slides<W: std::io::Write>(
-    writer: &mut W, slides: &[crate::slide::Slide], theme: &ThemeColors, width: usize,
+    writer: &mut W, slides: &[crate::slide::Slide], theme: &Theme, width: usize,
 ) -> std::io::Result<()> {
+    print_slides_with_options(writer, slides, theme, width, &PrintOptions::default())
+}
+
+/// Print slides to any writer using the given [PrintOptions]
+pub fn print_slides_with_options<W: std::io::Write>(
+    writer: &mut W, slides: &[crate::slide::Slide], theme: &Theme, width: usize, options: &PrintOptions,
+) -> std::io::Result<()> {
+    if options.notes_only {
+        return print_notes_script(writer, slides, theme, options.content_width(width));
+    }
+
+    let theme = &theme
+        .clone()
+        .with_highlighting(theme.settings.highlight && !options.no_highlight);
+    let anchors = if options.anchors { slide_anchors(slides) } else { Vec::new() };
+    let content_width = options.content_width(width);
+    let margin = " ".repeat((width.saturating_sub(content_width)) / 2);
+
     for (idx, slide) in slides.iter().enumerate() {
         if idx > 0 {
             writeln!(writer)?;
-            let sep_text = "═".repeat(width);
+            let sep_text = "═".repeat(content_width);
             let separator = theme.rule(&sep_text);
-            writeln!(writer, "{separator}")?;
+            writeln!(writer, "{margin}{separator}")?;
             writeln!(writer)?;
         }
 
-        print_slide(writer, slide, theme, width)?;
+        if let Some(anchor) = anchors.get(idx) {
+            writeln!(writer, "{margin}{}", theme.dimmed(&format!("[#{anchor}]")))?;
+        }
+
+        let notes = slide.notes.as_deref().filter(|_| options.notes);
+
+        if margin.is_empty() {
+            print_slide(writer, slide, theme, content_width)?;
+            if let Some(notes) = notes {
+                print_notes(writer, notes, theme, content_width)?;
+            }
+        } else {
+            let mut rendered = Vec::new();
+            print_slide(&mut rendered, slide, theme, content_width)?;
+            if let Some(notes) = notes {
+                print_notes(&mut rendered, notes, theme, content_width)?;
+            }
+            for line in String::from_utf8_lossy(&rendered).lines() {
+                if line.is_empty() {
+                    writeln!(writer)?;
+                } else {
+                    writeln!(writer, "{margin}{line}")?;
+                }
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Unique anchor per slide from its title slug, falling back to `slide-N` for untitled slides
+///
+/// Repeated slugs get a numeric suffix (`intro`, `intro-2`, ...).
+fn slide_anchors(slides: &[crate::slide::Slide]) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    slides
+        .iter()
+        .enumerate()
+        .map(|(idx, slide)| {
+            let slug = slide
+                .title()
+                .map(|title| slugify(&title))
+                .filter(|slug| !slug.is_empty())
+                .unwrap_or_else(|| format!("slide-{}", idx + 1));
+
+            let count = seen.entry(slug.clone()).or_insert(0);
+            *count += 1;
+            if *count == 1 { slug } else { format!("{slug}-{count}") }
+        })
+        .collect()
+}
+
+/// Render a single slide to a string using the same formatting as [print_slides]
+///
+/// The result contains the ANSI color codes the printer would write to a terminal.
+pub fn render_slide_to_string(slide: &crate::slide::Slide, theme: &Theme, width: usize) -> String {
+    let mut output = Vec::new();
+    print_slide(&mut output, slide, theme, width).expect("writing to a Vec cannot fail");
+    String::from_utf8_lossy(&output).into_owned()
+}
+
 /// Print a single slide with formatted blocks
 fn print_slide<W: std::io::Write>(
-    writer: &mut W, slide: &crate::slide::Slide, theme: &ThemeColors, width: usize,
+    writer: &mut W, slide: &crate::slide::Slide, theme: &Theme, width: usize,
 ) -> std::io::Result<()> {
     for block in &slide.blocks {
         print_block(writer, block, theme, width, 0)?;
-        writeln!(writer)?;
+        if *block != Block::PageBreak {
+            writeln!(writer)?;
+        }
+    }
+
+    if !slide.footnotes.is_empty() {
+        print_footnotes(writer, &slide.footnotes, theme, width)?;
+    }
+
+    Ok(())
+}
+
+/// Print a slide's footnotes, dimmed, under a short rule with continuation lines aligned past the marker
+fn print_footnotes<W: std::io::Write>(
+    writer: &mut W, footnotes: &[Footnote], theme: &Theme, width: usize,
+) -> std::io::Result<()> {
+    writeln!(writer, "{}", theme.dimmed(&"─".repeat(width.min(12))))?;
+
+    for footnote in footnotes {
+        let marker = footnote.marker();
+        let indent = display_width(&marker) + 1;
+        for (idx, line) in wrap_spans(&footnote.spans, width.saturating_sub(indent))
+            .iter()
+            .enumerate()
+        {
+            let text: String = line.iter().map(|span| span.text.as_str()).collect();
+            let lead = if idx == 0 { format!("{marker} ") } else { " ".repeat(indent) };
+            writeln!(writer, "{}", theme.dimmed(&format!("{lead}{text}")))?;
+        }
+    }
+
+    writeln!(writer)
+}
+
+/// Print speaker notes as a dimmed block under a `Notes` label, wrapped to `width`
+fn print_notes<W: std::io::Write>(writer: &mut W, notes: &str, theme: &Theme, width: usize) -> std::io::Result<()> {
+    writeln!(writer, "{}", theme.dimmed(&"┄ Notes ┄"))?;
+
+    for paragraph in notes.lines() {
+        let lines = wrap_spans(&[TextSpan::plain(paragraph)], width.saturating_sub(2));
+        if lines.is_empty() {
+            writeln!(writer, "{}", theme.dimmed(&"│"))?;
+        }
+        for line in lines {
+            let text: String = line.iter().map(|span| span.text.as_str()).collect();
+            writeln!(writer, "{}", theme.dimmed(&format!("│ {text}")))?;
+        }
+    }
+
+    writeln!(writer)
+}
+
+/// Print each slide's number and title followed by its speaker notes, skipping slide content
+fn print_notes_script<W: std::io::Write>(
+    writer: &mut W, slides: &[crate::slide::Slide], theme: &Theme, width: usize,
+) -> std::io::Result<()> {
+    for (idx, slide) in slides.iter().enumerate() {
+        if idx > 0 {
+            writeln!(writer)?;
+        }
+
+        let title = slide.title().unwrap_or_else(|| "Untitled".to_string());
+        writeln!(writer, "{}", theme.heading(&format!("{}. {title}", idx + 1)))?;
+
+        let Some(notes) = slide.notes.as_deref() else {
+            writeln!(writer, "{}", theme.dimmed(&"(no notes)"))?;
+            continue;
+        };
+        for paragraph in notes.lines() {
+            let lines = wrap_spans(&[TextSpan::plain(paragraph)], width);
+            if lines.is_empty() {
+                writeln!(writer)?;
+            }
+            for line in lines {
+                let text: String = line.iter().map(|span| span.text.as_str()).collect();
+                writeln!(writer, "{}", theme.body(&text))?;
+            }
+        }
     }
 
     Ok(())
@@ -48,7 +275,7 @@ fn print_slide<W: std::io::Write>(
 
 /// Print a single block with appropriate formatting
 fn print_block<W: std::io::Write>(
-    writer: &mut W, block: &Block, theme: &ThemeColors, width: usize, indent: usize,
+    writer: &mut W, block: &Block, theme: &Theme, width: usize, indent: usize,
 ) -> std::io::Result<()> {
     match block {
         Block::Heading { level, spans } => {
@@ -61,12 +288,12 @@ fn print_block<W: std::io::Write>(
             print_code_block(writer, code, theme, width)?;
         }
         Block::List(list) => {
-            print_list(writer, list, theme, width, indent)?;
+            print_list(writer, list, theme, width, indent, 0)?;
         }
         Block::Rule => {
             let rule_text = "─".repeat(width.saturating_sub(indent));
             let rule = theme.rule(&rule_text);
-            writeln!(writer, "{}{}", " ".repeat(indent), rule)?;
+            writeln!(writer, "{:indent$}{rule}", "")?;
         }
         Block::BlockQuote { blocks } => {
             print_blockquote(writer, blocks, theme, width, indent)?;
@@ -77,8 +304,17 @@ fn print_block<W: std::io::Write>(
         Block::Admonition(admonition) => {
             print_admonition(writer, admonition, theme, width, indent)?;
         }
-        Block::Image { path, alt } => {
+        Block::Callout { icon, spans } => {
+            print_callout(writer, icon, spans, theme, width, indent)?;
+        }
+        Block::Image { path, alt, caption } => {
             print_image(writer, path, alt, theme, indent)?;
+            if let Some(caption) = caption {
+                print_caption(writer, caption, theme, width.saturating_sub(indent), indent)?;
+            }
+        }
+        Block::PageBreak => {
+            writeln!(writer, "\x0c")?;
         }
     }
 
@@ -87,17 +323,9 @@ fn print_block<W: std::io::Write>(
 
 /// Print a heading with level-appropriate styling using Unicode block symbols
 fn print_heading<W: std::io::Write>(
-    writer: &mut W, level: u8, spans: &[TextSpan], theme: &ThemeColors,
+    writer: &mut W, level: u8, spans: &[TextSpan], theme: &Theme,
 ) -> std::io::Result<()> {
-    let prefix = match level {
-        1 => "▉ ",
-        2 => "▓ ",
-        3 => "▒ ",
-        4 => "░ ",
-        _ => "▌ ",
-    };
-
-    write!(writer, "{}", theme.heading(&prefix))?;
+    write!(writer, "{}", theme.heading(&theme.heading_prefix(level)))?;
 
     for span in spans {
         print_span(writer, span, theme, true)?;
@@ -109,52 +337,112 @@ fn print_heading<W: std::io::Write>(
 
 /// Print a paragraph with word wrapping
 fn print_paragraph<W: std::io::Write>(
-    writer: &mut W, spans: &[TextSpan], theme: &ThemeColors, width: usize, indent: usize,
+    writer: &mut W, spans: &[TextSpan], theme: &Theme, width: usize, indent: usize,
 ) -> std::io::Result<()> {
     let indent_str = " ".repeat(indent);
     let effective_width = width.saturating_sub(indent);
 
-    let text = spans.iter().map(|s| s.text.as_str()).collect::<Vec<_>>().join("");
+    for line in wrap_spans(spans, effective_width) {
+        write!(writer, "{indent_str}")?;
+        for span in &line {
+            print_span(writer, span, theme, false)?;
+        }
+        writeln!(writer)?;
+    }
 
-    let words: Vec<&str> = text.split_whitespace().collect();
-    let mut current_line = String::new();
+    Ok(())
+}
 
-    for word in words {
-        if current_line.is_empty() {
-            current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= effective_width {
-            current_line.push(' ');
-            current_line.push_str(word);
-        } else {
-            write!(writer, "{indent_str}")?;
-            for span in spans {
-                if current_line.contains(&span.text) {
-                    print_span(writer, span, theme, false)?;
-                    break;
+/// Greedily wrap styled spans into lines no wider than `width` display columns
+///
+/// Words keep their styling, including words made of several adjacent spans; a word wider than `width`, such as a
+/// long URL, is hard-broken at the column boundary so no line overflows.
+fn wrap_spans(spans: &[TextSpan], width: usize) -> Vec<Vec<TextSpan>> {
+    let mut words: Vec<Vec<TextSpan>> = Vec::new();
+    let mut word: Vec<TextSpan> = Vec::new();
+
+    for span in spans {
+        for ch in span.text.chars() {
+            if ch.is_whitespace() {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
                 }
+            } else if let Some(last) = word.last_mut().filter(|last| last.style == span.style) {
+                last.text.push(ch);
+            } else {
+                word.push(TextSpan { text: ch.to_string(), style: span.style.clone() });
             }
-            if !spans.is_empty() && !current_line.is_empty() {
-                write!(writer, "{}", theme.body(&current_line))?;
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    let mut lines = Vec::new();
+    let mut line: Vec<TextSpan> = Vec::new();
+    let mut line_width = 0;
+
+    for mut word in words {
+        let mut word_width: usize = word.iter().map(|s| display_width(&s.text)).sum();
+
+        if word_width > width && width > 0 {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
             }
-            writeln!(writer)?;
-            current_line = word.to_string();
+            let mut chunks = split_word(&word, width);
+            word = chunks.pop().unwrap_or_default();
+            word_width = word.iter().map(|s| display_width(&s.text)).sum();
+            lines.extend(chunks);
+        }
+
+        if !line.is_empty() && line_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if !line.is_empty() {
+            line.push(TextSpan::plain(" "));
+            line_width += 1;
         }
+        line_width += word_width;
+        line.extend(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
     }
 
-    if !current_line.is_empty() {
-        write!(writer, "{indent_str}")?;
-        for span in spans {
-            print_span(writer, span, theme, false)?;
+    lines
+}
+
+/// Split a word into pieces of at most `width` display columns, keeping each grapheme whole and its style
+fn split_word(word: &[TextSpan], width: usize) -> Vec<Vec<TextSpan>> {
+    let mut chunks = Vec::new();
+    let mut chunk: Vec<TextSpan> = Vec::new();
+    let mut chunk_width = 0;
+
+    for span in word {
+        for (grapheme, grapheme_width) in graphemes(&span.text) {
+            if chunk_width + grapheme_width > width && !chunk.is_empty() {
+                chunks.push(std::mem::take(&mut chunk));
+                chunk_width = 0;
+            }
+            chunk_width += grapheme_width;
+            match chunk.last_mut().filter(|last| last.style == span.style) {
+                Some(last) => last.text.push_str(grapheme),
+                None => chunk.push(TextSpan { text: grapheme.to_string(), style: span.style.clone() }),
+            }
         }
-        writeln!(writer)?;
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
     }
 
-    Ok(())
+    chunks
 }
 
 /// Print a code block with syntax highlighting
 fn print_code_block<W: std::io::Write>(
-    writer: &mut W, code: &CodeBlock, theme: &ThemeColors, width: usize,
+    writer: &mut W, code: &CodeBlock, theme: &Theme, width: usize,
 ) -> std::io::Result<()> {
     if let Some(lang) = &code.language {
         writeln!(writer, "{}", theme.code_fence(&format!("```{lang}")))?;
@@ -162,7 +450,19 @@ fn print_code_block<W: std::io::Write>(
         writeln!(writer, "{}", theme.code_fence(&"```"))?;
     }
 
-    let highlighted_lines = highlighter::highlight_code(&code.code, code.language.as_deref(), theme);
+    if code.is_ansi() {
+        for line in code.code.lines() {
+            writeln!(writer, "{line}\x1b[0m")?;
+        }
+        writeln!(writer, "{}", theme.code_fence(&"```"))?;
+        return Ok(());
+    }
+
+    let highlighted_lines = if code.is_diff_split() {
+        diff::diff_split(&code.code).side_by_side(theme)
+    } else {
+        highlighter::highlight_code(&code.code, code.language.as_deref(), theme)
+    };
 
     for tokens in highlighted_lines {
         let mut line_length = 0;
@@ -170,7 +470,11 @@ fn print_code_block<W: std::io::Write>(
             if line_length + token.text.len() > width - 4 {
                 let remaining = (width - 4).saturating_sub(line_length);
                 if remaining > 0 {
-                    let trimmed = &token.text[..remaining.min(token.text.len())];
+                    let end = (0..=remaining.min(token.text.len()))
+                        .rev()
+                        .find(|&end| token.text.is_char_boundary(end))
+                        .unwrap_or(0);
+                    let trimmed = &token.text[..end];
                     write!(writer, "{}", token.color.to_owo_color(&trimmed))?;
                 }
                 break;
@@ -187,12 +491,12 @@ fn print_code_block<W: std::io::Write>(
 
 /// Print a list with bullets or numbers
 fn print_list<W: std::io::Write>(
-    writer: &mut W, list: &List, theme: &ThemeColors, _width: usize, indent: usize,
+    writer: &mut W, list: &List, theme: &Theme, width: usize, indent: usize, depth: usize,
 ) -> std::io::Result<()> {
     for (idx, item) in list.items.iter().enumerate() {
-        let marker = if list.ordered { format!("{}. ", idx + 1) } else { "• ".to_string() };
+        let marker = format!("{} ", list.item_marker(idx, depth));
 
-        write!(writer, "{}", " ".repeat(indent))?;
+        write!(writer, "{:indent$}", "")?;
         write!(writer, "{}", theme.list_marker(&marker))?;
 
         for span in &item.spans {
@@ -201,8 +505,17 @@ fn print_list<W: std::io::Write>(
 
         writeln!(writer)?;
 
+        let block_indent = " ".repeat(indent + 2);
+        for block in &item.blocks {
+            let mut output = Vec::new();
+            print_block(&mut output, block, theme, width.saturating_sub(indent + 2), 0)?;
+            for line in String::from_utf8_lossy(&output).lines() {
+                writeln!(writer, "{block_indent}{line}")?;
+            }
+        }
+
         if let Some(nested) = &item.nested {
-            print_list(writer, nested, theme, _width, indent + 2)?;
+            print_list(writer, nested, theme, width, indent + 2, depth + 1)?;
         }
     }
 
@@ -211,21 +524,39 @@ fn print_list<W: std::io::Write>(
 
 /// Print a blockquote with border
 fn print_blockquote<W: std::io::Write>(
-    writer: &mut W, blocks: &[Block], theme: &ThemeColors, width: usize, indent: usize,
+    writer: &mut W, blocks: &[Block], theme: &Theme, width: usize, indent: usize,
 ) -> std::io::Result<()> {
+    let tint = format!(
+        "\x1b[48;2;{};{};{}m",
+        theme.inline_code_bg.r, theme.inline_code_bg.g, theme.inline_code_bg.b
+    );
+    let prefix = match theme.settings.blockquote_style {
+        BlockquoteStyle::Border => theme.blockquote_border(&"│ ").to_string(),
+        BlockquoteStyle::Indent => "  ".to_string(),
+        BlockquoteStyle::Background => format!("{tint}  \x1b[0m"),
+    };
+
     for block in blocks {
         match block {
             Block::Paragraph { spans } => {
-                write!(writer, "{}", " ".repeat(indent))?;
-                write!(writer, "{}", theme.blockquote_border(&"│ "))?;
+                let mut line = Vec::new();
                 for span in spans {
-                    print_span(writer, span, theme, false)?;
+                    print_span(&mut line, span, theme, false)?;
+                }
+                let line = String::from_utf8_lossy(&line);
+
+                write!(writer, "{:indent$}", "")?;
+                if theme.settings.blockquote_style == BlockquoteStyle::Background {
+                    // Resets inside the line would end the tint early, so it's re-applied after each one
+                    let padding = width.saturating_sub(indent + 2 + visible_width(&line));
+                    let line = line.replace("\x1b[0m", &format!("\x1b[0m{tint}"));
+                    writeln!(writer, "{tint}  {line}{:padding$}\x1b[0m", "")?;
+                } else {
+                    writeln!(writer, "{prefix}{line}")?;
                 }
-                writeln!(writer)?;
             }
             _ => {
-                write!(writer, "{}", " ".repeat(indent))?;
-                write!(writer, "{}", theme.blockquote_border(&"│ "))?;
+                write!(writer, "{:indent$}{prefix}", "")?;
                 print_block(writer, block, theme, width, indent + 2)?;
             }
         }
@@ -236,28 +567,9 @@ fn print_blockquote<W: std::io::Write>(
 
 /// Print an admonition with icon, colored border, and title
 fn print_admonition<W: std::io::Write>(
-    writer: &mut W, admonition: &crate::slide::Admonition, theme: &ThemeColors, width: usize, indent: usize,
+    writer: &mut W, admonition: &crate::slide::Admonition, theme: &Theme, width: usize, indent: usize,
 ) -> std::io::Result<()> {
-    use crate::slide::AdmonitionType;
-
-    let (icon, color, default_title) = match admonition.admonition_type {
-        AdmonitionType::Note => ("\u{24D8}", &theme.admonition_note, "Note"),
-        AdmonitionType::Tip => ("\u{1F4A1}", &theme.admonition_tip, "Tip"),
-        AdmonitionType::Important => ("\u{2757}", &theme.admonition_tip, "Important"),
-        AdmonitionType::Warning => ("\u{26A0}", &theme.admonition_warning, "Warning"),
-        AdmonitionType::Caution => ("\u{26A0}", &theme.admonition_warning, "Caution"),
-        AdmonitionType::Danger => ("\u{26D4}", &theme.admonition_danger, "Danger"),
-        AdmonitionType::Error => ("\u{2717}", &theme.admonition_danger, "Error"),
-        AdmonitionType::Info => ("\u{24D8}", &theme.admonition_info, "Info"),
-        AdmonitionType::Success => ("\u{2713}", &theme.admonition_success, "Success"),
-        AdmonitionType::Question => ("?", &theme.admonition_info, "Question"),
-        AdmonitionType::Example => ("\u{25B8}", &theme.admonition_success, "Example"),
-        AdmonitionType::Quote => ("\u{201C}", &theme.admonition_info, "Quote"),
-        AdmonitionType::Abstract => ("\u{00A7}", &theme.admonition_note, "Abstract"),
-        AdmonitionType::Todo => ("\u{2610}", &theme.admonition_info, "Todo"),
-        AdmonitionType::Bug => ("\u{1F41B}", &theme.admonition_danger, "Bug"),
-        AdmonitionType::Failure => ("\u{2717}", &theme.admonition_danger, "Failure"),
-    };
+    let (icon, color, default_title) = admonition.presentation(theme);
 
     let title = admonition.title.as_deref().unwrap_or(default_title);
     let indent_str = " ".repeat(indent);
@@ -266,14 +578,17 @@ fn print_admonition<W: std::io::Write>(
     let top_border = "\u{256D}".to_string() + &"\u{2500}".repeat(box_width.saturating_sub(2)) + "\u{256E}";
     writeln!(writer, "{}{}", indent_str, color.to_owo_color(&top_border))?;
 
-    let icon_display_width = icon.chars().next().and_then(|c| c.width()).unwrap_or(1);
-
     write!(writer, "{}{} ", indent_str, color.to_owo_color(&"\u{2502}"))?;
     write!(writer, "{icon} ")?;
-    write!(writer, "{}", color.to_owo_color(&title).bold())?;
-
-    let title_padding = box_width.saturating_sub(4 + icon_display_width + 1 + title.len());
-    write!(writer, "{}", " ".repeat(title_padding))?;
+    let (title_fg, title_bg) = admonition.title_colors(theme);
+    let badge_style = owo_colors::Style::new()
+        .truecolor(title_fg.r, title_fg.g, title_fg.b)
+        .on_truecolor(title_bg.r, title_bg.g, title_bg.b)
+        .bold();
+    write!(writer, "{}", format!(" {title} ").style(badge_style))?;
+
+    let title_padding = box_width.saturating_sub(4 + display_width(icon).max(1) + 1 + display_width(title) + 2);
+    write!(writer, "{:title_padding$}", "")?;
     writeln!(writer, " {}", color.to_owo_color(&"\u{2502}"))?;
 
     if !admonition.blocks.is_empty() {
@@ -283,12 +598,18 @@ fn print_admonition<W: std::io::Write>(
         for block in &admonition.blocks {
             match block {
                 Block::Paragraph { spans } => {
-                    print_wrapped_admonition_paragraph(writer, spans, theme, color, &indent_str, box_width)?;
+                    print_wrapped_admonition_paragraph(writer, spans, theme, &color, &indent_str, box_width)?;
                 }
                 _ => {
-                    write!(writer, "{}{} ", indent_str, color.to_owo_color(&"\u{2502}"))?;
-                    print_block(writer, block, theme, box_width.saturating_sub(4), indent + 2)?;
-                    writeln!(writer, "{}", color.to_owo_color(&"\u{2502}"))?;
+                    let content_width = box_width.saturating_sub(4);
+                    let mut rendered = Vec::new();
+                    print_block(&mut rendered, block, theme, content_width, 0)?;
+
+                    let border = color.to_owo_color(&"\u{2502}");
+                    for line in String::from_utf8_lossy(&rendered).trim_end_matches('\n').lines() {
+                        let padding = content_width.saturating_sub(visible_width(line));
+                        writeln!(writer, "{indent_str}{border} {line}{:padding$} {border}", "")?;
+                    }
                 }
             }
         }
@@ -300,29 +621,66 @@ fn print_admonition<W: std::io::Write>(
     Ok(())
 }
 
+/// Terminal columns `line` occupies, skipping ANSI escape sequences
+fn visible_width(line: &str) -> usize {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            text.push(ch);
+        }
+    }
+    display_width(&text)
+}
+
 /// Print an image placeholder with path and alt text
 fn print_image<W: std::io::Write>(
-    writer: &mut W, path: &str, alt: &str, theme: &ThemeColors, indent: usize,
+    writer: &mut W, path: &str, alt: &str, theme: &Theme, indent: usize,
 ) -> std::io::Result<()> {
     let indent_str = " ".repeat(indent);
-    let icon = "\u{1F5BC}";
+    let marker = format!("[Image: {}]", image_label(path, alt));
 
-    write!(writer, "{indent_str}{}", theme.heading(&format!("{icon} Image: ")))?;
+    writeln!(writer, "{indent_str}{}", theme.heading(&marker))?;
+    writeln!(writer, "{}  Path: {}", indent_str, theme.body(&path))?;
 
-    if !alt.is_empty() {
-        writeln!(writer, "{}", theme.heading(&alt))?;
-    } else {
+    Ok(())
+}
+
+/// Print a callout as its icon followed by text tinted with the tip color, wrapping under the text
+fn print_callout<W: std::io::Write>(
+    writer: &mut W, icon: &str, spans: &[TextSpan], theme: &Theme, width: usize, indent: usize,
+) -> std::io::Result<()> {
+    let indent_str = " ".repeat(indent);
+    let icon_width = display_width(icon).max(1) + 1;
+    let color = &theme.admonition_tip;
+
+    for (idx, line) in wrap_spans(spans, width.saturating_sub(indent + icon_width))
+        .iter()
+        .enumerate()
+    {
+        if idx == 0 {
+            write!(writer, "{indent_str}{} ", color.to_owo_color(&icon))?;
+        } else {
+            write!(writer, "{indent_str}{:icon_width$}", "")?;
+        }
+        for span in line {
+            if span.style.code {
+                print_span(writer, span, theme, false)?;
+            } else {
+                write_text_style(writer, &color.to_owo_color(&span.text), &span.style)?;
+            }
+        }
         writeln!(writer)?;
     }
 
-    writeln!(writer, "{}  Path: {}", indent_str, theme.body(&path))?;
-
     Ok(())
 }
 
 /// Print a wrapped paragraph inside an admonition with proper text wrapping
 fn print_wrapped_admonition_paragraph<W: std::io::Write>(
-    writer: &mut W, spans: &[TextSpan], theme: &ThemeColors, border_color: &crate::theme::Color, indent_str: &str,
+    writer: &mut W, spans: &[TextSpan], theme: &Theme, border_color: &crate::theme::Color, indent_str: &str,
     box_width: usize,
 ) -> std::io::Result<()> {
     let text = spans.iter().map(|s| s.text.as_str()).collect::<Vec<_>>().join("");
@@ -334,15 +692,15 @@ fn print_wrapped_admonition_paragraph<W: std::io::Write>(
     for word in words {
         if current_line.is_empty() {
             current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= content_width {
+        } else if display_width(&current_line) + 1 + display_width(word) <= content_width {
             current_line.push(' ');
             current_line.push_str(word);
         } else {
             write!(writer, "{}{} ", indent_str, border_color.to_owo_color(&"\u{2502}"))?;
             write!(writer, "{}", theme.body(&current_line))?;
-            let padding = content_width.saturating_sub(current_line.len());
-            write!(writer, "{}", " ".repeat(padding))?;
-            writeln!(writer, "{}", border_color.to_owo_color(&"\u{2502}"))?;
+            let padding = content_width.saturating_sub(display_width(&current_line));
+            write!(writer, "{:padding$}", "")?;
+            writeln!(writer, " {}", border_color.to_owo_color(&"\u{2502}"))?;
             current_line = word.to_string();
         }
     }
@@ -350,9 +708,9 @@ fn print_wrapped_admonition_paragraph<W: std::io::Write>(
     if !current_line.is_empty() {
         write!(writer, "{}{} ", indent_str, border_color.to_owo_color(&"\u{2502}"))?;
         write!(writer, "{}", theme.body(&current_line))?;
-        let padding = content_width.saturating_sub(current_line.len());
-        write!(writer, "{}", " ".repeat(padding))?;
-        writeln!(writer, "{}", border_color.to_owo_color(&"\u{2502}"))?;
+        let padding = content_width.saturating_sub(display_width(&current_line));
+        write!(writer, "{:padding$}", "")?;
+        writeln!(writer, " {}", border_color.to_owo_color(&"\u{2502}"))?;
     }
 
     Ok(())
@@ -361,9 +719,7 @@ fn print_wrapped_admonition_paragraph<W: std::io::Write>(
 /// Print a table with borders and proper column width calculation
 ///
 /// Calculates column widths based on content and distributes available space
-fn print_table<W: std::io::Write>(
-    writer: &mut W, table: &Table, theme: &ThemeColors, width: usize,
-) -> std::io::Result<()> {
+fn print_table<W: std::io::Write>(writer: &mut W, table: &Table, theme: &Theme, width: usize) -> std::io::Result<()> {
     let col_count = table.headers.len();
     if col_count == 0 {
         return Ok(());
@@ -372,20 +728,36 @@ fn print_table<W: std::io::Write>(
     let col_widths = calculate_column_widths(table, width);
 
     if !table.headers.is_empty() {
-        print_table_row(writer, &table.headers, &col_widths, theme, true)?;
+        print_table_row(writer, &table.headers, &col_widths, &table.alignments, theme, true)?;
 
         let separator = build_table_separator(&col_widths);
         writeln!(writer, "{}", theme.table_border(&separator))?;
     }
 
     for row in &table.rows {
-        print_table_row(writer, row, &col_widths, theme, false)?;
+        print_table_row(writer, row, &col_widths, &table.alignments, theme, false)?;
+    }
+
+    if let Some(caption) = &table.caption {
+        let table_width = display_width(&build_table_separator(&col_widths));
+        print_caption(writer, caption, theme, table_width, 0)?;
     }
 
     Ok(())
 }
 
+/// Print a dimmed caption centered within `width` columns starting at `indent`
+fn print_caption<W: std::io::Write>(
+    writer: &mut W, caption: &str, theme: &Theme, width: usize, indent: usize,
+) -> std::io::Result<()> {
+    let padding = indent + width.saturating_sub(display_width(caption)) / 2;
+    writeln!(writer, "{:padding$}{}", "", theme.dimmed(&caption))
+}
+
 /// Calculate column widths based on content and available space
+///
+/// Columns with a width hint take it (percentages are of the width left after borders and padding); the remaining
+/// columns size to their content and shrink to fit whatever the hinted columns leave.
 fn calculate_column_widths(table: &Table, max_width: usize) -> Vec<usize> {
     let col_count = table.headers.len();
     if col_count == 0 {
@@ -395,14 +767,14 @@ fn calculate_column_widths(table: &Table, max_width: usize) -> Vec<usize> {
     let mut col_widths = vec![0; col_count];
 
     for (col_idx, header) in table.headers.iter().enumerate() {
-        let content_len: usize = header.iter().map(|s| s.text.len()).sum();
+        let content_len: usize = header.iter().map(|s| display_width(&s.text)).sum();
         col_widths[col_idx] = content_len.max(3);
     }
 
     for row in &table.rows {
         for (col_idx, cell) in row.iter().enumerate() {
             if col_idx < col_widths.len() {
-                let content_len = cell.iter().map(|s| s.text.len()).sum();
+                let content_len = cell.iter().map(|s| display_width(&s.text)).sum();
                 col_widths[col_idx] = col_widths[col_idx].max(content_len);
             }
         }
@@ -412,11 +784,29 @@ fn calculate_column_widths(table: &Table, max_width: usize) -> Vec<usize> {
     let padding_width = col_count * 2;
     let available_width = max_width.saturating_sub(separator_width + padding_width);
 
-    let total_content_width: usize = col_widths.iter().sum();
+    let mut hinted = vec![false; col_count];
+    let mut auto_available = available_width;
+    for (col_idx, hint) in table.widths.iter().enumerate().take(col_count) {
+        let width = match hint {
+            ColumnWidth::Percent(percent) => available_width * usize::from(*percent) / 100,
+            ColumnWidth::Fixed(width) => *width,
+            ColumnWidth::Auto => continue,
+        };
+        col_widths[col_idx] = width.max(3);
+        hinted[col_idx] = true;
+        auto_available = auto_available.saturating_sub(col_widths[col_idx]);
+    }
+
+    let total_content_width: usize = col_widths
+        .iter()
+        .zip(&hinted)
+        .filter(|(_, h)| !**h)
+        .map(|(w, _)| w)
+        .sum();
 
-    if total_content_width > available_width {
-        let scale_factor = available_width as f64 / total_content_width as f64;
-        for width in &mut col_widths {
+    if total_content_width > auto_available {
+        let scale_factor = auto_available as f64 / total_content_width as f64;
+        for (width, _) in col_widths.iter_mut().zip(&hinted).filter(|(_, h)| !**h) {
             *width = ((*width as f64 * scale_factor).ceil() as usize).max(3);
         }
     }
@@ -437,8 +827,11 @@ fn build_table_separator(col_widths: &[usize]) -> String {
 }
 
 /// Print a single table row with proper padding and alignment
+///
+/// Columns without an entry in `alignments` are left-aligned.
 fn print_table_row<W: std::io::Write>(
-    writer: &mut W, cells: &[Vec<TextSpan>], col_widths: &[usize], theme: &ThemeColors, is_header: bool,
+    writer: &mut W, cells: &[Vec<TextSpan>], col_widths: &[usize], alignments: &[Alignment], theme: &Theme,
+    is_header: bool,
 ) -> std::io::Result<()> {
     for (idx, cell) in cells.iter().enumerate() {
         if idx > 0 {
@@ -449,15 +842,18 @@ fn print_table_row<W: std::io::Write>(
 
         let col_width = col_widths.get(idx).copied().unwrap_or(10);
         let content: String = cell.iter().map(|s| s.text.as_str()).collect();
-        let content_len = content.len();
+        let padding = col_width.saturating_sub(display_width(&content));
+        let left = match alignments.get(idx) {
+            Some(Alignment::Right) => padding,
+            Some(Alignment::Center) => padding / 2,
+            Some(Alignment::Left) | None => 0,
+        };
 
+        write!(writer, "{:left$}", "")?;
         for span in cell {
             print_span(writer, span, theme, is_header)?;
         }
-
-        if content_len < col_width {
-            write!(writer, "{}", " ".repeat(col_width - content_len))?;
-        }
+        write!(writer, "{:1$}", "", padding - left)?;
 
         write!(writer, " ")?;
     }
@@ -467,50 +863,64 @@ fn print_table_row<W: std::io::Write>(
 }
 
 /// Print a text span with styling
+///
+/// Inline code keeps the code color inside headings and table headers.
 fn print_span<W: std::io::Write>(
-    writer: &mut W, span: &TextSpan, theme: &ThemeColors, is_heading: bool,
+    writer: &mut W, span: &TextSpan, theme: &Theme, is_heading: bool,
 ) -> std::io::Result<()> {
     let text = &span.text;
     let style = &span.style;
 
-    if is_heading {
-        write!(writer, "{}", apply_text_style(&theme.heading(text), style))?;
-    } else if style.code {
-        write!(writer, "{}", apply_text_style(&theme.code(text), style))?;
+    if style.code {
+        write_text_style(writer, &theme.code(text), style)
+    } else if is_heading {
+        write_text_style(writer, &theme.heading(text), style)
     } else {
-        write!(writer, "{}", apply_text_style(&theme.body(text), style))?;
+        write_text_style(writer, &theme.body(text), style)
     }
-
-    Ok(())
 }
 
-/// Apply text style modifiers to styled text
-fn apply_text_style<T: std::fmt::Display>(styled: &owo_colors::Styled<T>, text_style: &TextStyle) -> String {
-    let mut result = styled.to_string();
+/// Write styled text wrapped in the ANSI codes for its text style modifiers
+///
+/// Writes straight to `writer` rather than building an intermediate string per modifier.
+fn write_text_style<W: std::io::Write, T: std::fmt::Display>(
+    writer: &mut W, styled: &owo_colors::Styled<T>, text_style: &TextStyle,
+) -> std::io::Result<()> {
+    if text_style.strikethrough {
+        write!(writer, "\x1b[9m")?;
+    }
+    if text_style.italic {
+        write!(writer, "\x1b[3m")?;
+    }
+    if text_style.bold {
+        write!(writer, "\x1b[1m")?;
+    }
+
+    write!(writer, "{styled}")?;
 
     if text_style.bold {
-        result = format!("\x1b[1m{result}\x1b[22m");
+        write!(writer, "\x1b[22m")?;
     }
     if text_style.italic {
-        result = format!("\x1b[3m{result}\x1b[23m");
+        write!(writer, "\x1b[23m")?;
     }
     if text_style.strikethrough {
-        result = format!("\x1b[9m{result}\x1b[29m");
+        write!(writer, "\x1b[29m")?;
     }
 
-    result
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::slide::Slide;
-    use crate::slide::{Alignment, Table};
+    use crate::slide::{Alignment, ListDelimiter, Table};
 
     #[test]
     fn print_empty_slides() {
         let slides: Vec<Slide> = vec![];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &slides, &theme, 80);
@@ -518,13 +928,153 @@ mod tests {
         assert_eq!(output.len(), 0);
     }
 
+    #[test]
+    fn print_slides_with_anchors() {
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Heading {
+                level: 1,
+                spans: vec![TextSpan::plain("Getting Started!")],
+            }]),
+            Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain("No title")] }]),
+            Slide::with_blocks(vec![Block::Heading {
+                level: 2,
+                spans: vec![TextSpan::plain("Getting started")],
+            }]),
+        ];
+        let theme = Theme::test_default();
+
+        let mut output = Vec::new();
+        print_slides_with_options(
+            &mut output,
+            &slides,
+            &theme,
+            40,
+            &PrintOptions { anchors: true, ..Default::default() },
+        )
+        .unwrap();
+        let text = String::from_utf8_lossy(&output);
+        assert!(text.contains("[#getting-started]"));
+        assert!(text.contains("[#slide-2]"));
+        assert!(text.contains("[#getting-started-2]"));
+
+        let mut output = Vec::new();
+        print_slides(&mut output, &slides, &theme, 40).unwrap();
+        assert!(!String::from_utf8_lossy(&output).contains("[#"));
+    }
+
+    #[test]
+    fn print_heading_honors_custom_and_disabled_prefixes() {
+        let slides = vec![Slide::with_blocks(vec![
+            Block::Heading { level: 1, spans: vec![TextSpan::plain("Title")] },
+            Block::Heading { level: 3, spans: vec![TextSpan::plain("Deep")] },
+        ])];
+
+        let theme = Theme::test_default().with_heading_prefixes(Some(vec!["# ".into(), "## ".into()]));
+        let mut output = Vec::new();
+        print_slides(&mut output, &slides, &theme, 80).unwrap();
+        let text = String::from_utf8_lossy(&output);
+        assert!(text.contains("# "));
+        assert_eq!(text.matches("## ").count(), 1);
+        assert!(!text.contains('▉') && !text.contains('▒'));
+
+        let theme = Theme::test_default().with_heading_prefixes(Some(Vec::new()));
+        let mut output = Vec::new();
+        print_slides(&mut output, &slides, &theme, 80).unwrap();
+        let text = String::from_utf8_lossy(&output);
+        assert!(!text.contains('#') && !text.contains('▉') && !text.contains('▒'));
+        assert!(text.contains("Title") && text.contains("Deep"));
+    }
+
+    #[test]
+    fn print_slides_caps_content_width() {
+        let long = "word ".repeat(60);
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain(long.trim())] }]),
+            Slide::with_blocks(vec![Block::Rule]),
+        ];
+        let options = PrintOptions { content_max_width: Some(40), ..Default::default() };
+
+        let mut output = Vec::new();
+        print_slides_with_options(&mut output, &slides, &Theme::test_default(), 100, &options).unwrap();
+        let text = String::from_utf8_lossy(&output);
+        let plain = strip_ansi_codes(&text);
+
+        let separator = plain.lines().find(|l| l.contains('═')).expect("separator");
+        assert_eq!(separator.chars().filter(|c| *c == '═').count(), 40);
+        assert!(separator.starts_with(&" ".repeat(30)));
+
+        let rule = plain.lines().find(|l| l.contains('─')).expect("slide rule");
+        assert_eq!(rule.chars().filter(|c| *c == '─').count(), 40);
+        assert!(rule.starts_with(&" ".repeat(30)));
+
+        for line in plain.lines().filter(|l| l.contains("word")) {
+            assert!(line.starts_with(&" ".repeat(30)));
+            assert!(line.trim().chars().count() <= 40, "line exceeds cap: {line:?}");
+        }
+    }
+
+    #[test]
+    fn print_page_break_emits_form_feed() {
+        let slide = Slide::with_blocks(vec![
+            Block::Paragraph { spans: vec![TextSpan::plain("First half")] },
+            Block::PageBreak,
+            Block::Paragraph { spans: vec![TextSpan::plain("Second half")] },
+        ]);
+
+        let text = render_slide_to_string(&slide, &Theme::test_default(), 40);
+        let plain = strip_ansi_codes(&text);
+        let feed = plain.find('\x0c').expect("form feed");
+        assert!(plain.find("First half").unwrap() < feed);
+        assert!(feed < plain.find("Second half").unwrap());
+        assert_eq!(plain.matches('\x0c').count(), 1);
+    }
+
+    #[test]
+    fn print_rules_match_content_width() {
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Rule]),
+            Slide::with_blocks(vec![Block::Rule]),
+        ];
+
+        for (options, expected) in [
+            (PrintOptions::default(), 80),
+            (PrintOptions { content_max_width: Some(50), ..Default::default() }, 50),
+            (PrintOptions { content_max_width: Some(200), ..Default::default() }, 80),
+        ] {
+            assert_eq!(options.content_width(80), expected);
+
+            let mut output = Vec::new();
+            print_slides_with_options(&mut output, &slides, &Theme::test_default(), 80, &options).unwrap();
+            let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+
+            let separator = plain.lines().find(|l| l.contains('═')).expect("separator");
+            assert_eq!(separator.trim().chars().count(), expected);
+            for rule in plain.lines().filter(|l| l.contains('─')) {
+                assert_eq!(rule.trim().chars().count(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn print_image_shows_alt_marker() {
+        let slide = Slide::with_blocks(vec![
+            Block::Image { path: "img/arch.png".to_string(), alt: "System overview".to_string(), caption: None },
+            Block::Image { path: "img/chart.png".to_string(), alt: String::new(), caption: None },
+        ]);
+
+        let plain = strip_ansi_codes(&render_slide_to_string(&slide, &Theme::test_default(), 80));
+        assert!(plain.contains("[Image: System overview]"));
+        assert!(plain.contains("[Image: chart.png]"));
+        assert!(plain.contains("Path: img/chart.png"));
+    }
+
     #[test]
     fn print_single_heading() {
         let slide = Slide::with_blocks(vec![Block::Heading {
             level: 1,
             spans: vec![TextSpan::plain("Hello World")],
         }]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 80);
@@ -537,20 +1087,60 @@ mod tests {
     fn print_paragraph_with_wrapping() {
         let long_text = "This is a very long paragraph that should wrap when printed to stdout with a width constraint applied to ensure readability.";
         let slide = Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain(long_text)] }]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 40);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn wrap_spans_keeps_styles_across_lines() {
+        let spans = vec![
+            TextSpan::plain("alpha beta "),
+            TextSpan::bold("gamma"),
+            TextSpan::plain("s delta"),
+        ];
+        let lines = wrap_spans(&spans, 11);
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|l| l.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+
+        assert_eq!(texts, vec!["alpha beta", "gammas", "delta"]);
+        assert!(lines[1][0].style.bold);
+        assert!(!lines[1][1].style.bold);
+    }
+
+    #[test]
+    fn wrap_spans_hard_breaks_long_words() {
+        let token = "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMN";
+        let spans = vec![
+            TextSpan::plain("see "),
+            TextSpan::plain(token),
+            TextSpan::plain(" next"),
+        ];
+        let lines = wrap_spans(&spans, 20);
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|l| l.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+
+        assert_eq!(
+            texts,
+            vec!["see", "abcdefghijklmnopqrst", "uvwxyz0123456789ABCD", "EFGHIJKLMN next"]
+        );
+        assert!(texts.iter().all(|line| line.chars().count() <= 20));
+        assert_eq!(texts[1..].concat().replace(" next", ""), token);
+    }
+
     #[test]
     fn print_code_block() {
         let slide = Slide::with_blocks(vec![Block::Code(CodeBlock::with_language(
             "rust",
             "fn main() {\n    println!(\"Hello\");\n}",
         ))]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 80);
@@ -562,6 +1152,49 @@ mod tests {
         assert!(text.contains("println"));
     }
 
+    #[test]
+    fn print_diff_split_code_block_side_by_side() {
+        let code = "fn add(a: i32) {}\n---\nfn add(a: i64) {}\n// done";
+        let slide = Slide::with_blocks(vec![Block::Code(CodeBlock::with_language("diff-split", code))]);
+        let theme = Theme::test_default();
+        let mut output = Vec::new();
+
+        print_slides(&mut output, &[slide], &theme, 80).unwrap();
+
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        assert!(plain.contains("fn add(a: i32) {} │ fn add(a: i64) {}\n"));
+        assert!(plain.contains("                  │ // done\n"));
+    }
+
+    #[test]
+    fn print_ansi_code_block_passes_through_raw() {
+        let raw = "\x1b[31mred\x1b[0m plain \x1b[1;32mbold green\x1b[0m";
+        let slide = Slide::with_blocks(vec![Block::Code(CodeBlock::with_language("ansi", raw))]);
+        let theme = Theme::test_default();
+        let mut output = Vec::new();
+
+        print_slides(&mut output, &[slide], &theme, 80).unwrap();
+
+        let text = String::from_utf8_lossy(&output);
+        assert!(text.contains("```ansi"));
+        assert!(text.contains(raw));
+    }
+
+    #[test]
+    fn render_slide_to_string_matches_printer() {
+        let slide = Slide::with_blocks(vec![
+            Block::Heading { level: 1, spans: vec![TextSpan::plain("Preview Title")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("Body text")] },
+        ]);
+        let theme = Theme::test_default();
+        let mut output = Vec::new();
+        print_slides(&mut output, std::slice::from_ref(&slide), &theme, 60).unwrap();
+
+        let rendered = render_slide_to_string(&slide, &theme, 60);
+        assert_eq!(rendered, String::from_utf8_lossy(&output));
+        assert!(rendered.contains("Preview Title"));
+    }
+
     #[test]
     fn print_multiple_slides() {
         let slides = vec![
@@ -575,7 +1208,7 @@ mod tests {
             }]),
         ];
 
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
         let result = print_slides(&mut output, &slides, &theme, 80);
         assert!(result.is_ok());
@@ -606,10 +1239,12 @@ mod tests {
                 ],
             ],
             alignments: vec![Alignment::Left, Alignment::Left, Alignment::Left],
+            widths: vec![],
+            caption: None,
         };
 
         let slide = Slide::with_blocks(vec![Block::Table(table)]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 80);
@@ -634,6 +1269,8 @@ mod tests {
                 vec![vec![TextSpan::plain("Very Long Content")], vec![TextSpan::plain("X")]],
             ],
             alignments: vec![Alignment::Left, Alignment::Left],
+            widths: vec![],
+            caption: None,
         };
 
         let col_widths = calculate_column_widths(&table, 80);
@@ -645,16 +1282,54 @@ mod tests {
 
     #[test]
     fn print_table_empty_headers() {
-        let table = Table { headers: vec![], rows: vec![], alignments: vec![] };
+        let table = Table { headers: vec![], rows: vec![], alignments: vec![], widths: vec![], caption: None };
 
         let slide = Slide::with_blocks(vec![Block::Table(table)]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 80);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn print_table_applies_column_alignments() {
+        let cell = |text: &str| vec![TextSpan::plain(text)];
+        let table = Table {
+            headers: vec![cell("Name"), cell("Count"), cell("Notes")],
+            rows: vec![vec![cell("a"), cell("1"), cell("x")]],
+            alignments: vec![Alignment::Left, Alignment::Right, Alignment::Center],
+            widths: vec![],
+            caption: None,
+        };
+        let mut output = Vec::new();
+        print_table(&mut output, &table, &Theme::test_default(), 80).unwrap();
+
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        let row = plain.lines().last().expect("body row");
+        assert_eq!(row, " a     │     1  │   x   ");
+    }
+
+    #[test]
+    fn print_table_header_honors_inline_code_and_emphasis() {
+        let table = Table {
+            headers: vec![vec![TextSpan::code("len")], vec![TextSpan::italic("Note")]],
+            rows: vec![vec![vec![TextSpan::plain("3")], vec![TextSpan::plain("ok")]]],
+            alignments: vec![Alignment::Left, Alignment::Left],
+            widths: vec![],
+            caption: None,
+        };
+        let theme = Theme::test_default();
+        let mut output = Vec::new();
+
+        print_table_row(&mut output, &table.headers, &[5, 5], &[], &theme, true).unwrap();
+        let text = String::from_utf8_lossy(&output);
+
+        assert!(text.contains(&theme.code(&"len").to_string()));
+        assert!(!text.contains(&theme.heading(&"len").to_string()));
+        assert!(text.contains(&format!("\x1b[3m{}\x1b[23m", theme.heading(&"Note"))));
+    }
+
     #[test]
     fn calculate_column_widths_scales_to_fit() {
         let table = Table {
@@ -664,6 +1339,8 @@ mod tests {
             ],
             rows: vec![],
             alignments: vec![Alignment::Left, Alignment::Left],
+            widths: vec![],
+            caption: None,
         };
 
         let col_widths = calculate_column_widths(&table, 40);
@@ -672,6 +1349,73 @@ mod tests {
         assert!(total_width <= 40);
     }
 
+    #[test]
+    fn print_table_caption_appears_under_table() {
+        let cell = |text: &str| vec![TextSpan::plain(text)];
+        let table = Table {
+            headers: vec![cell("Quarter"), cell("Revenue")],
+            rows: vec![vec![cell("Q1"), cell("10")]],
+            alignments: vec![Alignment::Left; 2],
+            widths: vec![],
+            caption: Some("Revenue by quarter".to_string()),
+        };
+        let mut output = Vec::new();
+        print_table(&mut output, &table, &Theme::test_default(), 80).unwrap();
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        let lines: Vec<&str> = plain.lines().collect();
+
+        let last_row = lines.iter().rposition(|line| line.contains('│')).unwrap();
+        assert_eq!(lines.len(), last_row + 2);
+        let caption = lines[last_row + 1];
+        assert_eq!(caption.trim(), "Revenue by quarter");
+        let table_width = display_width(lines[0]);
+        let left = caption.len() - caption.trim_start().len();
+        assert!(
+            left.abs_diff((table_width - "Revenue by quarter".len()) / 2) <= 1,
+            "{plain:?}"
+        );
+    }
+
+    #[test]
+    fn calculate_column_widths_honors_hints() {
+        let cell = |text: &str| vec![TextSpan::plain(text)];
+        let table = Table {
+            headers: vec![cell("Key"), cell("Description"), cell("Default")],
+            rows: vec![vec![cell("a"), cell("b"), cell("c")]],
+            alignments: vec![Alignment::Left; 3],
+            widths: vec![
+                ColumnWidth::Percent(20),
+                ColumnWidth::Percent(60),
+                ColumnWidth::Percent(20),
+            ],
+            caption: None,
+        };
+
+        // 80 columns minus 2 separators (6) and padding (6) leaves 68
+        assert_eq!(calculate_column_widths(&table, 80), vec![13, 40, 13]);
+
+        let table = Table { widths: vec![ColumnWidth::Fixed(10), ColumnWidth::Auto], ..table };
+        assert_eq!(calculate_column_widths(&table, 80), vec![10, 11, 7]);
+
+        let slide = Slide::with_blocks(vec![Block::Table(Table {
+            widths: vec![
+                ColumnWidth::Percent(20),
+                ColumnWidth::Percent(60),
+                ColumnWidth::Percent(20),
+            ],
+            ..table
+        })]);
+        let mut output = Vec::new();
+        print_slides(&mut output, &[slide], &Theme::test_default(), 80).unwrap();
+        let text = String::from_utf8_lossy(&output);
+        let separator = text.lines().find(|line| line.contains("┼")).unwrap();
+        let segments: Vec<usize> = strip_ansi_codes(separator)
+            .split('┼')
+            .map(|s| s.chars().count())
+            .collect();
+        assert_eq!(segments, vec![16, 44, 16]);
+    }
+
     #[test]
     fn build_table_separator_correct_format() {
         let col_widths = vec![5, 10, 7];
@@ -681,6 +1425,21 @@ mod tests {
         assert!(separator.contains("─"));
     }
 
+    #[test]
+    fn print_callout_single_tinted_line() {
+        let theme = Theme::test_default();
+        let callout = Block::Callout { icon: "🚀".to_string(), spans: vec![TextSpan::plain("Ship it early")] };
+        let output = render_slide_to_string(&Slide::with_blocks(vec![callout.clone()]), &theme, 80);
+
+        assert_eq!(strip_ansi_codes(&output), "🚀 Ship it early\n\n");
+        let tip = theme.admonition_tip;
+        assert!(output.contains(&format!("\x1b[38;2;{};{};{}m", tip.r, tip.g, tip.b)));
+        assert!(!strip_ansi_codes(&output).contains('╭'));
+
+        let wrapped = strip_ansi_codes(&render_slide_to_string(&Slide::with_blocks(vec![callout]), &theme, 12));
+        assert_eq!(wrapped, "🚀 Ship it\n   early\n\n");
+    }
+
     #[test]
     fn print_admonition_with_wrapping() {
         use crate::slide::{Admonition, AdmonitionType};
@@ -693,10 +1452,12 @@ mod tests {
                     "Variables are immutable by default - use mut only when you need to change values",
                 )],
             }],
+            custom: None,
+            collapsed: false,
         };
 
         let slide = Slide::with_blocks(vec![Block::Admonition(admonition)]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 80);
@@ -712,6 +1473,46 @@ mod tests {
         assert!(text.contains("│"));
     }
 
+    #[test]
+    fn print_admonition_frames_nested_blocks() {
+        use crate::slide::{Admonition, AdmonitionType, ListItem};
+
+        let item = |text: &str| ListItem { spans: vec![TextSpan::plain(text)], nested: None, blocks: vec![] };
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Note,
+            title: None,
+            blocks: vec![
+                Block::Paragraph { spans: vec![TextSpan::plain("Before the list")] },
+                Block::List(List {
+                    ordered: false,
+                    items: vec![item("First"), item("Second"), item("Third")],
+                    marker: None,
+                    delimiter: ListDelimiter::Period,
+                }),
+                Block::Code(CodeBlock::with_language("rust", "let a = 1;\nlet b = 2;")),
+            ],
+            custom: None,
+            collapsed: false,
+        };
+
+        let slide = Slide::with_blocks(vec![Block::Admonition(admonition)]);
+        let output = strip_ansi_codes(&render_slide_to_string(&slide, &Theme::test_default(), 40));
+        let lines: Vec<&str> = output.lines().filter(|line| !line.is_empty()).collect();
+
+        assert!(lines.contains(&"│ • Second                             │"));
+        assert!(lines.contains(&"│ let b = 2;                           │"));
+        for line in &lines[1..lines.len() - 1] {
+            if line.starts_with('├') {
+                continue;
+            }
+            assert!(
+                line.starts_with("│ ") && line.ends_with(" │"),
+                "unframed line: {line:?}"
+            );
+            assert_eq!(display_width(line), 40, "misaligned line: {line:?}");
+        }
+    }
+
     #[test]
     fn print_admonition_border_length() {
         use crate::slide::{Admonition, AdmonitionType};
@@ -720,10 +1521,12 @@ mod tests {
             admonition_type: AdmonitionType::Note,
             title: None,
             blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("Test content")] }],
+            custom: None,
+            collapsed: false,
         };
 
         let slide = Slide::with_blocks(vec![Block::Admonition(admonition)]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let width = 60;
@@ -745,6 +1548,264 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wrap_and_pad_measure_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let accented = "Cafe\u{301}";
+
+        let lines = wrap_spans(&[TextSpan::plain(format!("{family} {accented} {family}"))], 9);
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+        assert_eq!(texts, vec![format!("{family} {accented}"), family.to_string()]);
+
+        let chunks = split_word(&[TextSpan::plain(format!("{family}{family}{accented}"))], 3);
+        let texts: Vec<String> = chunks
+            .iter()
+            .map(|chunk| chunk.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![family.to_string(), format!("{family}C"), "afe\u{301}".to_string()]
+        );
+
+        let table = Table {
+            headers: vec![vec![TextSpan::plain("Name")], vec![TextSpan::plain("Icon")]],
+            rows: vec![
+                vec![vec![TextSpan::plain(accented)], vec![TextSpan::plain(family)]],
+                vec![vec![TextSpan::plain("Plain")], vec![TextSpan::plain("ok")]],
+            ],
+            alignments: vec![Alignment::Left, Alignment::Right],
+            widths: vec![],
+            caption: None,
+        };
+        let mut output = Vec::new();
+        print_table(&mut output, &table, &Theme::test_default(), 40).unwrap();
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        let widths: Vec<usize> = plain
+            .lines()
+            .filter(|line| line.contains('│'))
+            .map(display_width)
+            .collect();
+        assert!(
+            widths.windows(2).all(|pair| pair[0] == pair[1]),
+            "ragged table: {plain}"
+        );
+        assert!(plain.contains(&format!(" {accented}   │   {family} ")), "{plain:?}");
+    }
+
+    #[test]
+    fn print_admonition_title_padding_uses_display_width() {
+        use crate::slide::{Admonition, AdmonitionType, CustomAdmonition};
+
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Note,
+            title: None,
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("Body")] }],
+            custom: Some(CustomAdmonition {
+                icon: "🚀".to_string(),
+                color: "#88aaff".to_string(),
+                title: Some("Launch 🎉 発射".to_string()),
+            }),
+            collapsed: false,
+        };
+
+        let slide = Slide::with_blocks(vec![Block::Admonition(admonition)]);
+        let mut output = Vec::new();
+        print_slides(&mut output, &[slide], &Theme::test_default(), 60).unwrap();
+
+        let text = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        let top = text.lines().find(|line| line.starts_with('╭')).expect("top border");
+        let title = text.lines().find(|line| line.contains("Launch")).expect("title line");
+
+        assert!(title.ends_with('│'));
+        assert_eq!(display_width(title), display_width(top));
+    }
+
+    #[test]
+    fn print_ordered_list_markers_follow_depth_and_directive() {
+        let slides = crate::parser::parse_slides("1. One\n   1. Sub\n      1. Deep\n   2. Sub two\n2. Two\n").unwrap();
+        let output = strip_ansi_codes(&render_slide_to_string(&slides[0], &Theme::test_default(), 60));
+        assert_eq!(output, "1. One\n  a. Sub\n    i. Deep\n  b. Sub two\n2. Two\n\n");
+
+        let slides = crate::parser::parse_slides("<!-- list: upper-alpha -->\n1. One\n2. Two\n").unwrap();
+        let output = strip_ansi_codes(&render_slide_to_string(&slides[0], &Theme::test_default(), 60));
+        assert_eq!(output, "A. One\nB. Two\n\n");
+    }
+
+    #[test]
+    fn print_ordered_list_keeps_paren_delimiter() {
+        let slides = crate::parser::parse_slides("1) One\n   1) Sub\n2) Two\n").unwrap();
+        let output = strip_ansi_codes(&render_slide_to_string(&slides[0], &Theme::test_default(), 60));
+        assert_eq!(output, "1) One\n  a) Sub\n2) Two\n\n");
+    }
+
+    #[test]
+    fn print_slide_lists_footnotes_after_content() {
+        let mut slide = Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain("Claim¹")] }]);
+        slide.footnotes = vec![Footnote { number: 1, spans: vec![TextSpan::plain("one two three four five")] }];
+
+        let plain = strip_ansi_codes(&render_slide_to_string(&slide, &Theme::test_default(), 14));
+        let lines: Vec<&str> = plain.lines().collect();
+
+        assert_eq!(
+            lines,
+            ["Claim¹", "", "────────────", "¹ one two", "  three four", "  five", ""]
+        );
+    }
+
+    #[test]
+    fn print_blockquote_styles() {
+        let slide = Slide::with_blocks(vec![Block::BlockQuote {
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("Quoted "), TextSpan::bold("text")] }],
+        }]);
+        let print = |style| {
+            let theme = Theme::test_default().with_blockquote_style(style);
+            render_slide_to_string(&slide, &theme, 20)
+        };
+
+        let border = print(BlockquoteStyle::Border);
+        assert!(strip_ansi_codes(&border).starts_with("│ Quoted text\n"));
+
+        let indent = print(BlockquoteStyle::Indent);
+        assert!(strip_ansi_codes(&indent).starts_with("  Quoted text\n"));
+        assert!(!indent.contains('│'));
+
+        let background = print(BlockquoteStyle::Background);
+        let bg = Theme::test_default().inline_code_bg;
+        let tint = format!("\x1b[48;2;{};{};{}m", bg.r, bg.g, bg.b);
+        assert!(background.starts_with(&format!("{tint}  ")));
+        assert!(strip_ansi_codes(&background).starts_with("  Quoted text       \n"));
+        assert!(!background.contains('│'));
+        let line = background.lines().next().unwrap();
+        assert!(line.matches("\x1b[0m").count() > 1);
+        assert!(
+            line.split("\x1b[0m")
+                .skip(1)
+                .filter(|rest| !rest.is_empty())
+                .all(|rest| rest.starts_with(&tint))
+        );
+    }
+
+    #[test]
+    fn print_notes_only_skips_slide_bodies() {
+        let markdown = concat!(
+            "# Opening\n\nBody paragraph one\n\n::: notes\nWelcome everyone\n\nMention the agenda\n:::\n\n---\n\n",
+            "Untitled body\n\n---\n\n## Wrap Up\n\nBody paragraph three\n\n::: notes\nThank the hosts\n:::\n"
+        );
+        let slides = crate::parser::parse_slides(markdown).unwrap();
+        let options = PrintOptions { notes_only: true, ..Default::default() };
+
+        let mut output = Vec::new();
+        print_slides_with_options(&mut output, &slides, &Theme::test_default(), 60, &options).unwrap();
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+
+        assert_eq!(
+            plain,
+            concat!(
+                "1. Opening\nWelcome everyone\n\nMention the agenda\n\n",
+                "2. Untitled\n(no notes)\n\n",
+                "3. Wrap Up\nThank the hosts\n"
+            )
+        );
+        assert!(!plain.contains("Body paragraph"));
+    }
+
+    #[test]
+    fn print_with_notes_adds_notes_under_slide() {
+        let mut with_notes = Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain("Body")] }]);
+        with_notes.notes = Some("Remember the demo".to_string());
+        let without_notes = Slide::with_blocks(vec![Block::Paragraph { spans: vec![TextSpan::plain("Second")] }]);
+        let slides = [with_notes, without_notes];
+        let theme = Theme::test_default();
+        let options = PrintOptions { notes: true, ..Default::default() };
+
+        let mut output = Vec::new();
+        print_slides_with_options(&mut output, &slides, &theme, 60, &options).unwrap();
+        let plain = strip_ansi_codes(&String::from_utf8_lossy(&output));
+        let (first, second) = plain.split_once('═').expect("separator");
+
+        let body = first.find("Body").unwrap();
+        let label = first.find("┄ Notes ┄").expect("notes label");
+        assert!(body < label);
+        assert!(first.contains("│ Remember the demo"));
+        assert!(!second.contains("Notes"));
+
+        let mut output = Vec::new();
+        print_slides(&mut output, &slides, &theme, 60).unwrap();
+        assert!(!String::from_utf8_lossy(&output).contains("Remember the demo"));
+    }
+
+    #[test]
+    fn print_slides_to_vec_matches_writer_output() {
+        let mut styled = TextSpan::plain("styled");
+        styled.style = TextStyle { bold: true, italic: true, strikethrough: true, ..Default::default() };
+        let slides = vec![
+            Slide::with_blocks(vec![
+                Block::Heading { level: 1, spans: vec![TextSpan::plain("Title")] },
+                Block::Paragraph { spans: vec![TextSpan::plain("Some "), styled] },
+            ]),
+            Slide::with_blocks(vec![Block::Code(CodeBlock::with_language("rust", "fn main() {}"))]),
+        ];
+        let theme = Theme::test_default();
+
+        let mut expected = Vec::new();
+        print_slides_with_options(&mut expected, &slides, &theme, 60, &PrintOptions::default()).unwrap();
+        let output = print_slides_to_vec(&slides, &theme, 60);
+
+        assert_eq!(output, expected);
+        assert!(String::from_utf8_lossy(&output).contains("\x1b[9m\x1b[3m\x1b[1m"));
+    }
+
+    #[test]
+    fn preview_theme_covers_every_block_kind() {
+        let theme = crate::theme::ThemeRegistry::get("catppuccin-mocha");
+        let preview = preview_theme(&theme, 80);
+        let plain = strip_ansi_codes(&preview);
+
+        assert!(plain.contains("Theme Preview"));
+        assert!(plain.contains("Body text with strong"));
+        assert!(plain.contains("• First item"));
+        assert!(plain.contains("println!(\"Hello, lantern!\");"));
+        assert!(plain.contains(" Heading  │ base0D"));
+        for title in [
+            "Note",
+            "Tip",
+            "Important",
+            "Warning",
+            "Danger",
+            "Info",
+            "Success",
+            "Question",
+            "Example",
+        ] {
+            assert!(plain.contains(&format!(" {title}")), "missing {title} admonition");
+        }
+        for title in ["Quote", "Abstract", "Todo", "Bug", "Failure"] {
+            assert!(plain.contains(&format!(" {title}")), "missing {title} admonition");
+        }
+
+        let heading = format!("\x1b[38;2;{};{};{}m", theme.heading.r, theme.heading.g, theme.heading.b);
+        assert!(preview.contains(&heading));
+    }
+
+    #[test]
+    fn print_slides_to_vec_handles_large_deck() {
+        let slide = Slide::with_blocks(vec![
+            Block::Heading { level: 2, spans: vec![TextSpan::plain("Section")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("lorem ipsum dolor sit amet ".repeat(20))] },
+            Block::Code(CodeBlock::with_language("rust", "let x = 1;\nlet y = x + 1;")),
+        ]);
+        let slides = vec![slide; 500];
+        let theme = Theme::test_default();
+
+        let output = print_slides_to_vec(&slides, &theme, 80);
+
+        let text = String::from_utf8_lossy(&output);
+        assert_eq!(text.matches("Section").count(), 500);
+    }
+
     fn strip_ansi_codes(s: &str) -> String {
         let mut result = String::new();
         let mut chars = s.chars().peekable();
@@ -777,10 +1838,12 @@ mod tests {
             admonition_type: AdmonitionType::Warning,
             title: Some("Warning".to_string()),
             blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain(long_text)] }],
+            custom: None,
+            collapsed: false,
         };
 
         let slide = Slide::with_blocks(vec![Block::Admonition(admonition)]);
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let mut output = Vec::new();
 
         let result = print_slides(&mut output, &[slide], &theme, 50);
@@ -0,0 +1,224 @@
+use crate::slide::{Admonition, Block, CodeBlock, Footnote, List, Slide, Table, TextSpan, image_label};
+
+/// Export slides as roff (man page) source
+///
+/// Level 1 headings become `.SH` sections and deeper ones `.SS` subsections, paragraphs become `.PP` text, list
+/// items `.IP` entries, and code blocks indented `.nf` literal blocks. Slides are not separated; the output reads
+/// as one document, titled by the first slide title.
+pub fn export_roff(slides: &[Slide]) -> String {
+    let title = slides
+        .iter()
+        .find_map(Slide::title)
+        .unwrap_or_else(|| "Slides".to_string());
+
+    let mut roff = format!(".TH \"{}\" 7\n", quoted(&title.to_uppercase()));
+    for slide in slides {
+        for block in &slide.blocks {
+            push_block(&mut roff, block);
+        }
+        push_footnotes(&mut roff, &slide.footnotes);
+    }
+    roff
+}
+
+fn push_block(roff: &mut String, block: &Block) {
+    match block {
+        Block::Heading { level, spans } => {
+            let macro_name = if *level <= 1 { ".SH" } else { ".SS" };
+            roff.push_str(&format!("{macro_name} \"{}\"\n", quoted(&plain_text(spans))));
+        }
+        Block::Paragraph { spans } if spans.is_empty() => {}
+        Block::Paragraph { spans } => {
+            roff.push_str(".PP\n");
+            push_text(roff, &spans_roff(spans));
+        }
+        Block::Code(code) => push_code(roff, code),
+        Block::List(list) => push_list(roff, list),
+        Block::Rule | Block::PageBreak => roff.push_str(".sp\n"),
+        Block::BlockQuote { blocks } => {
+            roff.push_str(".RS 4\n");
+            blocks.iter().for_each(|block| push_block(roff, block));
+            roff.push_str(".RE\n");
+        }
+        Block::Table(table) => push_table(roff, table),
+        Block::Admonition(admonition) => push_admonition(roff, admonition),
+        Block::Callout { icon, spans } => {
+            roff.push_str(".PP\n");
+            push_text(roff, &format!("{} {}", escape(icon), spans_roff(spans)));
+        }
+        Block::Image { path, alt, caption } => {
+            roff.push_str(".PP\n");
+            push_text(roff, &format!("[Image: {}]", escape(&image_label(path, alt))));
+            if let Some(caption) = caption {
+                push_text(roff, &format!("\\fI{}\\fR", escape(caption)));
+            }
+        }
+    }
+}
+
+fn push_footnotes(roff: &mut String, footnotes: &[Footnote]) {
+    for footnote in footnotes {
+        roff.push_str(&format!(".IP [{}] 4\n", footnote.number));
+        push_text(roff, &spans_roff(&footnote.spans));
+    }
+}
+
+fn push_code(roff: &mut String, code: &CodeBlock) {
+    roff.push_str(".PP\n.RS 4\n.nf\n");
+    for line in code.code.lines() {
+        push_text(roff, &escape(line));
+    }
+    roff.push_str(".fi\n.RE\n");
+}
+
+/// `.IP` entries tagged with each item's marker; nested lists are indented with `.RS`/`.RE`
+fn push_list(roff: &mut String, list: &List) {
+    push_list_at_depth(roff, list, 0);
+}
+
+fn push_list_at_depth(roff: &mut String, list: &List, depth: usize) {
+    for (idx, item) in list.items.iter().enumerate() {
+        let marker = if list.ordered { quoted(&list.item_marker(idx, depth)) } else { "\\(bu".to_string() };
+        roff.push_str(&format!(".IP \"{marker}\" 4\n"));
+        push_text(roff, &spans_roff(&item.spans));
+        item.blocks.iter().for_each(|block| push_block(roff, block));
+
+        if let Some(nested) = &item.nested {
+            roff.push_str(".RS 4\n");
+            push_list_at_depth(roff, nested, depth + 1);
+            roff.push_str(".RE\n");
+        }
+    }
+}
+
+/// Rows as tab-separated literal lines, header in bold, since plain roff has no table macros
+fn push_table(roff: &mut String, table: &Table) {
+    roff.push_str(".PP\n.nf\n");
+    if !table.headers.is_empty() {
+        let headers: Vec<String> = table
+            .headers
+            .iter()
+            .map(|cell| format!("\\fB{}\\fR", spans_roff(cell)))
+            .collect();
+        push_text(roff, &headers.join("\t"));
+    }
+    for row in &table.rows {
+        let cells: Vec<String> = row.iter().map(|cell| spans_roff(cell)).collect();
+        push_text(roff, &cells.join("\t"));
+    }
+    roff.push_str(".fi\n");
+    if let Some(caption) = &table.caption {
+        push_text(roff, &format!("\\fI{}\\fR", escape(caption)));
+    }
+}
+
+fn push_admonition(roff: &mut String, admonition: &Admonition) {
+    let title = admonition.title.as_deref().unwrap_or(admonition.default_title());
+
+    roff.push_str(".PP\n");
+    push_text(roff, &format!("\\fB{}\\fR", escape(title)));
+    roff.push_str(".RS 4\n");
+    admonition.blocks.iter().for_each(|block| push_block(roff, block));
+    roff.push_str(".RE\n");
+}
+
+/// Append escaped text, one output line per source line, guarding lines that would read as requests
+fn push_text(roff: &mut String, text: &str) {
+    for line in text.lines() {
+        if line.starts_with('.') || line.starts_with('\'') {
+            roff.push_str("\\&");
+        }
+        roff.push_str(line);
+        roff.push('\n');
+    }
+}
+
+/// Inline roff for a run of styled spans: bold and code in `\fB`, italic in `\fI`
+fn spans_roff(spans: &[TextSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| {
+            let text = escape(&span.text);
+            if span.style.bold || span.style.code {
+                format!("\\fB{text}\\fR")
+            } else if span.style.italic {
+                format!("\\fI{text}\\fR")
+            } else {
+                text
+            }
+        })
+        .collect()
+}
+
+fn plain_text(spans: &[TextSpan]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+/// Escape text for roff: backslashes become `\e` so they print literally
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\e")
+}
+
+/// Escape text for a double-quoted macro argument
+fn quoted(text: &str) -> String {
+    escape(text).replace('"', "\"\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slide::{ListDelimiter, ListItem};
+
+    fn item(text: &str) -> ListItem {
+        ListItem { spans: vec![TextSpan::plain(text)], nested: None, blocks: vec![] }
+    }
+
+    #[test]
+    fn export_maps_headings_and_lists_to_macros() {
+        let list = List {
+            ordered: false,
+            items: vec![item("Fast"), item("Small")],
+            marker: None,
+            delimiter: ListDelimiter::Period,
+        };
+        let slides = vec![Slide::with_blocks(vec![
+            Block::Heading { level: 1, spans: vec![TextSpan::plain("Intro")] },
+            Block::Heading { level: 2, spans: vec![TextSpan::plain("Details")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("Use "), TextSpan::bold("lantern")] },
+            Block::List(list),
+        ])];
+
+        let roff = export_roff(&slides);
+        assert!(roff.starts_with(".TH \"INTRO\" 7\n"));
+        assert!(roff.lines().any(|line| line == ".SH \"Intro\""));
+        assert!(roff.lines().any(|line| line == ".SS \"Details\""));
+        assert!(roff.contains(".PP\nUse \\fBlantern\\fR\n"));
+        assert!(roff.contains(".IP \"\\(bu\" 4\nFast\n.IP \"\\(bu\" 4\nSmall\n"));
+    }
+
+    #[test]
+    fn export_indents_code_as_literal_block() {
+        let code = CodeBlock::with_language("sh", ".hidden\nls \\\n  -la");
+        let roff = export_roff(&[Slide::with_blocks(vec![Block::Code(code)])]);
+
+        assert!(roff.contains(".RS 4\n.nf\n\\&.hidden\nls \\e\n  -la\n.fi\n.RE\n"));
+    }
+
+    #[test]
+    fn export_numbers_ordered_lists_with_nesting() {
+        let mut first = item("One");
+        first.nested = Some(Box::new(List {
+            ordered: true,
+            items: vec![item("Inner")],
+            marker: None,
+            delimiter: ListDelimiter::Paren,
+        }));
+        let list = List { ordered: true, items: vec![first], marker: None, delimiter: ListDelimiter::Period };
+
+        let roff = export_roff(&[Slide::with_blocks(vec![Block::List(list)])]);
+        assert!(
+            roff.contains(".IP \"1.\" 4\nOne\n.RS 4\n.IP \"a)\" 4\nInner\n.RE\n"),
+            "{roff}"
+        );
+    }
+}
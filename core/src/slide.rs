@@ -1,7 +1,10 @@
 use std::str::FromStr;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::theme::{Color, Theme, readable_fg};
+
 /// A single slide in a presentation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Slide {
@@ -9,20 +12,193 @@ pub struct Slide {
     pub blocks: Vec<Block>,
     /// Optional speaker notes (not displayed on main slide)
     pub notes: Option<String>,
+    /// Target speaking time from a `<!-- time: 90s -->` directive
+    pub target_duration: Option<Duration>,
+    /// Full-bleed background image from a `<!-- bg-image: path.png -->` directive
+    pub bg_image: Option<String>,
+    /// Footnotes referenced on the slide, in marker order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub footnotes: Vec<Footnote>,
+    /// Direction the slide enters from, from a `<!-- transition: up -->` directive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transition: Option<TransitionDirection>,
+    /// Classes from the first heading's `{.dark .center}` attributes or a `<!-- class: ... -->` directive
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<String>,
 }
 
 impl Slide {
     pub fn new() -> Self {
-        Self { blocks: Vec::new(), notes: None }
+        Self::with_blocks(Vec::new())
     }
 
     pub fn with_blocks(blocks: Vec<Block>) -> Self {
-        Self { blocks, notes: None }
+        Self {
+            blocks,
+            notes: None,
+            target_duration: None,
+            bg_image: None,
+            footnotes: Vec::new(),
+            transition: None,
+            classes: Vec::new(),
+        }
+    }
+
+    /// Axis to animate along when moving to this slide; slides without a hint move horizontally
+    pub fn transition_axis(&self) -> TransitionAxis {
+        self.transition.unwrap_or_default().axis()
+    }
+
+    /// Combined [SlideStyle] of the slide's classes (see [SLIDE_CLASSES]); the last class naming a theme wins
+    pub fn style(&self) -> SlideStyle {
+        self.classes
+            .iter()
+            .filter_map(|class| SLIDE_CLASSES.iter().find(|(name, _)| name == class))
+            .fold(SlideStyle::default(), |style, (_, class)| SlideStyle {
+                center: style.center || class.center,
+                theme: class.theme.or(style.theme),
+            })
     }
 
     pub fn is_empty(&self) -> bool {
         self.blocks.is_empty()
     }
+
+    /// Text of the first heading on the slide
+    pub fn title(&self) -> Option<String> {
+        self.first_heading().map(|(_, spans)| spans_text(spans))
+    }
+
+    /// Level of the first heading on the slide
+    pub fn title_level(&self) -> Option<u8> {
+        self.first_heading().map(|(level, _)| level)
+    }
+
+    fn first_heading(&self) -> Option<(u8, &[TextSpan])> {
+        self.blocks.iter().find_map(|block| match block {
+            Block::Heading { level, spans } => Some((*level, spans.as_slice())),
+            _ => None,
+        })
+    }
+
+    /// Number of prose words on the slide; code blocks and images are not counted
+    pub fn word_count(&self) -> usize {
+        self.blocks.iter().map(Block::word_count).sum()
+    }
+
+    /// Whether the slide is a section divider: a lone heading, optionally followed by a subtitle
+    ///
+    /// The subtitle may be a deeper heading or a single short paragraph of at most
+    /// [SECTION_SUBTITLE_MAX_WORDS] words. Anything else (lists, code, images, longer prose) makes it a content slide.
+    pub fn is_section_divider(&self) -> bool {
+        match self.blocks.as_slice() {
+            [Block::Heading { .. }] => true,
+            [Block::Heading { level, .. }, Block::Heading { level: sub_level, .. }] => sub_level > level,
+            [Block::Heading { .. }, Block::Paragraph { spans }] => {
+                (1..=SECTION_SUBTITLE_MAX_WORDS).contains(&count_words(spans))
+            }
+            _ => false,
+        }
+    }
+
+    /// Blocks of each page of the slide, split on [Block::PageBreak]
+    ///
+    /// A slide without page breaks has a single page holding all of its blocks.
+    pub fn pages(&self) -> Vec<&[Block]> {
+        self.blocks.split(|block| matches!(block, Block::PageBreak)).collect()
+    }
+
+    /// Code blocks on the slide in document order, including those nested in quotes, admonitions, and lists
+    pub fn code_blocks(&self) -> Vec<&CodeBlock> {
+        let mut code_blocks = Vec::new();
+        for block in &self.blocks {
+            block.collect_code(&mut code_blocks);
+        }
+        code_blocks
+    }
+
+    /// Image paths the slide references: its background image, then image blocks in document order
+    pub fn image_paths(&self) -> Vec<&str> {
+        let mut paths: Vec<&str> = self.bg_image.as_deref().into_iter().collect();
+        for block in &self.blocks {
+            block.collect_images(&mut paths);
+        }
+        paths
+    }
+
+    /// Whether any block on the slide, including blocks nested in quotes and admonitions, matches `pred`
+    pub fn contains_block(&self, pred: &impl Fn(&Block) -> bool) -> bool {
+        self.blocks.iter().any(|block| block.any(pred))
+    }
+}
+
+/// Longest paragraph, in words, still treated as a section divider's subtitle
+pub const SECTION_SUBTITLE_MAX_WORDS: usize = 12;
+
+/// Turn a title into a URL-style slug: lowercase words joined by dashes, punctuation dropped
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+
+    for ch in title.chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+        } else if (ch.is_whitespace() || ch == '-' || ch == '_') && !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+/// A footnote definition, numbered by the order of its first reference on the slide
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Footnote {
+    pub number: usize,
+    pub spans: Vec<TextSpan>,
+}
+
+impl Footnote {
+    /// Superscript marker shown at references and before the definition (`12` → `¹²`)
+    pub fn marker(&self) -> String {
+        footnote_marker(self.number)
+    }
+}
+
+/// Superscript digits for footnote `number`
+pub fn footnote_marker(number: usize) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    number
+        .to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .map(|digit| DIGITS[digit as usize])
+        .collect()
+}
+
+/// Accessible label for an image: its alt text, or the file name from `path` when the alt is empty
+///
+/// Every output path (print, HTML export) labels images through this so a missing alt still shows something.
+pub fn image_label(path: &str, alt: &str) -> String {
+    let alt = alt.trim();
+    if !alt.is_empty() {
+        return alt.to_string();
+    }
+
+    std::path::Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "image".to_string())
+}
+
+/// Indices of the slides containing a block that matches `pred`, searching nested blocks
+pub fn slides_with(slides: &[Slide], pred: impl Fn(&Block) -> bool) -> Vec<usize> {
+    slides
+        .iter()
+        .enumerate()
+        .filter(|(_, slide)| slide.contains_block(&pred))
+        .map(|(idx, _)| idx)
+        .collect()
 }
 
 impl Default for Slide {
@@ -50,8 +226,84 @@ pub enum Block {
     Table(Table),
     /// Admonition/alert box with type, optional title, and content
     Admonition(Admonition),
-    /// Image with path and alt text
-    Image { path: String, alt: String },
+    /// Lightweight aside from a paragraph starting with `!!`, shown as an icon and tinted text without a box
+    Callout { icon: String, spans: Vec<TextSpan> },
+    /// Image with path, alt text, and an optional caption from a `<!-- caption: ... -->` directive
+    Image {
+        path: String,
+        alt: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    /// Manual page break from a `<!-- pagebreak -->` directive
+    PageBreak,
+}
+
+impl Block {
+    /// Whether this block or any block nested inside it matches `pred`
+    pub fn any(&self, pred: &impl Fn(&Block) -> bool) -> bool {
+        if pred(self) {
+            return true;
+        }
+
+        match self {
+            Block::BlockQuote { blocks } => blocks.iter().any(|block| block.any(pred)),
+            Block::Admonition(admonition) => admonition.blocks.iter().any(|block| block.any(pred)),
+            Block::List(list) => list.any(pred),
+            _ => false,
+        }
+    }
+
+    fn collect_code<'a>(&'a self, out: &mut Vec<&'a CodeBlock>) {
+        match self {
+            Block::Code(code) => out.push(code),
+            Block::BlockQuote { blocks } => blocks.iter().for_each(|block| block.collect_code(out)),
+            Block::Admonition(admonition) => admonition.blocks.iter().for_each(|block| block.collect_code(out)),
+            Block::List(list) => list.collect_code(out),
+            _ => {}
+        }
+    }
+
+    fn collect_images<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Block::Image { path, .. } => out.push(path),
+            Block::BlockQuote { blocks } => blocks.iter().for_each(|block| block.collect_images(out)),
+            Block::Admonition(admonition) => admonition.blocks.iter().for_each(|block| block.collect_images(out)),
+            Block::List(list) => list.collect_images(out),
+            _ => {}
+        }
+    }
+
+    /// Number of prose words in this block and the blocks nested inside it
+    pub fn word_count(&self) -> usize {
+        match self {
+            Block::Heading { spans, .. } | Block::Paragraph { spans } | Block::Callout { spans, .. } => {
+                count_words(spans)
+            }
+            Block::List(list) => list.word_count(),
+            Block::BlockQuote { blocks } => blocks.iter().map(Block::word_count).sum(),
+            Block::Table(table) => table
+                .headers
+                .iter()
+                .chain(table.rows.iter().flatten())
+                .map(|cell| count_words(cell))
+                .sum(),
+            Block::Admonition(admonition) => {
+                let title = admonition.title.as_deref().map_or(0, |t| t.split_whitespace().count());
+                title + admonition.blocks.iter().map(Block::word_count).sum::<usize>()
+            }
+            Block::Code(_) | Block::Rule | Block::Image { .. } | Block::PageBreak => 0,
+        }
+    }
+}
+
+/// Concatenated text of a run of spans
+fn spans_text(spans: &[TextSpan]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+fn count_words(spans: &[TextSpan]) -> usize {
+    spans_text(spans).split_whitespace().count()
 }
 
 /// Styled text span within a block
@@ -105,6 +357,57 @@ impl CodeBlock {
     pub fn with_language(language: impl Into<String>, code: impl Into<String>) -> Self {
         Self { language: Some(language.into()), code: code.into() }
     }
+
+    /// File extension for the block's language, e.g. `rs` for `rust`; `txt` when unknown or unset
+    pub fn file_extension(&self) -> &str {
+        let Some(language) = self.language.as_deref().and_then(|info| info.split_whitespace().next()) else {
+            return "txt";
+        };
+
+        match language.to_lowercase().as_str() {
+            "rust" | "rs" => "rs",
+            "python" | "py" => "py",
+            "javascript" | "js" => "js",
+            "typescript" | "ts" => "ts",
+            "jsx" => "jsx",
+            "tsx" => "tsx",
+            "bash" | "sh" | "shell" | "zsh" => "sh",
+            "ruby" | "rb" => "rb",
+            "go" | "golang" => "go",
+            "c" => "c",
+            "cpp" | "c++" => "cpp",
+            "csharp" | "c#" | "cs" => "cs",
+            "java" => "java",
+            "kotlin" | "kt" => "kt",
+            "swift" => "swift",
+            "haskell" | "hs" => "hs",
+            "elixir" | "ex" => "ex",
+            "ocaml" | "ml" => "ml",
+            "lua" => "lua",
+            "sql" => "sql",
+            "html" => "html",
+            "css" => "css",
+            "json" => "json",
+            "yaml" | "yml" => "yml",
+            "toml" => "toml",
+            "markdown" | "md" => "md",
+            _ => "txt",
+        }
+    }
+
+    /// Whether this block holds raw ANSI output that bypasses syntax highlighting
+    pub fn is_ansi(&self) -> bool {
+        self.language
+            .as_deref()
+            .is_some_and(|lang| lang.eq_ignore_ascii_case("ansi"))
+    }
+
+    /// Whether this block is a `diff-split` fence, drawn as a before/after diff (see [crate::diff::diff_split])
+    pub fn is_diff_split(&self) -> bool {
+        self.language
+            .as_deref()
+            .is_some_and(|lang| lang.eq_ignore_ascii_case("diff-split"))
+    }
 }
 
 /// List (ordered or unordered)
@@ -112,6 +415,233 @@ impl CodeBlock {
 pub struct List {
     pub ordered: bool,
     pub items: Vec<ListItem>,
+    /// Numbering style from a `<!-- list: ... -->` directive; `None` picks one by nesting depth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<ListMarkerStyle>,
+    /// Punctuation written after each number of an ordered list
+    #[serde(default)]
+    pub delimiter: ListDelimiter,
+}
+
+impl List {
+    /// Marker for the item at `idx` of a list nested `depth` levels deep: `•`, or a number such as `ii.` or `2)`
+    pub fn item_marker(&self, idx: usize, depth: usize) -> String {
+        if !self.ordered {
+            return "•".to_string();
+        }
+        let style = self.marker.unwrap_or_else(|| ListMarkerStyle::for_depth(depth));
+        format!("{}{}", ordinal_marker(style, idx + 1), self.delimiter.as_char())
+    }
+
+    fn collect_code<'a>(&'a self, out: &mut Vec<&'a CodeBlock>) {
+        for item in &self.items {
+            item.blocks.iter().for_each(|block| block.collect_code(out));
+            if let Some(nested) = &item.nested {
+                nested.collect_code(out);
+            }
+        }
+    }
+
+    fn collect_images<'a>(&'a self, out: &mut Vec<&'a str>) {
+        for item in &self.items {
+            item.blocks.iter().for_each(|block| block.collect_images(out));
+            if let Some(nested) = &item.nested {
+                nested.collect_images(out);
+            }
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        self.items
+            .iter()
+            .map(|item| {
+                count_words(&item.spans)
+                    + item.blocks.iter().map(Block::word_count).sum::<usize>()
+                    + item.nested.as_ref().map_or(0, |nested| nested.word_count())
+            })
+            .sum()
+    }
+
+    fn any(&self, pred: &impl Fn(&Block) -> bool) -> bool {
+        self.items.iter().any(|item| {
+            item.blocks.iter().any(|block| block.any(pred))
+                || item.nested.as_ref().is_some_and(|nested| nested.any(pred))
+        })
+    }
+}
+
+/// What a slide class changes about how the slide is presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SlideStyle {
+    /// Center the content horizontally and vertically
+    pub center: bool,
+    /// Built-in theme to draw the slide with instead of the deck's
+    pub theme: Option<&'static str>,
+}
+
+/// Registry of slide classes; classes not listed here are kept on the slide but have no effect
+pub const SLIDE_CLASSES: &[(&str, SlideStyle)] = &[
+    ("center", SlideStyle { center: true, theme: None }),
+    ("dark", SlideStyle { center: false, theme: Some("oxocarbon-dark") }),
+    ("light", SlideStyle { center: false, theme: Some("oxocarbon-light") }),
+];
+
+/// Direction a slide enters from during a transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransitionDirection {
+    /// Next topic: the slide enters from the right, moving left
+    #[default]
+    Left,
+    Right,
+    /// Sub-topic: the slide enters from below, moving up
+    Up,
+    Down,
+}
+
+/// Axis a [TransitionDirection] animates along
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl TransitionDirection {
+    /// Parse a direction name, ignoring case
+    pub fn parse(hint: &str) -> Option<Self> {
+        match hint.to_lowercase().as_str() {
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            "up" => Some(Self::Up),
+            "down" => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    pub fn axis(self) -> TransitionAxis {
+        match self {
+            Self::Left | Self::Right => TransitionAxis::Horizontal,
+            Self::Up | Self::Down => TransitionAxis::Vertical,
+        }
+    }
+}
+
+/// Delimiter after an ordered list number: `1.` or `1)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListDelimiter {
+    #[default]
+    Period,
+    Paren,
+}
+
+impl ListDelimiter {
+    pub fn as_char(self) -> char {
+        match self {
+            Self::Period => '.',
+            Self::Paren => ')',
+        }
+    }
+}
+
+/// Numbering style for ordered list markers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListMarkerStyle {
+    /// `1`, `2`, `3`
+    Decimal,
+    /// `a`, `b`, ..., `z`, `aa`
+    LowerAlpha,
+    /// `A`, `B`, ..., `Z`, `AA`
+    UpperAlpha,
+    /// `i`, `ii`, `iii`, `iv`
+    LowerRoman,
+    /// `I`, `II`, `III`, `IV`
+    UpperRoman,
+}
+
+impl ListMarkerStyle {
+    /// Style for an ordered list without a directive: decimal, then lower-alpha, then lower-roman, repeating
+    pub fn for_depth(depth: usize) -> Self {
+        [Self::Decimal, Self::LowerAlpha, Self::LowerRoman][depth % 3]
+    }
+
+    /// Parse a style name (`lower-roman`) or its first marker (`i`), the latter case-sensitively
+    pub fn parse(hint: &str) -> Option<Self> {
+        match hint {
+            "1" => return Some(Self::Decimal),
+            "a" => return Some(Self::LowerAlpha),
+            "A" => return Some(Self::UpperAlpha),
+            "i" => return Some(Self::LowerRoman),
+            "I" => return Some(Self::UpperRoman),
+            _ => {}
+        }
+        match hint.to_lowercase().as_str() {
+            "decimal" => Some(Self::Decimal),
+            "lower-alpha" => Some(Self::LowerAlpha),
+            "upper-alpha" => Some(Self::UpperAlpha),
+            "lower-roman" => Some(Self::LowerRoman),
+            "upper-roman" => Some(Self::UpperRoman),
+            _ => None,
+        }
+    }
+}
+
+/// The `n`th (1-based) marker of an ordered list in `style`, without trailing punctuation
+///
+/// Alphabetic markers continue past `z` as `aa`, `ab`, ... Numbers roman numerals can't express (0 and above 3999)
+/// fall back to decimal.
+pub fn ordinal_marker(style: ListMarkerStyle, n: usize) -> String {
+    match style {
+        ListMarkerStyle::Decimal => n.to_string(),
+        ListMarkerStyle::LowerAlpha => alpha_marker(n),
+        ListMarkerStyle::UpperAlpha => alpha_marker(n).to_uppercase(),
+        ListMarkerStyle::LowerRoman => roman_marker(n),
+        ListMarkerStyle::UpperRoman => roman_marker(n).to_uppercase(),
+    }
+}
+
+fn alpha_marker(mut n: usize) -> String {
+    if n == 0 {
+        return n.to_string();
+    }
+    let mut letters = Vec::new();
+    while n > 0 {
+        n -= 1;
+        letters.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    letters.iter().rev().map(|&b| b as char).collect()
+}
+
+fn roman_marker(n: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+    if !(1..4000).contains(&n) {
+        return n.to_string();
+    }
+    let mut remaining = n;
+    let mut roman = String::new();
+    for (value, numeral) in NUMERALS {
+        while remaining >= value {
+            roman.push_str(numeral);
+            remaining -= value;
+        }
+    }
+    roman
 }
 
 /// Single list item that can contain blocks
@@ -119,6 +649,9 @@ pub struct List {
 pub struct ListItem {
     pub spans: Vec<TextSpan>,
     pub nested: Option<Box<List>>,
+    /// Block content under the item's text, such as code blocks or further paragraphs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
 }
 
 /// Table with headers and rows
@@ -127,6 +660,36 @@ pub struct Table {
     pub headers: Vec<Vec<TextSpan>>,
     pub rows: Vec<Vec<Vec<TextSpan>>>,
     pub alignments: Vec<Alignment>,
+    /// Per-column width hints from a `<!-- cols: ... -->` directive; missing columns size to their content
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub widths: Vec<ColumnWidth>,
+    /// Caption from a `<!-- caption: ... -->` directive, shown beneath the table
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// Requested width of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColumnWidth {
+    /// Size to the column's content (`*` or `auto`)
+    Auto,
+    /// Share of the table's available width (`20%`)
+    Percent(u8),
+    /// Exact number of columns (`12`)
+    Fixed(usize),
+}
+
+impl ColumnWidth {
+    /// Parse one hint: `20%`, `12`, `*` or `auto`
+    pub fn parse(hint: &str) -> Option<Self> {
+        match hint {
+            "*" | "auto" => Some(Self::Auto),
+            _ => match hint.strip_suffix('%') {
+                Some(percent) => percent.parse().ok().filter(|p| *p <= 100).map(Self::Percent),
+                None => hint.parse().ok().map(Self::Fixed),
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -136,6 +699,18 @@ pub enum Alignment {
     Right,
 }
 
+impl Alignment {
+    /// Parse one column alignment: `L`, `C` or `R`, or the full word, in any case
+    pub fn parse(hint: &str) -> Option<Self> {
+        match hint.to_lowercase().as_str() {
+            "l" | "left" => Some(Self::Left),
+            "c" | "center" => Some(Self::Center),
+            "r" | "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
 /// Admonition type determines styling and icon
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -156,6 +731,79 @@ pub enum AdmonitionType {
     Todo,
     Bug,
     Failure,
+    /// User-defined type configured through the frontmatter `admonitions` map
+    Custom,
+}
+
+impl AdmonitionType {
+    /// Icon, theme color, and default title used when rendering this admonition type
+    ///
+    /// Shared by the printer and the TUI renderer so both present admonitions identically. The icon comes from
+    /// [AdmonitionType::ascii_icon] when the theme has `ascii_icons` set.
+    pub fn presentation<'a>(&self, theme: &'a Theme) -> (&'static str, &'a Color, &'static str) {
+        let (icon, color) = match self {
+            Self::Note => ("\u{24D8}", &theme.admonition_note),
+            Self::Tip => ("\u{1F4A1}", &theme.admonition_tip),
+            Self::Important => ("\u{2757}", &theme.admonition_tip),
+            Self::Warning => ("\u{26A0}", &theme.admonition_warning),
+            Self::Caution => ("\u{26A0}", &theme.admonition_warning),
+            Self::Danger => ("\u{26D4}", &theme.admonition_danger),
+            Self::Error => ("\u{2717}", &theme.admonition_danger),
+            Self::Info => ("\u{24D8}", &theme.admonition_info),
+            Self::Success => ("\u{2713}", &theme.admonition_success),
+            Self::Question => ("?", &theme.admonition_info),
+            Self::Example => ("\u{25B8}", &theme.admonition_success),
+            Self::Quote => ("\u{201C}", &theme.admonition_info),
+            Self::Abstract => ("\u{00A7}", &theme.admonition_note),
+            Self::Todo => ("\u{2610}", &theme.admonition_info),
+            Self::Bug => ("\u{1F41B}", &theme.admonition_danger),
+            Self::Failure => ("\u{2717}", &theme.admonition_danger),
+            Self::Custom => ("\u{25C6}", &theme.admonition_info),
+        };
+
+        let icon = if theme.settings.ascii_icons { self.ascii_icon() } else { icon };
+        (icon, color, self.default_title())
+    }
+
+    /// Title shown when an admonition of this type doesn't set one
+    pub fn default_title(&self) -> &'static str {
+        match self {
+            Self::Note | Self::Custom => "Note",
+            Self::Tip => "Tip",
+            Self::Important => "Important",
+            Self::Warning => "Warning",
+            Self::Caution => "Caution",
+            Self::Danger => "Danger",
+            Self::Error => "Error",
+            Self::Info => "Info",
+            Self::Success => "Success",
+            Self::Question => "Question",
+            Self::Example => "Example",
+            Self::Quote => "Quote",
+            Self::Abstract => "Abstract",
+            Self::Todo => "Todo",
+            Self::Bug => "Bug",
+            Self::Failure => "Failure",
+        }
+    }
+
+    /// Plain ASCII stand-in for the icon, for terminals that show emoji and symbols as boxes
+    pub fn ascii_icon(&self) -> &'static str {
+        match self {
+            Self::Note | Self::Info => "[i]",
+            Self::Tip => "[*]",
+            Self::Important | Self::Warning | Self::Caution => "[!]",
+            Self::Danger | Self::Error | Self::Failure => "[x]",
+            Self::Success => "[+]",
+            Self::Question => "[?]",
+            Self::Example => "[>]",
+            Self::Quote => "[\"]",
+            Self::Abstract => "[=]",
+            Self::Todo => "[ ]",
+            Self::Bug => "[#]",
+            Self::Custom => "[-]",
+        }
+    }
 }
 
 /// Error type for parsing AdmonitionType
@@ -203,6 +851,54 @@ pub struct Admonition {
     pub admonition_type: AdmonitionType,
     pub title: Option<String>,
     pub blocks: Vec<Block>,
+    /// Presentation for [AdmonitionType::Custom] admonitions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<CustomAdmonition>,
+    /// Folded callout (`> [!NOTE]-`) shown as its title line until expanded in the TUI
+    #[serde(default)]
+    pub collapsed: bool,
+}
+
+impl Admonition {
+    /// Icon, color, and default title for this admonition, preferring its custom presentation
+    pub fn presentation<'a>(&'a self, theme: &Theme) -> (&'a str, Color, &'a str) {
+        let (icon, color, _) = self.admonition_type.presentation(theme);
+        let title = self.default_title();
+
+        match &self.custom {
+            Some(custom) => (
+                custom.icon.as_str(),
+                Color::from_hex(&custom.color).unwrap_or(*color),
+                title,
+            ),
+            None => (icon, *color, title),
+        }
+    }
+
+    /// Title shown when the admonition doesn't set one: its custom title, else its type's
+    pub fn default_title(&self) -> &str {
+        self.custom
+            .as_ref()
+            .and_then(|custom| custom.title.as_deref())
+            .unwrap_or(self.admonition_type.default_title())
+    }
+
+    /// Foreground and background of the title badge: the admonition color behind a contrasting text color
+    pub fn title_colors(&self, theme: &Theme) -> (Color, Color) {
+        let (_, color, _) = self.presentation(theme);
+        (readable_fg(color), color)
+    }
+}
+
+/// Icon, color, and title for a user-defined admonition type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomAdmonition {
+    pub icon: String,
+    /// Hex color (`#RRGGBB`)
+    pub color: String,
+    /// Default title when the admonition doesn't set one
+    #[serde(default)]
+    pub title: Option<String>,
 }
 
 #[cfg(test)]
@@ -249,4 +945,316 @@ mod tests {
         let rust_code = CodeBlock::with_language("rust", "fn main() {}");
         assert_eq!(rust_code.language, Some("rust".to_string()));
     }
+
+    #[test]
+    fn code_block_file_extension() {
+        assert_eq!(CodeBlock::with_language("Rust", "").file_extension(), "rs");
+        assert_eq!(CodeBlock::with_language("bash", "").file_extension(), "sh");
+        assert_eq!(
+            CodeBlock::with_language("rust title=main.rs", "").file_extension(),
+            "rs"
+        );
+        assert_eq!(CodeBlock::with_language("brainfuck", "").file_extension(), "txt");
+        assert_eq!(CodeBlock::new("").file_extension(), "txt");
+    }
+
+    #[test]
+    fn slide_section_divider_detection() {
+        let heading = |level| Block::Heading { level, spans: vec![TextSpan::plain("Part Two")] };
+        let paragraph = |text: &str| Block::Paragraph { spans: vec![TextSpan::plain(text)] };
+        let divider = |blocks| Slide::with_blocks(blocks).is_section_divider();
+
+        assert!(divider(vec![heading(1)]));
+        assert!(divider(vec![heading(1), paragraph("Scaling the ingest pipeline")]));
+        assert!(divider(vec![heading(1), heading(3)]));
+
+        assert!(!divider(vec![]));
+        assert!(!divider(vec![paragraph("Just text")]));
+        assert!(!divider(vec![heading(2), heading(1)]));
+        assert!(!divider(vec![
+            heading(1),
+            paragraph(&"word ".repeat(SECTION_SUBTITLE_MAX_WORDS + 1))
+        ]));
+        assert!(!divider(vec![heading(1), paragraph("Subtitle"), paragraph("More")]));
+        assert!(!divider(vec![heading(1), Block::Code(CodeBlock::new("x"))]));
+        assert!(!divider(vec![
+            heading(1),
+            Block::Image { path: "a.png".into(), alt: String::new(), caption: None }
+        ]));
+        assert!(!divider(vec![
+            heading(1),
+            Block::List(List {
+                ordered: false,
+                items: vec![ListItem { spans: vec![TextSpan::plain("item")], nested: None, blocks: vec![] }],
+                marker: None,
+                delimiter: ListDelimiter::Period,
+            }),
+        ]));
+    }
+
+    #[test]
+    fn ordinal_marker_formats_each_style() {
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerRoman, 1), "i");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerRoman, 2), "ii");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerRoman, 4), "iv");
+        assert_eq!(ordinal_marker(ListMarkerStyle::UpperRoman, 1994), "MCMXCIV");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerRoman, 4000), "4000");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerAlpha, 1), "a");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerAlpha, 26), "z");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerAlpha, 27), "aa");
+        assert_eq!(ordinal_marker(ListMarkerStyle::UpperAlpha, 28), "AB");
+        assert_eq!(ordinal_marker(ListMarkerStyle::LowerAlpha, 702), "zz");
+        assert_eq!(ordinal_marker(ListMarkerStyle::Decimal, 12), "12");
+    }
+
+    #[test]
+    fn list_item_marker_uses_style_or_depth() {
+        let item = ListItem { spans: vec![TextSpan::plain("x")], nested: None, blocks: vec![] };
+        let mut list =
+            List { ordered: true, items: vec![item.clone(), item], marker: None, delimiter: ListDelimiter::Period };
+
+        assert_eq!(list.item_marker(1, 0), "2.");
+        assert_eq!(list.item_marker(1, 1), "b.");
+        assert_eq!(list.item_marker(1, 2), "ii.");
+        assert_eq!(list.item_marker(1, 3), "2.");
+
+        list.marker = Some(ListMarkerStyle::UpperRoman);
+        assert_eq!(list.item_marker(1, 0), "II.");
+
+        list.ordered = false;
+        assert_eq!(list.item_marker(1, 0), "•");
+
+        assert_eq!(ListMarkerStyle::parse("i"), Some(ListMarkerStyle::LowerRoman));
+        assert_eq!(ListMarkerStyle::parse("A"), Some(ListMarkerStyle::UpperAlpha));
+        assert_eq!(ListMarkerStyle::parse("Upper-Roman"), Some(ListMarkerStyle::UpperRoman));
+        assert_eq!(ListMarkerStyle::parse("roman"), None);
+    }
+
+    #[test]
+    fn slide_code_blocks_include_nested() {
+        let slide = Slide::with_blocks(vec![
+            Block::Code(CodeBlock::with_language("rust", "fn a() {}")),
+            Block::BlockQuote { blocks: vec![Block::Code(CodeBlock::new("quoted"))] },
+            Block::List(List {
+                ordered: false,
+                items: vec![ListItem {
+                    spans: vec![TextSpan::plain("Step")],
+                    nested: None,
+                    blocks: vec![Block::Code(CodeBlock::with_language("sh", "ls"))],
+                }],
+                marker: None,
+                delimiter: ListDelimiter::Period,
+            }),
+        ]);
+
+        let code: Vec<&str> = slide.code_blocks().iter().map(|code| code.code.as_str()).collect();
+        assert_eq!(code, vec!["fn a() {}", "quoted", "ls"]);
+    }
+
+    #[test]
+    fn slide_style_combines_registered_classes() {
+        let mut slide = Slide::new();
+        assert_eq!(slide.style(), SlideStyle::default());
+
+        slide.classes = vec!["dark".to_string(), "center".to_string(), "custom".to_string()];
+        assert_eq!(
+            slide.style(),
+            SlideStyle { center: true, theme: Some("oxocarbon-dark") }
+        );
+
+        slide.classes.push("light".to_string());
+        assert_eq!(slide.style().theme, Some("oxocarbon-light"));
+    }
+
+    #[test]
+    fn slide_image_paths_include_background_and_nested() {
+        let image = |path: &str| Block::Image { path: path.to_string(), alt: String::new(), caption: None };
+        let mut slide = Slide::with_blocks(vec![
+            image("a.png"),
+            Block::BlockQuote { blocks: vec![image("b.png")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("text")] },
+        ]);
+        slide.bg_image = Some("bg.jpg".to_string());
+
+        assert_eq!(slide.image_paths(), vec!["bg.jpg", "a.png", "b.png"]);
+    }
+
+    #[test]
+    fn admonition_presentation_covers_every_variant() {
+        let theme = Theme::test_default();
+        let variants = [
+            AdmonitionType::Note,
+            AdmonitionType::Tip,
+            AdmonitionType::Important,
+            AdmonitionType::Warning,
+            AdmonitionType::Caution,
+            AdmonitionType::Danger,
+            AdmonitionType::Error,
+            AdmonitionType::Info,
+            AdmonitionType::Success,
+            AdmonitionType::Question,
+            AdmonitionType::Example,
+            AdmonitionType::Quote,
+            AdmonitionType::Abstract,
+            AdmonitionType::Todo,
+            AdmonitionType::Bug,
+            AdmonitionType::Failure,
+        ];
+
+        for variant in variants {
+            let (icon, _, default_title) = variant.presentation(&theme);
+            assert!(!icon.is_empty(), "{variant:?} has no icon");
+            assert!(!default_title.is_empty(), "{variant:?} has no default title");
+        }
+
+        let (_, color, _) = AdmonitionType::Warning.presentation(&theme);
+        assert!(std::ptr::eq(color, &theme.admonition_warning));
+    }
+
+    #[test]
+    fn admonition_presentation_uses_ascii_icons_when_enabled() {
+        let theme = Theme::test_default().with_ascii_icons(true);
+        let variants = [
+            AdmonitionType::Note,
+            AdmonitionType::Tip,
+            AdmonitionType::Important,
+            AdmonitionType::Warning,
+            AdmonitionType::Caution,
+            AdmonitionType::Danger,
+            AdmonitionType::Error,
+            AdmonitionType::Info,
+            AdmonitionType::Success,
+            AdmonitionType::Question,
+            AdmonitionType::Example,
+            AdmonitionType::Quote,
+            AdmonitionType::Abstract,
+            AdmonitionType::Todo,
+            AdmonitionType::Bug,
+            AdmonitionType::Failure,
+            AdmonitionType::Custom,
+        ];
+
+        for variant in variants {
+            let (icon, _, _) = variant.presentation(&theme);
+            assert!(icon.is_ascii(), "{variant:?} icon {icon:?} is not ASCII");
+            assert!(
+                icon.starts_with('[') && icon.ends_with(']') && icon.len() == 3,
+                "{variant:?}: {icon:?}"
+            );
+            assert_eq!(icon, variant.ascii_icon());
+        }
+
+        assert_eq!(AdmonitionType::Note.presentation(&theme).0, "[i]");
+        assert_eq!(AdmonitionType::Warning.presentation(&theme).0, "[!]");
+        assert_eq!(AdmonitionType::Danger.presentation(&theme).0, "[x]");
+        assert_eq!(AdmonitionType::Note.presentation(&Theme::test_default()).0, "\u{24D8}");
+    }
+
+    #[test]
+    fn admonition_default_title_needs_no_theme() {
+        assert_eq!(AdmonitionType::Warning.default_title(), "Warning");
+        assert_eq!(AdmonitionType::Custom.default_title(), "Note");
+
+        let mut admonition = Admonition {
+            admonition_type: AdmonitionType::Custom,
+            title: None,
+            blocks: Vec::new(),
+            custom: None,
+            collapsed: false,
+        };
+        assert_eq!(admonition.default_title(), "Note");
+
+        admonition.custom = Some(CustomAdmonition {
+            icon: "*".to_string(),
+            color: "#112233".to_string(),
+            title: Some("Aside".to_string()),
+        });
+        assert_eq!(admonition.default_title(), "Aside");
+        assert_eq!(admonition.presentation(&Theme::test_default()).2, "Aside");
+    }
+
+    #[test]
+    fn slides_with_finds_code_and_images() {
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Title")] }]),
+            Slide::with_blocks(vec![Block::Code(CodeBlock::new("let x = 1;"))]),
+            Slide::with_blocks(vec![Block::Image {
+                path: "a.png".into(),
+                alt: String::new(),
+                caption: None,
+            }]),
+            Slide::with_blocks(vec![Block::Admonition(Admonition {
+                admonition_type: AdmonitionType::Tip,
+                title: None,
+                blocks: vec![Block::BlockQuote { blocks: vec![Block::Code(CodeBlock::new("nested"))] }],
+                custom: None,
+                collapsed: false,
+            })]),
+        ];
+
+        assert_eq!(slides_with(&slides, |b| matches!(b, Block::Code(_))), vec![1, 3]);
+        assert_eq!(slides_with(&slides, |b| matches!(b, Block::Image { .. })), vec![2]);
+        assert!(slides_with(&slides, |b| matches!(b, Block::Table(_))).is_empty());
+    }
+
+    #[test]
+    fn slide_transition_axis_follows_hint() {
+        let mut slide = Slide::new();
+        assert_eq!(slide.transition_axis(), TransitionAxis::Horizontal);
+
+        slide.transition = Some(TransitionDirection::Up);
+        assert_eq!(slide.transition_axis(), TransitionAxis::Vertical);
+        slide.transition = Some(TransitionDirection::Down);
+        assert_eq!(slide.transition_axis(), TransitionAxis::Vertical);
+        slide.transition = Some(TransitionDirection::Right);
+        assert_eq!(slide.transition_axis(), TransitionAxis::Horizontal);
+
+        assert_eq!(TransitionDirection::parse("UP"), Some(TransitionDirection::Up));
+        assert_eq!(TransitionDirection::parse("sideways"), None);
+    }
+
+    #[test]
+    fn slide_title_and_word_count() {
+        let slide = Slide::with_blocks(vec![
+            Block::Heading { level: 2, spans: vec![TextSpan::plain("Why "), TextSpan::code("Rust")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("Fast and "), TextSpan::bold("safe")] },
+            Block::Code(CodeBlock::new("fn main() { println!(\"not counted\"); }")),
+            Block::List(List {
+                ordered: false,
+                items: vec![ListItem {
+                    spans: vec![TextSpan::plain("one item")],
+                    blocks: vec![],
+                    nested: Some(Box::new(List {
+                        ordered: false,
+                        items: vec![ListItem { spans: vec![TextSpan::plain("nested")], nested: None, blocks: vec![] }],
+                        marker: None,
+                        delimiter: ListDelimiter::Period,
+                    })),
+                }],
+                marker: None,
+                delimiter: ListDelimiter::Period,
+            }),
+        ]);
+
+        assert_eq!(slide.title().as_deref(), Some("Why Rust"));
+        assert_eq!(slide.title_level(), Some(2));
+        assert_eq!(slide.word_count(), 8);
+        assert_eq!(Slide::new().title(), None);
+    }
+
+    #[test]
+    fn image_label_falls_back_to_file_name() {
+        assert_eq!(image_label("img/arch.png", "Architecture"), "Architecture");
+        assert_eq!(image_label("img/arch.png", "  "), "arch.png");
+        assert_eq!(image_label("", ""), "image");
+    }
+
+    #[test]
+    fn slugify_titles() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(slugify("  What's New in v2.0?  "), "whats-new-in-v20");
+        assert_eq!(slugify("Rust -- Fast & Safe"), "rust-fast-safe");
+        assert_eq!(slugify("snake_case Title"), "snake-case-title");
+        assert_eq!(slugify("!!!"), "");
+    }
 }
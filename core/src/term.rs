@@ -1,4 +1,8 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
 use std::{io, time::Duration};
 
 #[cfg(not(test))]
@@ -27,14 +31,24 @@ impl Terminal {
     ///
     /// Enables alternate screen and raw mode for full terminal control.
     pub fn setup() -> io::Result<Self> {
+        Self::setup_with(true)
+    }
+
+    /// Initialize terminal for TUI mode, optionally skipping the alternate screen
+    ///
+    /// Without the alternate screen only raw mode is enabled, so the presentation is drawn in the normal buffer and
+    /// stays in scrollback after exit.
+    pub fn setup_with(alternate_screen: bool) -> io::Result<Self> {
         #[cfg(not(test))]
         {
-            let mut stdout = io::stdout();
-            execute!(stdout, EnterAlternateScreen)?;
+            if alternate_screen {
+                let mut stdout = io::stdout();
+                execute!(stdout, EnterAlternateScreen)?;
+            }
             enable_raw_mode()?;
         }
 
-        Ok(Self::default())
+        Ok(Self { in_alternate_screen: alternate_screen, in_raw_mode: true })
     }
 
     /// Restore terminal to normal mode by disabling raw mode and exits alternate screen.
@@ -71,6 +85,58 @@ impl Drop for Terminal {
     }
 }
 
+/// Leave raw mode and the alternate screen, ignoring errors
+///
+/// Used where no [Terminal] handle is available, such as from a panic hook.
+pub fn restore_terminal() {
+    #[cfg(not(test))]
+    {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+/// Runs a terminal restore action at most once, however many times it is triggered
+pub struct RestoreOnPanic {
+    restore: Box<dyn Fn() + Send + Sync>,
+    restored: AtomicBool,
+}
+
+impl RestoreOnPanic {
+    pub fn new(restore: impl Fn() + Send + Sync + 'static) -> Self {
+        Self { restore: Box::new(restore), restored: AtomicBool::new(false) }
+    }
+
+    /// Run the restore action unless it has already run
+    pub fn restore(&self) {
+        if !self.restored.swap(true, Ordering::SeqCst) {
+            (self.restore)();
+        }
+    }
+
+    /// Whether the restore action has run
+    pub fn has_restored(&self) -> bool {
+        self.restored.load(Ordering::SeqCst)
+    }
+}
+
+/// Install a panic hook that runs `restore` before the previously installed hook
+///
+/// Restoring first means the panic message is printed to the normal screen instead of being lost
+/// in the alternate screen, and the shell is left usable after a crash.
+pub fn restore_on_panic(restore: impl Fn() + Send + Sync + 'static) -> Arc<RestoreOnPanic> {
+    let guard = Arc::new(RestoreOnPanic::new(restore));
+    let hook_guard = Arc::clone(&guard);
+    let previous = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        hook_guard.restore();
+        previous(info);
+    }));
+
+    guard
+}
+
 /// Input event handler for slide navigation and control
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputEvent {
@@ -82,6 +148,18 @@ pub enum InputEvent {
     ToggleNotes,
     /// Toggle help display
     ToggleHelp,
+    /// Toggle the print-preview overlay of the current slide
+    TogglePreview,
+    /// Toggle the grid overview of every slide
+    ToggleOverview,
+    /// Scroll wide content left
+    ScrollLeft,
+    /// Scroll wide content right
+    ScrollRight,
+    /// Focus the next code block on the current slide
+    FocusCode,
+    /// Expand or fold the collapsed admonitions on the current slide
+    ToggleFold,
     /// Search slides
     /// TODO: Implement search functionality
     Search,
@@ -99,36 +177,34 @@ impl InputEvent {
     ///
     /// Maps keyboard and terminal events to presentation actions.
     pub fn from_crossterm(event: Event) -> Self {
+        Self::from_crossterm_with(event, &KeyMap::default())
+    }
+
+    /// Convert crossterm event to input event using the bindings in `keymap`
+    pub fn from_crossterm_with(event: Event, keymap: &KeyMap) -> Self {
         match event {
-            Event::Key(KeyEvent { code, modifiers, .. }) => Self::from_key(code, modifiers),
+            Event::Key(KeyEvent { code, modifiers, .. }) => keymap.event_for(code, modifiers),
             Event::Resize(width, height) => Self::Resize { width, height },
             _ => Self::Other,
         }
     }
 
-    /// Map key press to input event
+    /// Map key press to input event using the default [KeyMap]
+    #[cfg(test)]
     fn from_key(code: KeyCode, modifiers: KeyModifiers) -> Self {
-        match (code, modifiers) {
-            (KeyCode::Right | KeyCode::Char('j') | KeyCode::Char(' '), _) => Self::Next,
-            (KeyCode::Char('n'), KeyModifiers::NONE) => Self::Next,
-            (KeyCode::Left | KeyCode::Char('k'), _) => Self::Previous,
-            (KeyCode::Char('p'), KeyModifiers::NONE) => Self::Previous,
-            (KeyCode::Char('q'), KeyModifiers::NONE) => Self::Quit,
-            (KeyCode::Char('c'), KeyModifiers::CONTROL) => Self::Quit,
-            (KeyCode::Esc, _) => Self::Quit,
-            (KeyCode::Char('n'), KeyModifiers::SHIFT) => Self::ToggleNotes,
-            (KeyCode::Char('?'), _) => Self::ToggleHelp,
-            (KeyCode::Char('f'), KeyModifiers::CONTROL) => Self::Search,
-            (KeyCode::Char('/'), KeyModifiers::NONE) => Self::Search,
-            _ => Self::Other,
-        }
+        KeyMap::default().event_for(code, modifiers)
     }
 
     /// Poll for next input event with timeout
     pub fn poll(timeout: Duration) -> io::Result<Option<Self>> {
+        Self::poll_with(timeout, &KeyMap::default())
+    }
+
+    /// Poll for next input event with timeout, mapping keys through `keymap`
+    pub fn poll_with(timeout: Duration, keymap: &KeyMap) -> io::Result<Option<Self>> {
         if event::poll(timeout)? {
             let event = event::read()?;
-            Ok(Some(Self::from_crossterm(event)))
+            Ok(Some(Self::from_crossterm_with(event, keymap)))
         } else {
             Ok(None)
         }
@@ -141,10 +217,249 @@ impl InputEvent {
     }
 }
 
+/// Group a key binding is listed under in the help overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCategory {
+    Navigation,
+    View,
+    Notes,
+    Search,
+    Quit,
+}
+
+impl KeyCategory {
+    /// Every category, in the order the help overlay lists them
+    pub const ALL: [KeyCategory; 5] = [
+        KeyCategory::Navigation,
+        KeyCategory::View,
+        KeyCategory::Notes,
+        KeyCategory::Search,
+        KeyCategory::Quit,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyCategory::Navigation => "Navigation",
+            KeyCategory::View => "View",
+            KeyCategory::Notes => "Notes",
+            KeyCategory::Search => "Search",
+            KeyCategory::Quit => "Quit",
+        }
+    }
+}
+
+/// A key press to match; `None` modifiers match any combination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub modifiers: Option<KeyModifiers>,
+}
+
+impl Key {
+    /// Match `code` with any modifiers
+    pub fn any(code: KeyCode) -> Self {
+        Self { code, modifiers: None }
+    }
+
+    /// Match `code` only with exactly `modifiers`
+    pub fn with(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers: Some(modifiers) }
+    }
+
+    fn matches(&self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.code == code && self.modifiers.is_none_or(|expected| expected == modifiers)
+    }
+
+    /// Short label for help text, e.g. `j`, `N`, `Ctrl+c`, `Space` or `→`
+    pub fn label(&self) -> String {
+        let name = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if self.modifiers == Some(KeyModifiers::SHIFT) => c.to_uppercase().to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            other => other.to_string(),
+        };
+
+        match self.modifiers {
+            Some(modifiers) if modifiers.contains(KeyModifiers::CONTROL) => format!("Ctrl+{name}"),
+            _ => name,
+        }
+    }
+}
+
+/// Keys that trigger one [InputEvent], with the help text describing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub keys: Vec<Key>,
+    pub event: InputEvent,
+    pub category: KeyCategory,
+    pub description: String,
+}
+
+impl KeyBinding {
+    pub fn new(keys: Vec<Key>, event: InputEvent, category: KeyCategory, description: &str) -> Self {
+        Self { keys, event, category, description: description.to_string() }
+    }
+}
+
+/// Key bindings of the presenter, consulted in order when mapping a key press
+///
+/// The default map holds the built-in bindings; the help overlay is generated from whichever map is in use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMap {
+    pub bindings: Vec<KeyBinding>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        use KeyCategory::*;
+        use KeyModifiers as M;
+
+        let bindings = vec![
+            KeyBinding::new(
+                vec![
+                    Key::any(KeyCode::Char('j')),
+                    Key::any(KeyCode::Right),
+                    Key::any(KeyCode::Char(' ')),
+                    Key::with(KeyCode::Char('n'), M::NONE),
+                ],
+                InputEvent::Next,
+                Navigation,
+                "Next slide",
+            ),
+            KeyBinding::new(
+                vec![
+                    Key::any(KeyCode::Char('k')),
+                    Key::any(KeyCode::Left),
+                    Key::with(KeyCode::Char('p'), M::NONE),
+                ],
+                InputEvent::Previous,
+                Navigation,
+                "Previous slide",
+            ),
+            KeyBinding::new(
+                vec![Key::with(KeyCode::Char('h'), M::NONE)],
+                InputEvent::ScrollLeft,
+                Navigation,
+                "Scroll wide content left",
+            ),
+            KeyBinding::new(
+                vec![Key::with(KeyCode::Char('l'), M::NONE)],
+                InputEvent::ScrollRight,
+                Navigation,
+                "Scroll wide content right",
+            ),
+            KeyBinding::new(
+                vec![Key::any(KeyCode::Tab)],
+                InputEvent::FocusCode,
+                View,
+                "Focus the next code block",
+            ),
+            KeyBinding::new(
+                vec![Key::any(KeyCode::Enter)],
+                InputEvent::ToggleFold,
+                View,
+                "Expand or fold callouts",
+            ),
+            KeyBinding::new(
+                vec![Key::with(KeyCode::Char('p'), M::SHIFT), Key::any(KeyCode::Char('P'))],
+                InputEvent::TogglePreview,
+                View,
+                "Toggle print preview",
+            ),
+            KeyBinding::new(
+                vec![Key::with(KeyCode::Char('o'), M::NONE)],
+                InputEvent::ToggleOverview,
+                View,
+                "Toggle slide overview",
+            ),
+            KeyBinding::new(
+                vec![Key::any(KeyCode::Char('?'))],
+                InputEvent::ToggleHelp,
+                View,
+                "Toggle this help",
+            ),
+            KeyBinding::new(
+                vec![Key::with(KeyCode::Char('n'), M::SHIFT)],
+                InputEvent::ToggleNotes,
+                Notes,
+                "Toggle speaker notes",
+            ),
+            KeyBinding::new(
+                vec![
+                    Key::with(KeyCode::Char('/'), M::NONE),
+                    Key::with(KeyCode::Char('f'), M::CONTROL),
+                ],
+                InputEvent::Search,
+                Search,
+                "Search slides",
+            ),
+            KeyBinding::new(
+                vec![
+                    Key::with(KeyCode::Char('q'), M::NONE),
+                    Key::any(KeyCode::Esc),
+                    Key::with(KeyCode::Char('c'), M::CONTROL),
+                ],
+                InputEvent::Quit,
+                Quit,
+                "Quit",
+            ),
+        ];
+
+        Self { bindings }
+    }
+}
+
+impl KeyMap {
+    /// Event of the first binding with a key matching the press, or [InputEvent::Other]
+    pub fn event_for(&self, code: KeyCode, modifiers: KeyModifiers) -> InputEvent {
+        self.bindings
+            .iter()
+            .find(|binding| binding.keys.iter().any(|key| key.matches(code, modifiers)))
+            .map_or(InputEvent::Other, |binding| binding.event.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn keymap_event_for_uses_custom_bindings() {
+        let mut keymap = KeyMap::default();
+        keymap.bindings[0]
+            .keys
+            .push(Key::with(KeyCode::Char('x'), KeyModifiers::NONE));
+
+        assert_eq!(
+            keymap.event_for(KeyCode::Char('x'), KeyModifiers::NONE),
+            InputEvent::Next
+        );
+        assert_eq!(
+            keymap.event_for(KeyCode::Char('x'), KeyModifiers::CONTROL),
+            InputEvent::Other
+        );
+        assert_eq!(
+            KeyMap::default().event_for(KeyCode::Char('x'), KeyModifiers::NONE),
+            InputEvent::Other
+        );
+
+        let event = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert_eq!(InputEvent::from_crossterm_with(event, &keymap), InputEvent::Next);
+    }
+
+    #[test]
+    fn key_labels() {
+        assert_eq!(Key::any(KeyCode::Char(' ')).label(), "Space");
+        assert_eq!(Key::with(KeyCode::Char('n'), KeyModifiers::SHIFT).label(), "N");
+        assert_eq!(Key::with(KeyCode::Char('c'), KeyModifiers::CONTROL).label(), "Ctrl+c");
+        assert_eq!(Key::any(KeyCode::Right).label(), "→");
+        assert_eq!(Key::any(KeyCode::Esc).label(), "Esc");
+    }
+
     #[test]
     fn input_event_navigation() {
         let next = InputEvent::from_key(KeyCode::Right, KeyModifiers::NONE);
@@ -187,6 +502,39 @@ mod tests {
         assert_eq!(help_shift, InputEvent::ToggleHelp);
     }
 
+    #[test]
+    fn input_event_horizontal_scroll() {
+        let left = InputEvent::from_key(KeyCode::Char('h'), KeyModifiers::NONE);
+        assert_eq!(left, InputEvent::ScrollLeft);
+
+        let right = InputEvent::from_key(KeyCode::Char('l'), KeyModifiers::NONE);
+        assert_eq!(right, InputEvent::ScrollRight);
+    }
+
+    #[test]
+    fn input_event_focus_code() {
+        let focus = InputEvent::from_key(KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(focus, InputEvent::FocusCode);
+
+        let fold = InputEvent::from_key(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(fold, InputEvent::ToggleFold);
+    }
+
+    #[test]
+    fn input_event_toggle_preview() {
+        let preview = InputEvent::from_key(KeyCode::Char('P'), KeyModifiers::SHIFT);
+        assert_eq!(preview, InputEvent::TogglePreview);
+
+        let prev = InputEvent::from_key(KeyCode::Char('p'), KeyModifiers::NONE);
+        assert_eq!(prev, InputEvent::Previous);
+    }
+
+    #[test]
+    fn input_event_toggle_overview() {
+        let overview = InputEvent::from_key(KeyCode::Char('o'), KeyModifiers::NONE);
+        assert_eq!(overview, InputEvent::ToggleOverview);
+    }
+
     #[test]
     fn terminal_default_state() {
         let terminal = Terminal::default();
@@ -194,6 +542,16 @@ mod tests {
         assert!(terminal.in_raw_mode);
     }
 
+    #[test]
+    fn terminal_setup_without_alternate_screen() {
+        let terminal = Terminal::setup_with(false).unwrap();
+        assert!(!terminal.in_alternate_screen);
+        assert!(terminal.in_raw_mode);
+
+        let terminal = Terminal::setup_with(true).unwrap();
+        assert!(terminal.in_alternate_screen);
+    }
+
     #[test]
     fn terminal_restore_idempotent() {
         let mut terminal = Terminal { in_alternate_screen: false, in_raw_mode: false };
@@ -211,4 +569,20 @@ mod tests {
         assert!(!terminal.in_alternate_screen);
         assert!(!terminal.in_raw_mode);
     }
+
+    #[test]
+    fn restore_on_panic_guard_restores_once() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let guard = RestoreOnPanic::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(!guard.has_restored());
+        guard.restore();
+        guard.restore();
+
+        assert!(guard.has_restored());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
 }
@@ -1,7 +1,12 @@
 use owo_colors::{OwoColorize, Style};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Deref;
+use std::sync::OnceLock;
 use terminal_colorsaurus::{QueryOptions, ThemeMode, theme_mode};
 
+use crate::error::{self, SlideError};
+use crate::highlighter;
+
 /// Parses a hex color string to RGB values.
 ///
 /// Supports both `#RRGGBB` and `RRGGBB` formats.
@@ -75,7 +80,7 @@ static SOLARIZED_DARK: &str = include_str!("themes/solarized-dark.yml");
 static SOLARIZED_LIGHT: &str = include_str!("themes/solarized-light.yml");
 
 /// RGB color value for use with both owo-colors and ratatui
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -87,12 +92,73 @@ impl Color {
         Self { r, g, b }
     }
 
+    /// Parse a `#RRGGBB` or `RRGGBB` hex string
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        parse_hex_color(hex).map(|(r, g, b)| Self::new(r, g, b))
+    }
+
+    /// Format as a lowercase `#rrggbb` hex string
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
     /// Apply this color to text using owo-colors
     pub fn to_owo_color<'a, T: OwoColorize>(&self, text: &'a T) -> owo_colors::Styled<&'a T> {
         text.style(self.into())
     }
 }
 
+/// WCAG AA minimum contrast ratio for body text
+pub const MIN_CONTRAST: f32 = 4.5;
+
+/// Near-black or near-white, whichever contrasts more with `background`
+///
+/// Uses WCAG relative luminance so text stays legible on any tinted background.
+pub fn readable_fg(background: Color) -> Color {
+    const NEAR_BLACK: Color = Color::new(20, 20, 20);
+    const NEAR_WHITE: Color = Color::new(245, 245, 245);
+
+    let prefer_black = contrast_ratio(NEAR_BLACK, background) >= contrast_ratio(NEAR_WHITE, background);
+    if prefer_black { NEAR_BLACK } else { NEAR_WHITE }
+}
+
+/// WCAG contrast ratio between two colors, from `1.0` (identical) to `21.0` (black on white)
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (a.relative_luminance(), b.relative_luminance());
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// `color` with each channel scaled by `factor`, clamped to `0.0..=1.0`: `0.0` gives black and `1.0` the color itself
+pub fn dim_color(color: Color, factor: f32) -> Color {
+    let factor = factor.clamp(0.0, 1.0);
+    let scale = |c: u8| (f32::from(c) * factor).round() as u8;
+    Color::new(scale(color.r), scale(color.g), scale(color.b))
+}
+
+impl Color {
+    /// WCAG relative luminance in `0.0..=1.0`
+    fn relative_luminance(&self) -> f32 {
+        let channel = |c: u8| {
+            let c = c as f32 / 255.0;
+            if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Color::from_hex(&hex).ok_or_else(|| serde::de::Error::custom(format!("invalid hex color '{hex}'")))
+    }
+}
+
 impl From<Color> for Style {
     fn from(color: Color) -> Self {
         Style::new().truecolor(color.r, color.g, color.b)
@@ -107,10 +173,17 @@ impl From<&Color> for Style {
 
 /// Detects if the terminal background is dark.
 ///
-/// Uses [terminal_colorsaurus] to query the terminal theme mode.
+/// The `SLIDES_BACKGROUND` environment variable (`dark` or `light`) overrides detection.
+/// Otherwise uses [terminal_colorsaurus] to query the terminal theme mode once and caches the result.
 /// Defaults to true (dark) if detection fails.
 pub fn detect_is_dark() -> bool {
-    match theme_mode(QueryOptions::default()) {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+
+    if let Some(is_dark) = background_override() {
+        return is_dark;
+    }
+
+    *DETECTED.get_or_init(|| match theme_mode(QueryOptions::default()) {
         Ok(mode) => {
             let is_dark = mode == ThemeMode::Dark;
             tracing::debug!("Terminal theme detection: mode={:?} -> is_dark={}", mode, is_dark);
@@ -120,13 +193,28 @@ pub fn detect_is_dark() -> bool {
             tracing::debug!("Terminal theme detection failed: {}, defaulting to dark", e);
             true
         }
+    })
+}
+
+/// Background darkness forced through `SLIDES_BACKGROUND`, if set to `dark` or `light`
+fn background_override() -> Option<bool> {
+    background_from(&std::env::var("SLIDES_BACKGROUND").ok()?)
+}
+
+/// Darkness named by a `SLIDES_BACKGROUND` value: `Some(true)` for `dark`, `Some(false)` for `light`
+pub fn background_from(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "dark" => Some(true),
+        "light" => Some(false),
+        _ => None,
     }
 }
 
 /// Color theme abstraction for slides with semantic roles for consistent theming across the application.
 ///
 /// Stores RGB colors that can be converted to both owo-colors Style (for terminal output) and ratatui Color (for TUI rendering).
-#[derive(Debug, Clone, Copy)]
+/// Serializes to YAML with each color as a `#rrggbb` string (see [Theme::to_yaml]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ThemeColors {
     pub heading: Color,
     pub heading_bold: bool,
@@ -155,15 +243,208 @@ pub struct ThemeColors {
     pub admonition_info: Color,
 }
 
-impl Default for ThemeColors {
+/// Rendering options that travel with a theme but aren't colors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    /// Syntect theme override for code blocks (see [crate::highlighter])
+    #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_code_theme")]
+    pub code_theme: Option<String>,
+    /// Run syntect over code blocks; when off every line is a single token in the `code` color
+    pub highlight: bool,
+    /// Per-level heading prefixes; `None` uses [DEFAULT_HEADING_PREFIXES] and an empty list disables them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading_prefixes: Option<Vec<String>>,
+    /// How blockquotes are set off from surrounding text
+    pub blockquote_style: BlockquoteStyle,
+    /// Draw admonition icons from the ASCII set (`[i]`, `[!]`, ...) for terminals without emoji glyphs
+    pub ascii_icons: bool,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            code_theme: None,
+            highlight: true,
+            heading_prefixes: None,
+            blockquote_style: BlockquoteStyle::default(),
+            ascii_icons: false,
+        }
+    }
+}
+
+/// Resolve a serialized `code_theme` name to its bundled syntect key, warning about and dropping unknown names
+fn deserialize_code_theme<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let name = Option::<String>::deserialize(deserializer)?;
+    Ok(name.and_then(|name| {
+        let key = highlighter::code_theme_key(&name);
+        if key.is_none() {
+            tracing::warn!("Unknown code theme '{}' in theme YAML, ignoring it", name);
+        }
+        key.map(str::to_string)
+    }))
+}
+
+/// A color palette plus the settings renderers need to draw with it
+///
+/// Dereferences to its [ThemeColors], so `theme.heading` and the styling helpers work on a [Theme] directly.
+/// Serializes the colors at the top level and the settings under a `settings` key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Theme {
+    #[serde(flatten)]
+    pub colors: ThemeColors,
+    #[serde(default)]
+    pub settings: ThemeSettings,
+}
+
+impl From<ThemeColors> for Theme {
+    fn from(colors: ThemeColors) -> Self {
+        Self { colors, settings: ThemeSettings::default() }
+    }
+}
+
+impl Deref for Theme {
+    type Target = ThemeColors;
+
+    fn deref(&self) -> &ThemeColors {
+        &self.colors
+    }
+}
+
+/// Blockquote presentation selected with the `blockquote_style` frontmatter key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockquoteStyle {
+    /// `│ ` bar in the blockquote border color
+    #[default]
+    Border,
+    /// Plain two-column indent without a bar
+    Indent,
+    /// Indented and tinted with the inline code background
+    Background,
+}
+
+/// Built-in heading prefixes using Unicode block symbols
+/// 1. (*h1*) Large block / heavy fill (`U+2589`)
+/// 2. (*h2*) Dark shade (`U+2593`)
+/// 3. (*h3*) Medium shade (`U+2592`)
+/// 4. (*h4*) Light shade (`U+2591`)
+/// 5. (*h5*) Left half block (`U+258C`)
+/// 6. (*h6*) Left half block (`U+258C`)
+pub const DEFAULT_HEADING_PREFIXES: [&str; 6] = ["▉ ", "▓ ", "▒ ", "░ ", "▌ ", "▌ "];
+
+impl Default for Theme {
     fn default() -> Self {
         let is_dark = detect_is_dark();
         let theme_name = if is_dark { "oxocarbon-dark" } else { "oxocarbon-light" };
-        tracing::debug!("ThemeColors::default() selecting theme: {}", theme_name);
+        tracing::debug!("Theme::default() selecting theme: {}", theme_name);
         ThemeRegistry::get(theme_name)
     }
 }
 
+impl Theme {
+    /// Fixed oxocarbon-dark theme that never queries the terminal
+    ///
+    /// Unlike [Theme::default], the result does not depend on the terminal background, so tests and CI get the
+    /// same colors everywhere without blocking on terminal I/O.
+    pub fn test_default() -> Self {
+        ThemeRegistry::get("oxocarbon-dark")
+    }
+
+    /// Lighten or darken the body color until it reaches [MIN_CONTRAST] against the UI background
+    ///
+    /// The body is blended toward [readable_fg] of the background in small steps, so it keeps as much of its hue as
+    /// the ratio allows. Themes that already pass are returned unchanged. On mid-tone backgrounds where even
+    /// [readable_fg] falls short, the body ends at [readable_fg] and a warning is logged instead.
+    pub fn with_ensured_contrast(mut self) -> Self {
+        const STEPS: u16 = 20;
+
+        let background = self.ui_background;
+        let original = self.body;
+        if contrast_ratio(original, background) >= MIN_CONTRAST {
+            return self;
+        }
+
+        let target = readable_fg(background);
+        let blend = |from: u8, to: u8, step: u16| {
+            let (from, to) = (u16::from(from), u16::from(to));
+            ((from * (STEPS - step) + to * step) / STEPS) as u8
+        };
+        for step in 1..=STEPS {
+            self.colors.body = Color::new(
+                blend(original.r, target.r, step),
+                blend(original.g, target.g, step),
+                blend(original.b, target.b, step),
+            );
+            if contrast_ratio(self.body, background) >= MIN_CONTRAST {
+                break;
+            }
+        }
+
+        let ratio = contrast_ratio(self.body, background);
+        if ratio >= MIN_CONTRAST {
+            tracing::info!(
+                "Adjusted body color {} to {} for contrast {:.2}:1 against {} (was {:.2}:1)",
+                original.to_hex(),
+                self.body.to_hex(),
+                ratio,
+                background.to_hex(),
+                contrast_ratio(original, background)
+            );
+        } else {
+            tracing::warn!(
+                "Body color {} can't reach {:.1}:1 against {}; using {} at {:.2}:1 (was {:.2}:1)",
+                original.to_hex(),
+                MIN_CONTRAST,
+                background.to_hex(),
+                self.body.to_hex(),
+                ratio,
+                contrast_ratio(original, background)
+            );
+        }
+        self
+    }
+
+    /// Override the heading prefixes; `Some(vec![])` removes them entirely
+    pub fn with_heading_prefixes(mut self, prefixes: Option<Vec<String>>) -> Self {
+        self.settings.heading_prefixes = prefixes;
+        self
+    }
+
+    pub fn with_blockquote_style(mut self, style: BlockquoteStyle) -> Self {
+        self.settings.blockquote_style = style;
+        self
+    }
+
+    pub fn with_ascii_icons(mut self, ascii_icons: bool) -> Self {
+        self.settings.ascii_icons = ascii_icons;
+        self
+    }
+
+    /// Prefix drawn before a heading of the given level
+    ///
+    /// Levels past the end of a custom list reuse its last entry.
+    pub fn heading_prefix(&self, level: u8) -> &str {
+        let index = usize::from(level.max(1)) - 1;
+        match &self.settings.heading_prefixes {
+            Some(prefixes) => prefixes.get(index).or(prefixes.last()).map_or("", String::as_str),
+            None => DEFAULT_HEADING_PREFIXES[index.min(DEFAULT_HEADING_PREFIXES.len() - 1)],
+        }
+    }
+
+    /// Serialize the resolved semantic colors, with the settings under `settings`, to YAML for editing
+    pub fn to_yaml(&self) -> error::Result<String> {
+        serde_yml::to_string(self).map_err(|e| SlideError::theme_error(format!("Failed to serialize theme: {e}")))
+    }
+
+    /// Load a theme previously written by [Theme::to_yaml]
+    ///
+    /// `settings.code_theme` is resolved against the bundled syntect themes; unknown names are dropped.
+    pub fn from_yaml(yaml: &str) -> error::Result<Self> {
+        serde_yml::from_str(yaml).map_err(|e| SlideError::theme_error(format!("Failed to parse theme YAML: {e}")))
+    }
+}
+
 impl ThemeColors {
     /// Create a ThemeColors from a base16 color scheme.
     ///
@@ -330,11 +611,13 @@ impl ThemeRegistry {
     /// Get a theme by name.
     ///
     /// Loads and parses the corresponding YAML theme file embedded at compile time.
-    /// "default" maps to oxocarbon-dark or oxocarbon-light based on terminal background detection.
+    /// "default" and "auto" map to oxocarbon-dark or oxocarbon-light based on terminal background detection.
+    /// "random" picks one of [ThemeRegistry::available_themes] (see [ThemeRegistry::random_name]).
     /// Falls back to Nord theme if the requested theme is not found or parsing fails.
-    pub fn get(name: &str) -> ThemeColors {
+    pub fn get(name: &str) -> Theme {
         let yaml = match name.to_lowercase().as_str() {
-            "default" => {
+            "random" => return Self::get(Self::random_name()),
+            "default" | "auto" => {
                 let is_dark = detect_is_dark();
                 if is_dark { OXOCARBON_DARK } else { OXOCARBON_LIGHT }
             }
@@ -360,6 +643,36 @@ impl ThemeRegistry {
                     .and_then(|scheme| ThemeColors::from_base16(&scheme))
                     .expect("Failed to parse fallback Nord theme")
             })
+            .into()
+    }
+
+    /// Pick a built-in theme at random, seeded from `LANTERN_SEED` when it holds a number and the clock otherwise
+    pub fn random_name() -> &'static str {
+        Self::random_name_from(std::env::var("LANTERN_SEED").ok().as_deref())
+    }
+
+    /// [ThemeRegistry::random_name] with the `LANTERN_SEED` value passed in; `None` or a non-number uses the clock
+    pub fn random_name_from(seed: Option<&str>) -> &'static str {
+        let seed = seed.and_then(|seed| seed.trim().parse().ok()).unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+        });
+        let name = Self::random_name_with_seed(seed);
+        tracing::info!("Random theme selected: {} (seed {})", name, seed);
+        name
+    }
+
+    /// The theme [ThemeRegistry::random_name] picks for `seed`, uniformly across the built-in themes
+    pub fn random_name_with_seed(seed: u64) -> &'static str {
+        // SplitMix64 finalizer, so neighbouring seeds land on unrelated themes
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        let themes = Self::available_themes();
+        themes[(z % themes.len() as u64) as usize]
     }
 
     /// List all available theme names.
@@ -514,15 +827,22 @@ palette:
 
     #[test]
     fn theme_colors_default() {
-        let theme = ThemeColors::default();
+        let theme = Theme::default();
         let text = "Test";
         let heading = theme.heading(&text);
         assert!(heading.to_string().contains("Test"));
     }
 
+    #[test]
+    fn theme_colors_test_default_is_fixed() {
+        let first = Theme::test_default();
+        assert_eq!(first, Theme::test_default());
+        assert_eq!(first, ThemeRegistry::get("oxocarbon-dark"));
+    }
+
     #[test]
     fn theme_colors_apply_styles() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
 
         assert!(theme.heading(&"Heading").to_string().contains("Heading"));
         assert!(theme.body(&"Body").to_string().contains("Body"));
@@ -649,7 +969,7 @@ palette:
 
     #[test]
     fn theme_colors_all_semantic_roles() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
 
         assert!(theme.heading(&"Test").to_string().contains("Test"));
         assert!(theme.body(&"Test").to_string().contains("Test"));
@@ -683,4 +1003,111 @@ palette:
             );
         }
     }
+
+    #[test]
+    fn theme_colors_yaml_round_trip() {
+        let theme = ThemeRegistry::get("catppuccin-mocha")
+            .with_code_theme("InspiredGitHub")
+            .with_heading_prefixes(Some(vec!["# ".into()]))
+            .with_ascii_icons(true);
+        let yaml = theme.to_yaml().unwrap();
+        assert!(yaml.contains("admonition_warning: '#"));
+        assert!(yaml.contains("settings:\n  code_theme: InspiredGitHub"));
+
+        let reparsed = Theme::from_yaml(&yaml).unwrap();
+        assert_eq!(reparsed, theme);
+    }
+
+    #[test]
+    fn theme_from_yaml_defaults_missing_settings() {
+        let colors = ThemeRegistry::get("nord").colors;
+        let yaml = serde_yml::to_string(&colors).unwrap();
+
+        let theme = Theme::from_yaml(&yaml).unwrap();
+        assert_eq!(theme.colors, colors);
+        assert_eq!(theme.settings, ThemeSettings::default());
+
+        let unknown = format!("{yaml}settings:\n  code_theme: not-a-real-theme\n");
+        assert_eq!(Theme::from_yaml(&unknown).unwrap().settings.code_theme, None);
+    }
+
+    #[test]
+    fn theme_colors_from_yaml_rejects_bad_color() {
+        let yaml = ThemeRegistry::get("nord").to_yaml().unwrap();
+        let broken = yaml.replacen("heading: '#", "heading: '#zz", 1);
+        assert!(Theme::from_yaml(&broken).is_err());
+    }
+
+    #[test]
+    fn ensured_contrast_adjusts_low_contrast_body() {
+        let mut theme = Theme::test_default();
+        theme.colors.ui_background = Color::new(40, 40, 40);
+        theme.colors.body = Color::new(80, 80, 80);
+        assert!(contrast_ratio(theme.body, theme.ui_background) < MIN_CONTRAST);
+
+        let adjusted = theme.clone().with_ensured_contrast();
+        assert_ne!(adjusted.body, theme.body);
+        assert!(contrast_ratio(adjusted.body, adjusted.ui_background) >= MIN_CONTRAST);
+        assert!(
+            adjusted.body.r > theme.body.r,
+            "body should lighten on a dark background"
+        );
+        assert_eq!(adjusted.heading, theme.heading);
+    }
+
+    #[test]
+    fn ensured_contrast_unreachable_uses_readable_fg() {
+        let mut theme = Theme::test_default();
+        theme.colors.ui_background = Color::new(119, 119, 119);
+        theme.colors.body = Color::new(130, 130, 130);
+
+        let adjusted = theme.with_ensured_contrast();
+        assert_eq!(adjusted.body, readable_fg(adjusted.ui_background));
+        assert!(contrast_ratio(adjusted.body, adjusted.ui_background) < MIN_CONTRAST);
+    }
+
+    #[test]
+    fn ensured_contrast_keeps_high_contrast_body() {
+        let theme = Theme::test_default();
+        assert!(contrast_ratio(theme.body, theme.ui_background) >= MIN_CONTRAST);
+        assert_eq!(theme.clone().with_ensured_contrast(), theme);
+    }
+
+    #[test]
+    fn random_theme_is_deterministic_for_a_seed() {
+        let themes = ThemeRegistry::available_themes();
+        let picked = ThemeRegistry::random_name_with_seed(42);
+
+        assert!(themes.contains(&picked));
+        assert_eq!(ThemeRegistry::random_name_with_seed(42), picked);
+
+        let spread: std::collections::HashSet<_> = (0..200).map(ThemeRegistry::random_name_with_seed).collect();
+        assert_eq!(spread.len(), themes.len());
+
+        assert_eq!(ThemeRegistry::random_name_from(Some(" 42 ")), picked);
+        assert!(themes.contains(&ThemeRegistry::random_name_from(Some("not-a-seed"))));
+        assert!(themes.contains(&ThemeRegistry::random_name_from(None)));
+    }
+
+    #[test]
+    fn dim_color_scales_channels() {
+        let color = Color::new(200, 100, 51);
+        assert_eq!(dim_color(color, 0.5), Color::new(100, 50, 26));
+        assert_eq!(dim_color(color, 1.0), color);
+        assert_eq!(dim_color(color, 0.0), Color::new(0, 0, 0));
+        assert_eq!(dim_color(color, 2.0), color);
+        assert_eq!(dim_color(color, -1.0), Color::new(0, 0, 0));
+    }
+
+    #[test]
+    fn readable_fg_contrasts_with_background() {
+        let light_on_dark = readable_fg(Color::new(30, 30, 60));
+        assert!(light_on_dark.r > 200 && light_on_dark.g > 200 && light_on_dark.b > 200);
+
+        let dark_on_light = readable_fg(Color::new(250, 220, 120));
+        assert!(dark_on_light.r < 50 && dark_on_light.g < 50 && dark_on_light.b < 50);
+
+        assert_eq!(readable_fg(Color::new(0, 0, 0)), readable_fg(Color::new(0, 0, 128)));
+        assert_ne!(readable_fg(Color::new(255, 255, 255)), readable_fg(Color::new(0, 0, 0)));
+    }
 }
@@ -1,9 +1,17 @@
 use crate::error::{Result, SlideError};
+use crate::highlighter;
 use crate::metadata::Meta;
 use crate::parser::parse_slides_with_meta;
-use crate::theme::{Base16Scheme, ThemeColors, ThemeRegistry};
+use crate::slide::{Block, List, Slide};
+use crate::theme::{Base16Scheme, Color, Theme, ThemeRegistry};
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// File extensions treated as image assets by [unused_assets]
+const IMAGE_EXTENSIONS: [&str; 7] = ["png", "jpg", "jpeg", "gif", "webp", "bmp", "svg"];
+
+/// Theme names that [ThemeRegistry::get] resolves to a built-in theme rather than naming one
+const THEME_ALIASES: [&str; 3] = ["default", "auto", "random"];
 
 /// Validation result containing errors and warnings
 #[derive(Debug, Clone, Default)]
@@ -74,17 +82,108 @@ pub fn validate_slides(file_path: &Path, strict: bool) -> ValidationResult {
     result
 }
 
+/// Image files in the deck's asset directories that no slide references, relative to the deck's directory and sorted
+///
+/// The asset directories are the deck's own directory and every directory inside it that a slide loads an image
+/// from; none are searched recursively, so unrelated trees next to the deck are left alone. Image blocks and
+/// `bg-image` directives count as references; remote URLs are ignored.
+pub fn unused_assets(file_path: &Path) -> Result<Vec<PathBuf>> {
+    let markdown = std::fs::read_to_string(file_path)?;
+    let (_, slides) = parse_slides_with_meta(&markdown)?;
+    let deck_dir = canonical(
+        file_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new(".")),
+    );
+
+    let referenced: Vec<PathBuf> = slides
+        .iter()
+        .flat_map(Slide::image_paths)
+        .filter(|path| !path.contains("://"))
+        .map(|path| canonical(&deck_dir.join(path)))
+        .collect();
+
+    let mut asset_dirs = vec![deck_dir.clone()];
+    for dir in referenced.iter().filter_map(|path| path.parent()) {
+        if dir.starts_with(&deck_dir) && dir.is_dir() && !asset_dirs.iter().any(|known| known == dir) {
+            asset_dirs.push(dir.to_path_buf());
+        }
+    }
+
+    let mut assets = Vec::new();
+    for dir in &asset_dirs {
+        collect_image_files(dir, &mut assets)?;
+    }
+
+    let mut unused: Vec<PathBuf> = assets
+        .into_iter()
+        .filter(|asset| !referenced.contains(&canonical(asset)))
+        .map(|asset| asset.strip_prefix(&deck_dir).map(Path::to_path_buf).unwrap_or(asset))
+        .collect();
+    unused.sort();
+    Ok(unused)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Gather the files with an image extension directly in `dir`, skipping hidden entries
+fn collect_image_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if !hidden && path.is_file() && is_image_file(&path) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_image_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_string_lossy().to_ascii_lowercase().as_str()))
+}
+
 /// Validate metadata fields
 fn validate_metadata(meta: &Meta, result: &mut ValidationResult) {
-    if meta.theme != "default" && !ThemeRegistry::available_themes().contains(&meta.theme.as_str()) {
+    for name in meta.theme.names() {
+        if !THEME_ALIASES.contains(&name) && !ThemeRegistry::available_themes().contains(&name) {
+            result.add_warning(format!(
+                "Theme '{}' is not a built-in theme. Available themes: {}",
+                name,
+                ThemeRegistry::available_themes().join(", ")
+            ));
+        }
+    }
+
+    if let Some(code_theme) = &meta.code_theme
+        && !highlighter::is_known_code_theme(code_theme)
+    {
         result.add_warning(format!(
-            "Theme '{}' is not a built-in theme. Available themes: {}",
-            meta.theme,
-            ThemeRegistry::available_themes().join(", ")
+            "Code theme '{}' is not a known syntax theme. Available code themes: {}",
+            code_theme,
+            highlighter::available_code_themes().join(", ")
         ));
     }
 
-    if meta.author == "Unknown" {
+    for (name, custom) in &meta.admonitions {
+        if Color::from_hex(&custom.color).is_none() {
+            result.add_warning(format!(
+                "Custom admonition '{name}' has invalid color '{}' (expected #RRGGBB)",
+                custom.color
+            ));
+        }
+    }
+
+    for warning in meta.shadowed_admonition_warnings() {
+        result.add_warning(warning);
+    }
+
+    if meta.author.is_empty() || meta.author == ["Unknown"] {
         result.add_warning("No author specified in frontmatter".to_string());
     }
 }
@@ -94,10 +193,68 @@ fn validate_slide_content(slides: &[crate::slide::Slide], result: &mut Validatio
     for (idx, slide) in slides.iter().enumerate() {
         if slide.blocks.is_empty() {
             result.add_warning(format!("Slide {} is empty", idx + 1));
+        } else if renders_blank(&slide.blocks) {
+            result.add_warning(format!("Slide {} is empty: it only has rules or whitespace", idx + 1));
+        }
+
+        validate_code_indentation(&slide.blocks, idx + 1, result);
+    }
+}
+
+/// Whether every block draws nothing but a rule or whitespace, leaving the slide blank
+fn renders_blank(blocks: &[Block]) -> bool {
+    blocks.iter().all(|block| match block {
+        Block::Rule => true,
+        Block::Paragraph { spans } => spans.iter().all(|span| span.text.trim().is_empty()),
+        _ => false,
+    })
+}
+
+/// Warn about code blocks whose leading indentation mixes tabs and spaces
+fn validate_code_indentation(blocks: &[Block], slide_number: usize, result: &mut ValidationResult) {
+    for block in blocks {
+        match block {
+            Block::Code(code) if has_mixed_indentation(&code.code) => {
+                let lang = code.language.as_deref().unwrap_or("plain");
+                result.add_warning(format!(
+                    "Slide {slide_number}: {lang} code block mixes tabs and spaces in indentation"
+                ));
+            }
+            Block::BlockQuote { blocks } => validate_code_indentation(blocks, slide_number, result),
+            Block::Admonition(admonition) => validate_code_indentation(&admonition.blocks, slide_number, result),
+            Block::List(list) => validate_list_code_indentation(list, slide_number, result),
+            _ => {}
         }
     }
 }
 
+/// Run [validate_code_indentation] over the blocks of each list item, descending into nested lists
+fn validate_list_code_indentation(list: &List, slide_number: usize, result: &mut ValidationResult) {
+    for item in &list.items {
+        validate_code_indentation(&item.blocks, slide_number, result);
+        if let Some(nested) = &item.nested {
+            validate_list_code_indentation(nested, slide_number, result);
+        }
+    }
+}
+
+/// Check whether leading whitespace across the lines of `code` uses both tabs and spaces
+fn has_mixed_indentation(code: &str) -> bool {
+    let (mut tabs, mut spaces) = (false, false);
+    for line in code.lines() {
+        for ch in line.chars().take_while(|c| *c == ' ' || *c == '\t') {
+            match ch {
+                '\t' => tabs = true,
+                _ => spaces = true,
+            }
+        }
+        if tabs && spaces {
+            return true;
+        }
+    }
+    false
+}
+
 /// Validate a theme file
 ///
 /// Checks for:
@@ -195,10 +352,10 @@ fn validate_hex_color(name: &str, hex: &str, result: &mut ValidationResult) {
 /// Validate theme by name
 ///
 /// Checks if the theme exists in the built-in registry
-pub fn validate_theme_name(name: &str) -> Result<ThemeColors> {
+pub fn validate_theme_name(name: &str) -> Result<Theme> {
     let available = ThemeRegistry::available_themes();
 
-    if available.contains(&name) || name == "default" {
+    if available.contains(&name) || THEME_ALIASES.contains(&name) {
         Ok(ThemeRegistry::get(name))
     } else {
         Err(SlideError::theme_error(format!(
@@ -212,6 +369,7 @@ pub fn validate_theme_name(name: &str) -> Result<ThemeColors> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::slide::TextSpan;
 
     #[test]
     fn validate_slides_nonexistent_file() {
@@ -235,6 +393,41 @@ mod tests {
         std::fs::remove_file(&test_file).ok();
     }
 
+    #[test]
+    fn validate_slides_warns_for_blank_slides() {
+        let test_file = std::env::temp_dir().join("test_blank_slides_validation.md");
+        let content = "---\nauthor: Test\n---\n# Intro\n\n---\n\n&nbsp;\n\n---\n\n***\n\n---\n\n# End\n\n***";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        std::fs::remove_file(&test_file).ok();
+
+        assert_eq!(
+            result.warnings,
+            [
+                "Slide 2 is empty: it only has rules or whitespace",
+                "Slide 3 is empty: it only has rules or whitespace",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_blank_ignores_rules_and_whitespace_only() {
+        let whitespace = Block::Paragraph { spans: vec![TextSpan::plain(" \u{a0}\t")] };
+        assert!(renders_blank(std::slice::from_ref(&whitespace)));
+        assert!(renders_blank(&[Block::Rule]));
+        assert!(renders_blank(&[
+            Block::Rule,
+            whitespace,
+            Block::Paragraph { spans: vec![] }
+        ]));
+        assert!(!renders_blank(&[
+            Block::Rule,
+            Block::Paragraph { spans: vec![TextSpan::plain("Hi")] }
+        ]));
+        assert!(!renders_blank(&[Block::PageBreak]));
+    }
+
     #[test]
     fn validate_slides_valid_content() {
         let temp_dir = std::env::temp_dir();
@@ -276,6 +469,130 @@ mod tests {
         std::fs::remove_file(&test_file).ok();
     }
 
+    #[test]
+    fn validate_slides_unknown_code_theme_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_code_theme_validation.md");
+        let content = "---\nauthor: Test\ncode_theme: not-a-real-theme\n---\n# Slide 1\n\nContent";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(result.is_valid());
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("Code theme 'not-a-real-theme'"))
+        );
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn validate_slides_known_code_theme_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_known_code_theme_validation.md");
+        let content = "---\nauthor: Test\ncode_theme: InspiredGitHub\n---\n# Slide 1\n\nContent";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(!result.warnings.iter().any(|w| w.contains("Code theme")));
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn validate_slides_custom_admonition_bad_color_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_custom_admonition_validation.md");
+        let content = "---\nauthor: Test\nadmonitions:\n  glossary:\n    icon: G\n    color: blue\n---\n# Slide 1\n";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("glossary") && w.contains("invalid color"))
+        );
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn validate_slides_mixed_indent_code_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_mixed_indent_validation.md");
+        let content = "# Slide 1\n\n```rust\nfn main() {\n\tlet a = 1;\n    let b = 2;\n}\n```\n";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("Slide 1") && w.contains("mixes tabs and spaces"))
+        );
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn validate_slides_mixed_indent_code_in_list_item_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_mixed_indent_list_validation.md");
+        let content = "# Slide 1\n\n- Step\n  - Nested step\n\n    ```sh\n    if true; then\n    \techo tab\n        echo spaces\n    fi\n    ```\n";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.contains("Slide 1") && w.contains("sh code block mixes tabs and spaces"))
+        );
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn validate_slides_consistent_indent_code_strict() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_consistent_indent_validation.md");
+        let content = "# Slide 1\n\n```rust\nfn main() {\n    let a = 1;\n    let b = 2;\n}\n```\n";
+        std::fs::write(&test_file, content).expect("Failed to write test file");
+
+        let result = validate_slides(&test_file, true);
+        assert!(!result.warnings.iter().any(|w| w.contains("mixes tabs and spaces")));
+
+        std::fs::remove_file(&test_file).ok();
+    }
+
+    #[test]
+    fn unused_assets_reports_only_orphans() {
+        let deck_dir = std::env::temp_dir().join(format!("lantern_unused_assets_{}", std::process::id()));
+        std::fs::create_dir_all(deck_dir.join("images")).unwrap();
+        std::fs::create_dir_all(deck_dir.join("vendor/icons")).unwrap();
+        std::fs::write(deck_dir.join("images/used.png"), b"png").unwrap();
+        std::fs::write(deck_dir.join("images/stale.png"), b"png").unwrap();
+        std::fs::write(deck_dir.join("vendor/icons/skip.png"), b"png").unwrap();
+        std::fs::write(deck_dir.join("orphan.png"), b"png").unwrap();
+        std::fs::write(deck_dir.join("notes.txt"), b"text").unwrap();
+        let deck = deck_dir.join("deck.md");
+        std::fs::write(
+            &deck,
+            "# Slide\n\n![Used](images/used.png)\n\n![Remote](https://example.com/a.png)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            unused_assets(&deck).unwrap(),
+            vec![PathBuf::from("images/stale.png"), PathBuf::from("orphan.png")]
+        );
+
+        std::fs::remove_dir_all(&deck_dir).ok();
+    }
+
     #[test]
     fn validate_theme_file_invalid_yaml() {
         let temp_dir = std::env::temp_dir();
@@ -419,6 +736,12 @@ palette:
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn validate_theme_name_auto() {
+        let result = validate_theme_name("auto");
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn validate_theme_name_invalid() {
         let result = validate_theme_name("nonexistent-theme");
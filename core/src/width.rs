@@ -0,0 +1,38 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Terminal columns `text` occupies, measured one grapheme cluster at a time
+///
+/// Emoji joined with ZWJ, flags, skin-tone modifiers, and letters with combining marks each count as a single unit
+/// instead of the sum of their code points.
+pub fn display_width(text: &str) -> usize {
+    text.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Grapheme clusters of `text` paired with their display widths, for splitting text without breaking a cluster
+pub fn graphemes(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    text.graphemes(true).map(|grapheme| (grapheme, grapheme.width()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_counts_zwj_emoji_once() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(display_width(family), 2);
+        assert_eq!(display_width(&format!("a{family}b")), 4);
+        assert_eq!(graphemes(family).collect::<Vec<_>>(), vec![(family, 2)]);
+    }
+
+    #[test]
+    fn display_width_counts_combining_marks_with_their_base() {
+        let accented = "cafe\u{301}";
+        assert_eq!(display_width(accented), 4);
+        assert_eq!(graphemes(accented).last(), Some(("e\u{301}", 1)));
+        assert_eq!(display_width("\u{1F1FA}\u{1F1F8}"), 2);
+        assert_eq!(display_width("\u{1F44D}\u{1F3FD}"), 2);
+        assert_eq!(display_width(""), 0);
+    }
+}
@@ -1,14 +1,37 @@
-use lantern_core::{metadata::Meta, slide::Slide, term::InputEvent, theme::ThemeColors};
+use lantern_core::{
+    metadata::Meta,
+    slide::{Slide, TransitionAxis, TransitionDirection},
+    term::{InputEvent, KeyMap},
+    theme::{Theme, dim_color},
+};
 use ratatui::{
     Terminal as RatatuiTerminal,
     backend::Backend,
+    buffer::Buffer,
+    layout::Rect,
     style::{Color, Style},
     widgets::Block,
 };
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use crate::{layout::SlideLayout, viewer::SlideViewer};
+use crate::{layout::SlideLayout, source::source_status, viewer::SlideViewer};
+
+/// A slide that failed to render in [App::render_all]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderFailure {
+    /// 1-based slide number
+    pub slide: usize,
+    pub reason: String,
+}
+
+impl std::fmt::Display for RenderFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "slide {}: {}", self.slide, self.reason)
+    }
+}
 
 /// Main TUI application coordinator
 ///
@@ -17,26 +40,143 @@ pub struct App {
     viewer: SlideViewer,
     layout: SlideLayout,
     should_quit: bool,
-    theme: ThemeColors,
+    theme: Theme,
     help_visible: bool,
+    on_slide_change: Option<Box<dyn FnMut(usize)>>,
+    speaker_file: Option<PathBuf>,
+    idle_dim_after: Option<Duration>,
+    last_input: Instant,
+    keymap: KeyMap,
+    transition_started: Option<Instant>,
+}
+
+/// Input wait while something on screen moves between keys, about 20 redraws a second
+pub const ACTIVE_POLL_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Input wait when nothing moves between keys; short enough for the elapsed clock and idle dimming
+pub const IDLE_POLL_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// What the event loop is doing, for [poll_timeout]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PollState {
+    /// A slide transition is being animated
+    pub transition_active: bool,
+}
+
+/// How long the event loop waits for input before redrawing
+pub fn poll_timeout(state: PollState) -> Duration {
+    if state.transition_active { ACTIVE_POLL_TIMEOUT } else { IDLE_POLL_TIMEOUT }
+}
+
+/// How long a slide with a `transition` hint takes to move into place
+pub const TRANSITION_DURATION: Duration = Duration::from_millis(200);
+
+/// Cells the entering `slide` is shifted by within `area`, `progress` (`0.0..=1.0`) of the way into its transition
+///
+/// The slide starts a full width or height away along its [transition axis](Slide::transition_axis): right of or
+/// below the area for `left`/`up`, left of or above it for `right`/`down`.
+pub fn transition_offset(slide: &Slide, area: Rect, progress: f32) -> (i32, i32) {
+    let remaining = 1.0 - progress.clamp(0.0, 1.0);
+    let sign = match slide.transition {
+        Some(TransitionDirection::Right | TransitionDirection::Down) => -1.0,
+        _ => 1.0,
+    };
+    let distance = |extent: u16| (f32::from(extent) * remaining * sign).round() as i32;
+
+    match slide.transition_axis() {
+        TransitionAxis::Horizontal => (distance(area.width), 0),
+        TransitionAxis::Vertical => (0, distance(area.height)),
+    }
+}
+
+/// Brightness the screen is dimmed to after the `idle_dim` timeout
+const IDLE_DIM_FACTOR: f32 = 0.3;
+
+/// Whether the presenter has gone `after` without input since `last_input`; never when `after` is `None`
+pub fn is_idle(last_input: Instant, now: Instant, after: Option<Duration>) -> bool {
+    after.is_some_and(|after| now.saturating_duration_since(last_input) >= after)
 }
 
 impl App {
     /// Create a new presentation application
-    pub fn new(slides: Vec<Slide>, theme: ThemeColors, filename: String, meta: Meta) -> Self {
-        let viewer = SlideViewer::with_context(
+    pub fn new(slides: Vec<Slide>, theme: Theme, filename: String, meta: Meta) -> Self {
+        let mut viewer = SlideViewer::with_context(
             slides,
-            theme,
+            theme.clone(),
             Some(filename.clone()),
-            meta.theme.clone(),
+            meta.theme.resolve(),
             Some(Instant::now()),
         );
+        viewer.set_author(meta.author_display());
+        viewer.set_image_fit(meta.image_fit);
+        viewer.set_image_max_height(meta.image_max_height);
+        viewer.set_show_slide_number(meta.show_slide_number);
+        viewer.set_end_slide(meta.end_slide);
+        viewer.set_loop(meta.loop_slides);
+        viewer.set_notes_markdown(meta.notes_markdown);
+        let idle_dim_after = meta.idle_dim.map(Duration::from_secs);
+
+        Self {
+            viewer,
+            layout: SlideLayout::default(),
+            should_quit: false,
+            theme,
+            help_visible: false,
+            on_slide_change: None,
+            speaker_file: None,
+            idle_dim_after,
+            last_input: Instant::now(),
+            keymap: KeyMap::default(),
+            transition_started: None,
+        }
+    }
 
-        Self { viewer, layout: SlideLayout::default(), should_quit: false, theme, help_visible: false }
+    /// Map keys through `keymap` instead of the default bindings; the help overlay lists the same bindings
+    pub fn set_keymap(&mut self, keymap: KeyMap) {
+        self.keymap = keymap;
+    }
+
+    /// Show the source file's modification time (and commit with the `git` feature) in the status bar
+    pub fn set_source_path(&mut self, path: &Path) {
+        self.viewer.set_source_status(source_status(path));
+    }
+
+    /// Register a hook called with the new slide index whenever navigation changes the current slide
+    pub fn set_on_slide_change(&mut self, callback: impl FnMut(usize) + 'static) {
+        self.on_slide_change = Some(Box::new(callback));
+    }
+
+    /// Write the [presenter view](SlideViewer::presenter_view) to `path` now and after every slide change
+    ///
+    /// Meant to be followed from a second terminal, e.g. with `watch cat <path>`.
+    pub fn set_speaker_file(&mut self, path: PathBuf) {
+        self.speaker_file = Some(path);
+        self.write_speaker_file();
+    }
+
+    fn write_speaker_file(&self) {
+        if let Some(path) = &self.speaker_file
+            && let Err(e) = std::fs::write(path, self.viewer.presenter_view())
+        {
+            tracing::warn!("Failed to write speaker file {}: {e}", path.display());
+        }
     }
 
     /// Run the main event loop
     pub fn run<B: Backend>(&mut self, terminal: &mut RatatuiTerminal<B>) -> io::Result<()> {
+        let keymap = self.keymap.clone();
+        self.run_with_events(terminal, |timeout| InputEvent::poll_with(timeout, &keymap))
+    }
+
+    /// Run the main event loop, pulling input from `next_event` instead of the terminal
+    ///
+    /// `next_event` gets the [poll_timeout] to wait and returns `Ok(None)` when no input arrived in time; the loop
+    /// redraws and asks again until a quit event.
+    pub fn run_with_events<B, F>(&mut self, terminal: &mut RatatuiTerminal<B>, mut next_event: F) -> io::Result<()>
+    where
+        B: Backend,
+        F: FnMut(Duration) -> io::Result<Option<InputEvent>>,
+    {
         loop {
             terminal.draw(|frame| self.draw(frame))?;
 
@@ -44,14 +184,78 @@ impl App {
                 break;
             }
 
-            if let Some(event) = InputEvent::poll(Duration::from_millis(50))? {
-                self.handle_event(event);
+            if let Some(event) = next_event(poll_timeout(self.poll_state()))? {
+                // The key that wakes a dimmed screen only restores it
+                let waking = self.is_dimmed();
+                self.last_input = Instant::now();
+                if !waking {
+                    self.handle_event(event);
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Draw every page of every slide once, collecting the slides that fail instead of stopping
+    ///
+    /// A slide fails when drawing it panics or errors, or when one of its images can't be loaded. Used to smoke-test
+    /// a deck against an off-screen backend; the viewer is left on the first slide.
+    pub fn render_all<B: Backend>(&mut self, terminal: &mut RatatuiTerminal<B>) -> Vec<RenderFailure> {
+        let mut failures = Vec::new();
+
+        for slide in 1..=self.viewer.total_slides() {
+            self.viewer.jump_to(slide);
+            for reason in self.viewer.image_errors() {
+                failures.push(RenderFailure { slide, reason });
+            }
+
+            for _ in 0..self.viewer.page_count() {
+                let drawn = panic::catch_unwind(AssertUnwindSafe(|| terminal.draw(|frame| self.draw(frame)).err()));
+                let reason = match drawn {
+                    Ok(None) => None,
+                    Ok(Some(err)) => Some(err.to_string()),
+                    Err(payload) => Some(panic_message(payload.as_ref())),
+                };
+                if let Some(reason) = reason {
+                    failures.push(RenderFailure { slide, reason });
+                    break;
+                }
+                self.viewer.next();
+            }
+        }
+
+        self.viewer.jump_to(1);
+        failures
+    }
+
+    fn poll_state(&self) -> PollState {
+        PollState { transition_active: self.transition_progress().is_some() }
+    }
+
+    /// Fraction of [TRANSITION_DURATION] since the last hinted slide change, or `None` once it has finished
+    fn transition_progress(&self) -> Option<f32> {
+        let elapsed = self.transition_started?.elapsed();
+        (elapsed < TRANSITION_DURATION).then(|| elapsed.as_secs_f32() / TRANSITION_DURATION.as_secs_f32())
+    }
+
+    /// Shift the freshly drawn slide in `area` toward where its transition starts
+    fn animate_transition(&mut self, buffer: &mut Buffer, area: Rect, background: Color) {
+        let Some(progress) = self.transition_progress() else {
+            self.transition_started = None;
+            return;
+        };
+
+        if let Some(slide) = self.viewer.current_slide() {
+            let (dx, dy) = transition_offset(slide, area, progress);
+            shift_area(buffer, area, dx, dy, background);
+        }
+    }
+
+    fn is_dimmed(&self) -> bool {
+        is_idle(self.last_input, Instant::now(), self.idle_dim_after)
+    }
+
     fn toggle_notes(&mut self) {
         self.viewer.toggle_notes();
         self.layout.set_show_notes(self.viewer.is_showing_notes())
@@ -64,14 +268,38 @@ impl App {
 
     /// Handle input events
     fn handle_event(&mut self, event: InputEvent) {
+        let previous_index = self.viewer.current_index();
+
         match event {
+            InputEvent::Next if self.viewer.is_showing_preview() => self.viewer.scroll_preview(1),
+            InputEvent::Previous if self.viewer.is_showing_preview() => self.viewer.scroll_preview(-1),
             InputEvent::Next => self.viewer.next(),
             InputEvent::Previous => self.viewer.previous(),
+            InputEvent::TogglePreview => self.viewer.toggle_preview(),
+            InputEvent::ToggleFold if self.viewer.is_showing_overview() => self.viewer.toggle_overview(),
+            InputEvent::ToggleOverview => self.viewer.toggle_overview(),
+            InputEvent::ScrollLeft => self.viewer.scroll_horizontal(-4),
+            InputEvent::ScrollRight => self.viewer.scroll_horizontal(4),
+            InputEvent::FocusCode => self.viewer.cycle_code_focus(),
+            InputEvent::ToggleFold => self.viewer.toggle_folds(),
             InputEvent::ToggleNotes => self.toggle_notes(),
             InputEvent::ToggleHelp => self.toggle_help(),
             InputEvent::Quit => self.should_quit = true,
             InputEvent::Resize { .. } | InputEvent::Search | InputEvent::Other => {}
         }
+
+        let index = self.viewer.current_index();
+        if index != previous_index {
+            let hinted = self
+                .viewer
+                .current_slide()
+                .is_some_and(|slide| slide.transition.is_some());
+            self.transition_started = hinted.then(Instant::now);
+            self.write_speaker_file();
+            if let Some(callback) = &mut self.on_slide_change {
+                callback(index);
+            }
+        }
     }
 
     /// Draw the UI
@@ -87,7 +315,12 @@ impl App {
 
         let (main_area, notes_area, status_area, help_area) = self.layout.calculate(frame.area());
 
-        self.viewer.render(frame, main_area);
+        if self.viewer.is_showing_overview() {
+            self.viewer.render_overview(frame, main_area);
+        } else {
+            self.viewer.render(frame, main_area);
+            self.animate_transition(frame.buffer_mut(), main_area, bg_color);
+        }
 
         if let Some(notes_area) = notes_area {
             self.viewer.render_notes(frame, notes_area);
@@ -98,13 +331,78 @@ impl App {
         if let Some(help_area) = help_area {
             self.viewer.render_help_line(frame, help_area);
         }
+
+        self.viewer.render_preview(frame, main_area);
+
+        if self.help_visible {
+            let overlay_area = Rect { height: status_area.y.saturating_sub(frame.area().y), ..frame.area() };
+            self.viewer.render_help_overlay(frame, overlay_area, &self.keymap);
+        }
+
+        if self.is_dimmed() {
+            dim_buffer(frame.buffer_mut(), IDLE_DIM_FACTOR);
+        }
+    }
+}
+
+/// Move the cells of `area` by `(dx, dy)`, filling the uncovered edge with blank cells on `background`
+fn shift_area(buffer: &mut Buffer, area: Rect, dx: i32, dy: i32, background: Color) {
+    let snapshot = buffer.clone();
+    let inside = |x: i32, y: i32| {
+        (i32::from(area.left())..i32::from(area.right())).contains(&x)
+            && (i32::from(area.top())..i32::from(area.bottom())).contains(&y)
+    };
+
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let (from_x, from_y) = (i32::from(x) - dx, i32::from(y) - dy);
+            let cell = &mut buffer[(x, y)];
+            if inside(from_x, from_y) {
+                *cell = snapshot[(from_x as u16, from_y as u16)].clone();
+            } else {
+                cell.reset();
+                cell.set_bg(background);
+            }
+        }
     }
 }
 
+/// Darken every cell of `buffer`: RGB colors are scaled by `factor`, other foregrounds get the dim modifier
+fn dim_buffer(buffer: &mut ratatui::buffer::Buffer, factor: f32) {
+    let dim = |color: Color| match color {
+        Color::Rgb(r, g, b) => {
+            let dimmed = dim_color(lantern_core::theme::Color::new(r, g, b), factor);
+            Color::Rgb(dimmed.r, dimmed.g, dimmed.b)
+        }
+        other => other,
+    };
+
+    for cell in &mut buffer.content {
+        if !matches!(cell.fg, Color::Rgb(..)) {
+            cell.modifier |= ratatui::style::Modifier::DIM;
+        }
+        cell.fg = dim(cell.fg);
+        cell.bg = dim(cell.bg);
+    }
+}
+
+/// Text of a caught panic payload
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    format!("panicked: {message}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use lantern_core::slide::{Block, TextSpan};
+    use ratatui::backend::TestBackend;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     fn create_test_app() -> App {
         let slides = vec![
@@ -118,7 +416,7 @@ mod tests {
             }]),
         ];
 
-        App::new(slides, ThemeColors::default(), "test.md".to_string(), Meta::default())
+        App::new(slides, Theme::test_default(), "test.md".to_string(), Meta::default())
     }
 
     #[test]
@@ -170,6 +468,20 @@ mod tests {
         assert!(!app.should_quit);
     }
 
+    #[test]
+    fn app_preview_scrolls_instead_of_navigating() {
+        let mut app = create_test_app();
+        app.handle_event(InputEvent::TogglePreview);
+        assert!(app.viewer.is_showing_preview());
+
+        app.handle_event(InputEvent::Next);
+        assert_eq!(app.viewer.current_index(), 0);
+
+        app.handle_event(InputEvent::TogglePreview);
+        app.handle_event(InputEvent::Next);
+        assert_eq!(app.viewer.current_index(), 1);
+    }
+
     #[test]
     fn app_handle_toggle_help() {
         let mut app = create_test_app();
@@ -184,4 +496,228 @@ mod tests {
         assert!(!app.help_visible);
         assert!(!app.layout.is_showing_help());
     }
+
+    #[test]
+    fn app_draws_help_overlay_from_keymap() {
+        let mut app = create_test_app();
+        let mut keymap = KeyMap::default();
+        keymap.bindings[0]
+            .keys
+            .push(lantern_core::term::Key::any(crossterm::event::KeyCode::Char('x')));
+        app.set_keymap(keymap);
+        app.handle_event(InputEvent::ToggleHelp);
+
+        let mut terminal = RatatuiTerminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Keyboard Shortcuts"));
+        assert!(screen.contains("Space, n, x"));
+    }
+
+    #[test]
+    fn app_overview_picks_a_slide() {
+        let mut app = create_test_app();
+        app.handle_event(InputEvent::ToggleOverview);
+        app.handle_event(InputEvent::Next);
+        assert!(app.viewer.is_showing_overview());
+        assert_eq!(app.viewer.current_index(), 1);
+
+        let mut terminal = RatatuiTerminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        let titles = (0..buffer.area.height)
+            .map(row)
+            .find(|line| line.contains("1. Slide 1"))
+            .unwrap();
+        assert!(
+            titles.contains("2. Slide 2"),
+            "slides should share a grid row: {titles}"
+        );
+
+        app.handle_event(InputEvent::ToggleFold);
+        assert!(!app.viewer.is_showing_overview());
+        assert_eq!(app.viewer.current_index(), 1);
+    }
+
+    #[test]
+    fn app_speaker_file_follows_navigation() {
+        let path = std::env::temp_dir().join("lantern_app_speaker_file.txt");
+        let mut app = create_test_app();
+        app.set_speaker_file(path.clone());
+        assert!(std::fs::read_to_string(&path).unwrap().starts_with("Slide 1/"));
+
+        app.handle_event(InputEvent::Next);
+        assert!(std::fs::read_to_string(&path).unwrap().starts_with("Slide 2/"));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn poll_timeout_short_only_while_animating() {
+        assert_eq!(poll_timeout(PollState::default()), IDLE_POLL_TIMEOUT);
+        assert_eq!(poll_timeout(PollState { transition_active: true }), ACTIVE_POLL_TIMEOUT);
+        assert!(ACTIVE_POLL_TIMEOUT < IDLE_POLL_TIMEOUT);
+    }
+
+    #[test]
+    fn idle_after_timeout_only_when_enabled() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(90);
+
+        assert!(!is_idle(start, later, None));
+        assert!(!is_idle(start, later, Some(Duration::from_secs(120))));
+        assert!(is_idle(start, later, Some(Duration::from_secs(60))));
+        assert!(is_idle(start, later, Some(Duration::from_secs(90))));
+        assert!(!is_idle(later, start, Some(Duration::from_secs(60))));
+    }
+
+    #[test]
+    fn app_dims_when_idle_and_wake_key_only_restores() {
+        let slides = vec![Slide::new(), Slide::new()];
+        let meta = Meta { idle_dim: Some(1), ..Meta::default() };
+        let theme = Theme::test_default();
+        let mut app = App::new(slides, theme.clone(), "test.md".to_string(), meta);
+        app.last_input = Instant::now().checked_sub(Duration::from_secs(2)).unwrap();
+
+        let mut terminal = RatatuiTerminal::new(TestBackend::new(40, 10)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let dimmed = dim_color(theme.ui_background, IDLE_DIM_FACTOR);
+        assert_eq!(
+            terminal.backend().buffer()[(0, 0)].bg,
+            Color::Rgb(dimmed.r, dimmed.g, dimmed.b)
+        );
+
+        let mut events = vec![InputEvent::Next, InputEvent::Quit].into_iter();
+        app.run_with_events(&mut terminal, |_| Ok(events.next())).unwrap();
+        assert_eq!(app.viewer.current_index(), 0);
+        assert!(app.should_quit);
+        let background = theme.ui_background;
+        let restored = Color::Rgb(background.r, background.g, background.b);
+        assert_eq!(terminal.backend().buffer()[(0, 0)].bg, restored);
+    }
+
+    #[test]
+    fn app_slide_change_callback_follows_navigation() {
+        let mut app = create_test_app();
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&changes);
+        app.set_on_slide_change(move |index| recorded.borrow_mut().push(index));
+
+        let mut events = vec![
+            InputEvent::Next,
+            InputEvent::Next,
+            InputEvent::ToggleHelp,
+            InputEvent::Previous,
+            InputEvent::Previous,
+            InputEvent::Quit,
+        ]
+        .into_iter();
+        let mut terminal = RatatuiTerminal::new(TestBackend::new(60, 20)).unwrap();
+        app.run_with_events(&mut terminal, |_| Ok(events.next())).unwrap();
+
+        assert!(app.should_quit);
+        assert_eq!(*changes.borrow(), vec![1, 0]);
+    }
+
+    #[test]
+    fn transition_offset_follows_slide_axis_and_direction() {
+        let area = Rect::new(0, 0, 40, 10);
+        let mut slide = Slide::new();
+        slide.transition = Some(TransitionDirection::Left);
+        assert_eq!(transition_offset(&slide, area, 0.0), (40, 0));
+        assert_eq!(transition_offset(&slide, area, 0.5), (20, 0));
+        assert_eq!(transition_offset(&slide, area, 1.0), (0, 0));
+
+        slide.transition = Some(TransitionDirection::Right);
+        assert_eq!(transition_offset(&slide, area, 0.25), (-30, 0));
+        slide.transition = Some(TransitionDirection::Up);
+        assert_eq!(transition_offset(&slide, area, 0.5), (0, 5));
+        slide.transition = Some(TransitionDirection::Down);
+        assert_eq!(transition_offset(&slide, area, 0.0), (0, -10));
+    }
+
+    #[test]
+    fn shift_area_moves_cells_and_blanks_the_edge() {
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::empty(area);
+        buffer.set_string(0, 0, "abcd", Style::default());
+        buffer.set_string(0, 1, "efgh", Style::default());
+
+        shift_area(&mut buffer, area, 1, 0, Color::Blue);
+        assert_eq!(buffer[(0, 0)].symbol(), " ");
+        assert_eq!(buffer[(0, 0)].bg, Color::Blue);
+        assert_eq!(buffer[(1, 0)].symbol(), "a");
+        assert_eq!(buffer[(3, 1)].symbol(), "g");
+
+        shift_area(&mut buffer, area, 0, -1, Color::Blue);
+        assert_eq!(buffer[(1, 0)].symbol(), "e");
+        assert_eq!(buffer[(1, 1)].bg, Color::Blue);
+    }
+
+    #[test]
+    fn app_animates_only_hinted_slide_changes() {
+        let mut hinted = Slide::with_blocks(vec![Block::Heading {
+            level: 1,
+            spans: vec![TextSpan::plain("Detail")],
+        }]);
+        hinted.transition = Some(TransitionDirection::Up);
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Intro")] }]),
+            hinted,
+        ];
+        let mut app = App::new(slides, Theme::test_default(), "deck.md".to_string(), Meta::default());
+
+        assert_eq!(poll_timeout(app.poll_state()), IDLE_POLL_TIMEOUT);
+
+        app.handle_event(InputEvent::Next);
+        assert!(app.transition_progress().is_some());
+        assert_eq!(poll_timeout(app.poll_state()), ACTIVE_POLL_TIMEOUT);
+
+        app.handle_event(InputEvent::Previous);
+        assert!(app.transition_progress().is_none());
+        assert_eq!(poll_timeout(app.poll_state()), IDLE_POLL_TIMEOUT);
+    }
+
+    #[test]
+    fn app_render_all_reports_failing_slides() {
+        let mut app = create_test_app();
+        let mut terminal = RatatuiTerminal::new(TestBackend::new(80, 24)).unwrap();
+        assert_eq!(app.render_all(&mut terminal), vec![]);
+
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Fine")] }]),
+            Slide::with_blocks(vec![Block::Image {
+                path: "/nonexistent/logo.png".to_string(),
+                alt: "Logo".to_string(),
+                caption: None,
+            }]),
+        ];
+        let mut app = App::new(slides, Theme::test_default(), "deck.md".to_string(), Meta::default());
+        let failures = app.render_all(&mut terminal);
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].slide, 2);
+        assert!(failures[0].to_string().starts_with("slide 2: /nonexistent/logo.png: "));
+        assert_eq!(app.viewer.current_index(), 0);
+    }
+
+    #[test]
+    fn panic_message_reads_str_and_string_payloads() {
+        let caught = panic::catch_unwind(|| panic!("boom {}", 1)).unwrap_err();
+        assert_eq!(panic_message(caught.as_ref()), "panicked: boom 1");
+        assert_eq!(panic_message(&"static"), "panicked: static");
+        assert_eq!(panic_message(&42), "panicked: unknown panic");
+    }
 }
@@ -1,24 +1,73 @@
 use image::DynamicImage;
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
+use lantern_core::metadata::ImageFit;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use ratatui_image::{
+    picker::{Picker, ProtocolType},
+    protocol::StatefulProtocol,
+};
 use std::collections::HashMap;
-use std::io;
+use std::io::{self, IsTerminal};
 use std::path::{Path, PathBuf};
 
 /// Manages image loading and protocol state for terminal rendering
 ///
 /// Handles image loading from paths, protocol detection, and caching of loaded images.
+/// Images are cached by canonical path, so different spellings of the same file share one protocol.
 pub struct ImageManager {
     picker: Picker,
-    protocols: HashMap<String, StatefulProtocol>,
+    protocols: HashMap<PathBuf, StatefulProtocol>,
+    native_sizes: HashMap<PathBuf, (u16, u16)>,
+    /// Canonical cache key for each path spelling seen by [ImageManager::load_image]
+    keys: HashMap<String, PathBuf>,
+    /// Half-block renderings drawn when the terminal has no graphics protocol, keyed by path and cell size
+    halfblocks: HashMap<(PathBuf, u16, u16), Vec<Line<'static>>>,
     base_path: Option<PathBuf>,
 }
 
 impl ImageManager {
+    /// Font size (width, height) in pixels assumed when the terminal cannot be queried
+    pub const DEFAULT_FONT_SIZE: (u16, u16) = (8, 16);
+
     /// Create a new ImageManager with protocol detection
+    ///
+    /// When stdin is not a TTY (CI, pipes) the terminal query is skipped, since it can block or fail,
+    /// and the [ImageManager::new_non_interactive] picker is used instead.
     pub fn new() -> io::Result<Self> {
+        if !io::stdin().is_terminal() {
+            return Ok(Self::new_non_interactive());
+        }
+
         let picker = Picker::from_query_stdio().map_err(io::Error::other)?;
+        Ok(Self::with_picker(picker))
+    }
+
+    /// Create an ImageManager without querying the terminal
+    ///
+    /// Uses [ImageManager::DEFAULT_FONT_SIZE] and never touches stdin.
+    pub fn new_non_interactive() -> Self {
+        Self::with_picker(Picker::from_fontsize(Self::DEFAULT_FONT_SIZE))
+    }
+
+    fn with_picker(picker: Picker) -> Self {
+        Self {
+            picker,
+            protocols: HashMap::new(),
+            native_sizes: HashMap::new(),
+            keys: HashMap::new(),
+            halfblocks: HashMap::new(),
+            base_path: None,
+        }
+    }
 
-        Ok(Self { picker, protocols: HashMap::new(), base_path: None })
+    /// Whether the terminal query found a graphics protocol (Kitty, Sixel, iTerm2)
+    ///
+    /// `false` for the font-size fallback and for terminals that only support half blocks.
+    pub fn graphics_supported(&self) -> bool {
+        self.picker.protocol_type() != ProtocolType::Halfblocks
     }
 
     /// Set the base path for resolving relative image paths
@@ -30,24 +79,64 @@ impl ImageManager {
     ///
     /// Returns a reference to the protocol if successful.
     pub fn load_image(&mut self, path: &str) -> io::Result<&mut StatefulProtocol> {
-        if !self.protocols.contains_key(path) {
-            let image_path = self.resolve_path(path);
-            let dyn_img = load_image_from_path(&image_path)?;
+        let key = match self.keys.get(path) {
+            Some(key) => key.clone(),
+            None => {
+                let image_path = self.resolve_path(path);
+                let key = std::fs::canonicalize(&image_path).unwrap_or(image_path);
+                self.keys.insert(path.to_string(), key.clone());
+                key
+            }
+        };
+
+        if !self.protocols.contains_key(&key) {
+            let dyn_img = load_image_from_path(&key)?;
+            let (font_w, font_h) = self.picker.font_size();
+            let cells = (
+                dyn_img.width().div_ceil(font_w.max(1) as u32).min(u16::MAX as u32) as u16,
+                dyn_img.height().div_ceil(font_h.max(1) as u32).min(u16::MAX as u32) as u16,
+            );
+            self.native_sizes.insert(key.clone(), cells);
             let protocol = self.picker.new_resize_protocol(dyn_img);
-            self.protocols.insert(path.to_string(), protocol);
+            self.protocols.insert(key.clone(), protocol);
         }
 
-        Ok(self.protocols.get_mut(path).unwrap())
+        Ok(self.protocols.get_mut(&key).unwrap())
     }
 
     /// Check if an image is already loaded
     pub fn has_image(&self, path: &str) -> bool {
-        self.protocols.contains_key(path)
+        self.keys.get(path).is_some_and(|key| self.protocols.contains_key(key))
+    }
+
+    /// Native size of a loaded image in terminal cells (columns, rows)
+    pub fn native_size(&self, path: &str) -> Option<(u16, u16)> {
+        self.native_sizes.get(self.keys.get(path)?).copied()
     }
 
     /// Get a mutable reference to a loaded image protocol
     pub fn get_protocol_mut(&mut self, path: &str) -> Option<&mut StatefulProtocol> {
-        self.protocols.get_mut(path)
+        self.protocols.get_mut(self.keys.get(path)?)
+    }
+
+    /// Load the image at `path` (resolved like [ImageManager::load_image]) as a [halfblock_thumbnail]
+    ///
+    /// The image is decoded on every call; [ImageManager::halfblock_image] caches the result.
+    pub fn thumbnail(&self, path: &str, width: u16, height: u16) -> io::Result<Vec<Line<'static>>> {
+        let image = load_image_from_path(&self.resolve_path(path))?;
+        Ok(halfblock_thumbnail(&image, width, height))
+    }
+
+    /// [ImageManager::thumbnail] of `path` cached per size, for drawing slide images without graphics support
+    pub fn halfblock_image(&mut self, path: &str, width: u16, height: u16) -> io::Result<Vec<Line<'static>>> {
+        let key = (self.resolve_path(path), width, height);
+        if let Some(lines) = self.halfblocks.get(&key) {
+            return Ok(lines.clone());
+        }
+
+        let lines = self.thumbnail(path, width, height)?;
+        self.halfblocks.insert(key, lines.clone());
+        Ok(lines)
     }
 
     /// Resolve a path relative to the base path if set
@@ -58,10 +147,10 @@ impl ImageManager {
             return path.to_path_buf();
         }
 
-        if let Some(base) = &self.base_path {
-            if let Some(parent) = base.parent() {
-                return parent.join(path);
-            }
+        if let Some(base) = &self.base_path
+            && let Some(parent) = base.parent()
+        {
+            return parent.join(path);
         }
 
         path.to_path_buf()
@@ -70,12 +159,54 @@ impl ImageManager {
 
 impl Default for ImageManager {
     fn default() -> Self {
-        Self::new().unwrap_or_else(|_| Self {
-            picker: Picker::from_fontsize((8, 16)),
-            protocols: HashMap::new(),
-            base_path: None,
-        })
+        Self::new().unwrap_or_else(|_| Self::new_non_interactive())
+    }
+}
+
+/// Compute the (width, height) in cells an image of `native` cell size occupies in `area` under `mode`
+///
+/// Aspect ratio is preserved for [ImageFit::Fit] and [ImageFit::Contain]; only `Fit` may upscale.
+/// [ImageFit::Native] keeps the native size and crops to the area.
+pub fn fit_size(native: (u16, u16), area: Rect, mode: ImageFit) -> (u16, u16) {
+    let (native_w, native_h) = native;
+    if native_w == 0 || native_h == 0 || area.width == 0 || area.height == 0 {
+        return (0, 0);
+    }
+
+    let scale = (area.width as f64 / native_w as f64).min(area.height as f64 / native_h as f64);
+    let scale = match mode {
+        ImageFit::Fit => scale,
+        ImageFit::Contain => scale.min(1.0),
+        ImageFit::Native => return (native_w.min(area.width), native_h.min(area.height)),
+    };
+
+    let width = ((native_w as f64 * scale).floor() as u16).clamp(1, area.width);
+    let height = ((native_h as f64 * scale).floor() as u16).clamp(1, area.height);
+    (width, height)
+}
+
+/// Draw `image` with half-block characters in at most `width` × `height` cells, keeping its aspect ratio
+///
+/// Each cell is a `▀` colored with the upper pixel as foreground and the lower one as background, so it needs only
+/// truecolor support rather than a terminal graphics protocol.
+pub fn halfblock_thumbnail(image: &DynamicImage, width: u16, height: u16) -> Vec<Line<'static>> {
+    if width == 0 || height == 0 {
+        return Vec::new();
     }
+
+    let pixels = image.thumbnail(u32::from(width), u32::from(height) * 2).to_rgb8();
+    let rgb = |x: u32, y: u32| pixels.get_pixel_checked(x, y).map(|p| Color::Rgb(p[0], p[1], p[2]));
+    (0..pixels.height().div_ceil(2))
+        .map(|row| {
+            let cells: Vec<Span<'static>> = (0..pixels.width())
+                .map(|col| {
+                    let style = Style { fg: rgb(col, row * 2), bg: rgb(col, row * 2 + 1), ..Style::default() };
+                    Span::styled("▀", style)
+                })
+                .collect();
+            Line::from(cells)
+        })
+        .collect()
 }
 
 /// Load an image from a file path
@@ -113,9 +244,87 @@ mod tests {
         assert_eq!(resolved, PathBuf::from("test.png"));
     }
 
+    #[test]
+    fn graphics_supported_false_for_fallback_picker() {
+        let mut manager = ImageManager::new_non_interactive();
+        assert!(!manager.graphics_supported());
+
+        manager.picker.set_protocol_type(ProtocolType::Kitty);
+        assert!(manager.graphics_supported());
+    }
+
+    #[test]
+    fn new_non_interactive_uses_default_font_size() {
+        let manager = ImageManager::new_non_interactive();
+        assert_eq!(manager.picker.font_size(), ImageManager::DEFAULT_FONT_SIZE);
+        assert!(!manager.has_image("test.png"));
+    }
+
+    #[test]
+    fn load_image_shares_cache_entry_across_path_spellings() {
+        let dir = std::env::temp_dir().join(format!("lantern-logo-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let logo = dir.join("logo.png");
+        image::RgbImage::new(4, 4).save(&logo).unwrap();
+
+        let mut manager = ImageManager::new_non_interactive();
+        manager.set_base_path(dir.join("deck.md"));
+        for spelling in ["logo.png", "./logo.png", &logo.to_string_lossy()] {
+            assert!(manager.load_image(spelling).is_ok());
+        }
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(manager.protocols.len(), 1);
+        assert!(manager.has_image("./logo.png"));
+        assert_eq!(manager.native_size("logo.png"), manager.native_size("./logo.png"));
+    }
+
+    #[test]
+    fn halfblock_thumbnail_pairs_pixel_rows() {
+        let mut image = image::RgbImage::from_pixel(4, 4, image::Rgb([255, 0, 0]));
+        for x in 0..4 {
+            image.put_pixel(x, 1, image::Rgb([0, 0, 255]));
+        }
+        let lines = halfblock_thumbnail(&DynamicImage::ImageRgb8(image), 4, 2);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].spans.len(), 4);
+        assert_eq!(lines[0].spans[0].content, "▀");
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(lines[0].spans[0].style.bg, Some(Color::Rgb(0, 0, 255)));
+        assert!(halfblock_thumbnail(&DynamicImage::new_rgb8(4, 4), 0, 2).is_empty());
+    }
+
     #[test]
     fn has_image_returns_false_for_unloaded() {
         let manager = ImageManager::default();
         assert!(!manager.has_image("test.png"));
     }
+
+    #[test]
+    fn fit_size_fit_upscales_to_area() {
+        let area = Rect::new(0, 0, 80, 20);
+        assert_eq!(fit_size((10, 5), area, ImageFit::Fit), (40, 20));
+        assert_eq!(fit_size((160, 20), area, ImageFit::Fit), (80, 10));
+    }
+
+    #[test]
+    fn fit_size_contain_does_not_upscale() {
+        let area = Rect::new(0, 0, 80, 20);
+        assert_eq!(fit_size((10, 5), area, ImageFit::Contain), (10, 5));
+        assert_eq!(fit_size((160, 20), area, ImageFit::Contain), (80, 10));
+    }
+
+    #[test]
+    fn fit_size_native_crops_to_area() {
+        let area = Rect::new(0, 0, 80, 20);
+        assert_eq!(fit_size((10, 5), area, ImageFit::Native), (10, 5));
+        assert_eq!(fit_size((160, 40), area, ImageFit::Native), (80, 20));
+    }
+
+    #[test]
+    fn fit_size_empty_inputs() {
+        assert_eq!(fit_size((0, 5), Rect::new(0, 0, 10, 10), ImageFit::Fit), (0, 0));
+        assert_eq!(fit_size((10, 5), Rect::new(0, 0, 0, 10), ImageFit::Contain), (0, 0));
+    }
 }
@@ -81,6 +81,21 @@ impl SlideLayout {
     }
 }
 
+/// Target width of an overview grid cell in columns, borders included
+pub const OVERVIEW_CELL_WIDTH: u16 = 28;
+
+/// Height of an overview grid cell in rows, borders included
+pub const OVERVIEW_CELL_HEIGHT: u16 = 8;
+
+/// Number of columns for an overview grid of `slide_count` slides in `area_width` columns
+///
+/// Fits as many cells of about [OVERVIEW_CELL_WIDTH] as the width allows, then evens out the rows: 8 slides that
+/// would fit 7 to a row are laid out 4 by 2 rather than 7 and 1. Always at least one column.
+pub fn overview_columns(area_width: u16, slide_count: usize) -> usize {
+    let fit = usize::from((area_width / OVERVIEW_CELL_WIDTH).max(1));
+    let rows = slide_count.div_ceil(fit.min(slide_count.max(1)));
+    slide_count.div_ceil(rows.max(1)).max(1)
+}
 
 #[cfg(test)]
 mod tests {
@@ -112,6 +127,18 @@ mod tests {
         assert_eq!(status.height, 1);
     }
 
+    #[test]
+    fn overview_columns_adapt_to_width_and_count() {
+        assert_eq!(overview_columns(80, 10), 2);
+        assert_eq!(overview_columns(120, 12), 4);
+        assert_eq!(overview_columns(200, 3), 3);
+        assert_eq!(overview_columns(200, 8), 4);
+        assert_eq!(overview_columns(160, 7), 4);
+        assert_eq!(overview_columns(20, 5), 1);
+        assert_eq!(overview_columns(0, 0), 1);
+        assert_eq!(overview_columns(200, 0), 1);
+    }
+
     #[test]
     fn layout_toggle_notes() {
         let mut layout = SlideLayout::default();
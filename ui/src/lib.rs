@@ -2,15 +2,18 @@ pub mod app;
 pub mod image;
 pub mod layout;
 pub mod renderer;
+pub mod source;
 pub mod viewer;
 
-pub use app::App;
+pub use app::{App, RenderFailure};
 pub use image::ImageManager;
-pub use layout::SlideLayout;
-pub use renderer::{ImageInfo, render_slide_content, render_slide_with_images};
+pub use layout::{SlideLayout, overview_columns};
+pub use renderer::{
+    ImageInfo, render_overview_cell, render_slide_content, render_slide_content_wrapped, render_slide_with_images,
+};
 pub use viewer::SlideViewer;
 
 pub use lantern_core::{
     slide::{Block, Slide, TextSpan},
-    theme::ThemeColors,
+    theme::{Theme, ThemeColors},
 };
@@ -1,38 +1,126 @@
+use ansi_to_tui::IntoText;
 use lantern_core::{
-    highlighter,
-    slide::{Block, CodeBlock, List, Table, TextSpan, TextStyle},
-    theme::ThemeColors,
+    diff, highlighter,
+    slide::{Block, CodeBlock, Footnote, List, Slide, Table, TextSpan, TextStyle},
+    theme::{BlockquoteStyle, Theme},
+    width::display_width,
 };
 use ratatui::{
     style::{Modifier, Style},
     text::{Line, Span, Text},
 };
-use unicode_width::UnicodeWidthChar;
+
+use crate::image::ImageManager;
+
+/// Width rules, admonitions and table separators are drawn at when the target width isn't known
+const DEFAULT_BOX_WIDTH: usize = 60;
 
 /// Image information extracted from blocks
 pub struct ImageInfo {
     pub path: String,
     pub alt: String,
+    pub caption: Option<String>,
 }
 
 /// Render a slide's blocks and extract images
 ///
 /// Returns both the text content and a list of images found in the blocks.
-pub fn render_slide_with_images(blocks: &[Block], theme: &ThemeColors) -> (Text<'static>, Vec<ImageInfo>) {
+pub fn render_slide_with_images(blocks: &[Block], theme: &Theme) -> (Text<'static>, Vec<ImageInfo>) {
+    render_slide_with_focus(blocks, theme, None, None)
+}
+
+/// Render a section divider (see [lantern_core::slide::Slide::is_section_divider]) for centered display
+///
+/// The heading is drawn without its level prefix, underlined by an accent rule sized to the title (capped at
+/// `width`), with the subtitle dimmed below. Callers center the returned lines in the slide area.
+pub fn render_section_divider(blocks: &[Block], theme: &Theme, width: u16) -> Text<'static> {
+    let spans_of = |block: &Block| match block {
+        Block::Heading { spans, .. } | Block::Paragraph { spans } => Some(spans.clone()),
+        _ => None,
+    };
+    let spans_width = |spans: &[TextSpan]| spans.iter().map(|span| display_width(&span.text)).sum::<usize>();
+
+    let title = blocks.first().and_then(spans_of).unwrap_or_default();
+    let subtitle = blocks.get(1).and_then(spans_of);
+    let rule_width = spans_width(&title).max(subtitle.as_deref().map_or(0, spans_width)) + 4;
+
+    let mut lines = vec![
+        Line::from(
+            title
+                .iter()
+                .map(|span| create_span(span, theme, true))
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            "━".repeat(rule_width.min(width as usize)),
+            to_ratatui_style(&theme.accent, false),
+        )),
+    ];
+
+    if let Some(subtitle) = subtitle {
+        let style = to_ratatui_style(&theme.dimmed, false).add_modifier(Modifier::ITALIC);
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            subtitle
+                .iter()
+                .map(|span| Span::styled(span.text.clone(), style))
+                .collect::<Vec<_>>(),
+        ));
+    }
+
+    Text::from(lines)
+}
+
+/// Render a slide's blocks and extract images, dimming every code block except the `focused_code`-th
+///
+/// Only top-level code blocks are counted (see [code_block_count]); `None` renders all of them normally. With a
+/// `width`, content is laid out for that many columns as in [render_slide_content_wrapped].
+pub fn render_slide_with_focus(
+    blocks: &[Block], theme: &Theme, focused_code: Option<usize>, width: Option<u16>,
+) -> (Text<'static>, Vec<ImageInfo>) {
+    render_blocks(blocks, theme, focused_code, width.map(usize::from))
+}
+
+/// Render `blocks` into lines, collecting images instead of drawing them
+///
+/// `wrap` is the column count paragraphs are wrapped to and boxes are sized to; `None` leaves paragraphs on a
+/// single line for the caller to wrap and draws boxes [DEFAULT_BOX_WIDTH] wide.
+fn render_blocks(
+    blocks: &[Block], theme: &Theme, focused_code: Option<usize>, wrap: Option<usize>,
+) -> (Text<'static>, Vec<ImageInfo>) {
     let mut lines = Vec::new();
     let mut images = Vec::new();
+    let mut code_index = 0;
+    let box_width = wrap.unwrap_or(DEFAULT_BOX_WIDTH);
 
     for block in blocks {
         match block {
             Block::Heading { level, spans } => render_heading(*level, spans, theme, &mut lines),
-            Block::Paragraph { spans } => render_paragraph(spans, theme, &mut lines),
-            Block::Code(code_block) => render_code_block(code_block, theme, &mut lines),
-            Block::List(list) => render_list(list, theme, &mut lines, 0),
-            Block::Rule => render_rule(theme, &mut lines),
+            Block::Paragraph { spans } => render_paragraph(spans, theme, &mut lines, wrap),
+            Block::Code(code_block) => {
+                let start = lines.len();
+                render_code_block(code_block, theme, &mut lines);
+                if focused_code.is_some_and(|focused| focused != code_index) {
+                    for line in &mut lines[start..] {
+                        for span in &mut line.spans {
+                            span.style = span.style.add_modifier(Modifier::DIM);
+                        }
+                    }
+                }
+                code_index += 1;
+            }
+            Block::List(list) => render_list(list, theme, &mut lines, 0, wrap),
+            Block::Rule => render_rule(theme, &mut lines, box_width),
             Block::BlockQuote { blocks } => render_blockquote(blocks, theme, &mut lines),
-            Block::Table(table) => render_table(table, theme, &mut lines),
-            Block::Admonition(admonition) => render_admonition(admonition, theme, &mut lines),
-            Block::Image { path, alt } => images.push(ImageInfo { path: path.clone(), alt: alt.clone() }),
+            Block::Table(table) => render_table(table, theme, &mut lines, box_width),
+            Block::Admonition(admonition) => render_admonition(admonition, theme, &mut lines, box_width),
+            Block::Callout { icon, spans } => render_callout(icon, spans, theme, &mut lines, wrap),
+            Block::Image { path, alt, caption } => {
+                images.push(ImageInfo { path: path.clone(), alt: alt.clone(), caption: caption.clone() })
+            }
+            // Page breaks split a slide into pages in the viewer and take no space here
+            Block::PageBreak => continue,
         }
 
         lines.push(Line::raw(""));
@@ -41,55 +129,117 @@ pub fn render_slide_with_images(blocks: &[Block], theme: &ThemeColors) -> (Text<
     (Text::from(lines), images)
 }
 
-/// Render a slide's blocks into ratatui Text
+/// Contents of an overview grid cell `width` × `height` for the `number`th slide
 ///
-/// Converts slide blocks into styled ratatui text with theming applied.
-pub fn render_slide_content(blocks: &[Block], theme: &ThemeColors) -> Text<'static> {
-    let mut lines = Vec::new();
+/// A slide whose first block is an image gets a half-block thumbnail of it, cached in `images`, above the
+/// `number. title` line; other slides, and images that fail to load, get the title line alone.
+pub fn render_overview_cell(
+    slide: &Slide, number: usize, images: &mut ImageManager, theme: &Theme, width: u16, height: u16,
+) -> Vec<Line<'static>> {
+    let mut lines = match slide.blocks.first() {
+        Some(Block::Image { path, .. }) => images
+            .halfblock_image(path, width, height.saturating_sub(1))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
 
-    for block in blocks {
-        match block {
-            Block::Heading { level, spans } => render_heading(*level, spans, theme, &mut lines),
-            Block::Paragraph { spans } => render_paragraph(spans, theme, &mut lines),
-            Block::Code(code_block) => render_code_block(code_block, theme, &mut lines),
-            Block::List(list) => render_list(list, theme, &mut lines, 0),
-            Block::Rule => render_rule(theme, &mut lines),
-            Block::BlockQuote { blocks } => render_blockquote(blocks, theme, &mut lines),
-            Block::Table(table) => render_table(table, theme, &mut lines),
-            Block::Admonition(admonition) => render_admonition(admonition, theme, &mut lines),
-            // Images are handled separately when using render_slide_with_images
-            Block::Image { .. } => {}
+    let title = slide.title().unwrap_or_else(|| "Untitled".to_string());
+    lines.push(Line::from(Span::styled(
+        format!("{number}. {title}"),
+        to_ratatui_style(&theme.heading, false),
+    )));
+    lines
+}
+
+/// Render a slide's footnotes as dimmed `¹ text` lines under a short rule
+pub fn render_footnotes(footnotes: &[Footnote], theme: &Theme) -> Vec<Line<'static>> {
+    if footnotes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        "─".repeat(12),
+        to_ratatui_style(&theme.rule, false),
+    ))];
+    for footnote in footnotes {
+        let mut spans = vec![Span::raw(format!("{} ", footnote.marker()))];
+        spans.extend(footnote.spans.iter().map(|span| create_span(span, theme, false)));
+        for span in &mut spans {
+            span.style = span.style.add_modifier(Modifier::DIM);
         }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
 
-        lines.push(Line::raw(""));
+/// Number of top-level code blocks on a slide, the blocks `Tab` focus cycles through
+pub fn code_block_count(blocks: &[Block]) -> usize {
+    blocks.iter().filter(|block| matches!(block, Block::Code(_))).count()
+}
+
+/// Render a slide's blocks into ratatui Text
+///
+/// Converts slide blocks into styled ratatui text with theming applied. Images are handled separately by
+/// [render_slide_with_images].
+pub fn render_slide_content(blocks: &[Block], theme: &Theme) -> Text<'static> {
+    render_blocks(blocks, theme, None, None).0
+}
+
+/// Render a slide's blocks into ratatui Text laid out for `width` columns
+///
+/// Paragraphs are word-wrapped to `width` and rules, admonitions and table separators span it.
+pub fn render_slide_content_wrapped(blocks: &[Block], theme: &Theme, width: u16) -> Text<'static> {
+    render_blocks(blocks, theme, None, Some(width.into())).0
+}
+
+/// Number of terminal rows the rendered blocks occupy when word-wrapped at `width` columns
+pub fn content_height(blocks: &[Block], theme: &Theme, width: u16) -> u16 {
+    text_height(&render_slide_content(blocks, theme), width)
+}
+
+/// Number of terminal rows `text` occupies when word-wrapped at `width` columns
+///
+/// Mirrors the greedy word wrapping of `Paragraph` with `Wrap { trim: false }`.
+pub fn text_height(text: &Text, width: u16) -> u16 {
+    if width == 0 {
+        return text.height() as u16;
     }
 
-    Text::from(lines)
+    let rows: usize = text.lines.iter().map(|line| wrapped_rows(line, width as usize)).sum();
+    rows.min(u16::MAX as usize) as u16
 }
 
-/// Get heading prefix using Unicode block symbols
-/// 1. (*h1*) Large block / heavy fill (`U+2589`)
-/// 2. (*h2*) Dark shade (`U+2593`)
-/// 3. (*h3*) Medium shade (`U+2592`)
-/// 4. (*h4*) Light shade (`U+2591`)
-/// 5. (*h5*) Left half block (`U+258C`)
-/// 6. (*h6*) Left half block (`U+258C`)
-fn get_prefix(level: u8) -> &'static str {
-    match level {
-        1 => "▉ ",
-        2 => "▓ ",
-        3 => "▒ ",
-        4 => "░ ",
-        5 => "▌ ",
-        _ => "▌ ",
+/// Count the rows a single line wraps onto at `width` columns
+fn wrapped_rows(line: &Line, width: usize) -> usize {
+    let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    let mut rows = 1;
+    let mut col = 0;
+
+    for word in content.split(' ') {
+        let word_width = display_width(word);
+        let needed = if col == 0 { word_width } else { col + 1 + word_width };
+
+        if needed <= width {
+            col = needed;
+        } else if word_width <= width {
+            rows += 1;
+            col = word_width;
+        } else {
+            if col > 0 {
+                rows += 1;
+            }
+            rows += (word_width - 1) / width;
+            col = word_width - (word_width - 1) / width * width;
+        }
     }
+
+    rows
 }
 
 /// Render a heading with size based on level
-fn render_heading(level: u8, spans: &[TextSpan], theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
-    let prefix = get_prefix(level);
+fn render_heading(level: u8, spans: &[TextSpan], theme: &Theme, lines: &mut Vec<Line<'static>>) {
     let heading_style = to_ratatui_style(&theme.heading, theme.heading_bold);
-    let mut line_spans = vec![Span::styled(prefix.to_string(), heading_style)];
+    let mut line_spans = vec![Span::styled(theme.heading_prefix(level).to_string(), heading_style)];
 
     for span in spans {
         line_spans.push(create_span(span, theme, true));
@@ -98,14 +248,86 @@ fn render_heading(level: u8, spans: &[TextSpan], theme: &ThemeColors, lines: &mu
     lines.push(Line::from(line_spans));
 }
 
-/// Render a paragraph with styled text spans
-fn render_paragraph(spans: &[TextSpan], theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
+/// Render a paragraph with styled text spans, word-wrapped to `wrap` columns when given
+fn render_paragraph(spans: &[TextSpan], theme: &Theme, lines: &mut Vec<Line<'static>>, wrap: Option<usize>) {
     let line_spans: Vec<_> = spans.iter().map(|span| create_span(span, theme, false)).collect();
-    lines.push(Line::from(line_spans));
+    match wrap {
+        Some(width) => lines.extend(wrap_spans(line_spans, width)),
+        None => lines.push(Line::from(line_spans)),
+    }
+}
+
+/// Render a callout as its icon followed by text tinted with the tip color, wrapping under the text
+fn render_callout(icon: &str, spans: &[TextSpan], theme: &Theme, lines: &mut Vec<Line<'static>>, wrap: Option<usize>) {
+    let tint = to_ratatui_style(&theme.admonition_tip, false);
+    let icon_width = display_width(icon).max(1) + 1;
+    let text_spans: Vec<_> = spans
+        .iter()
+        .map(|span| {
+            let styled = create_span(span, theme, false);
+            if span.style.code { styled } else { styled.patch_style(tint) }
+        })
+        .collect();
+
+    let text_lines = match wrap {
+        Some(width) => wrap_spans(text_spans, width.saturating_sub(icon_width)),
+        None => vec![Line::from(text_spans)],
+    };
+    for (idx, mut line) in text_lines.into_iter().enumerate() {
+        let prefix = if idx == 0 {
+            Span::styled(format!("{icon} "), tint.add_modifier(Modifier::BOLD))
+        } else {
+            Span::raw(" ".repeat(icon_width))
+        };
+        line.spans.insert(0, prefix);
+        lines.push(line);
+    }
+}
+
+/// Greedily word-wrap styled spans onto lines at most `width` columns wide
+///
+/// Words longer than `width` are left whole for `Paragraph` wrapping to break.
+fn wrap_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Line<'static>> {
+    // Each word keeps the style of the space before it so underlines and links stay continuous
+    let mut words: Vec<(Style, Vec<Span<'static>>)> = vec![(Style::default(), Vec::new())];
+    for span in spans {
+        for (idx, part) in span.content.split(' ').enumerate() {
+            if idx > 0 {
+                words.push((span.style, Vec::new()));
+            }
+            if !part.is_empty()
+                && let Some((_, word)) = words.last_mut()
+            {
+                word.push(Span::styled(part.to_string(), span.style));
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut current = Vec::new();
+    let mut col = 0;
+    for (space_style, word) in words {
+        let word_width: usize = word.iter().map(Span::width).sum();
+        if word_width == 0 {
+            continue;
+        }
+        if col > 0 && col + 1 + word_width > width {
+            lines.push(Line::from(std::mem::take(&mut current)));
+            col = 0;
+        }
+        if col > 0 {
+            current.push(Span::styled(" ".to_string(), space_style));
+            col += 1;
+        }
+        current.extend(word);
+        col += word_width;
+    }
+    lines.push(Line::from(current));
+    lines
 }
 
 /// Render a code block with syntax highlighting
-fn render_code_block(code: &CodeBlock, theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
+fn render_code_block(code: &CodeBlock, theme: &Theme, lines: &mut Vec<Line<'static>>) {
     let fence_style = to_ratatui_style(&theme.code_fence, false);
 
     if let Some(lang) = &code.language {
@@ -114,7 +336,20 @@ fn render_code_block(code: &CodeBlock, theme: &ThemeColors, lines: &mut Vec<Line
         lines.push(Line::from(Span::styled("```".to_string(), fence_style)));
     }
 
-    let highlighted_lines = highlighter::highlight_code(&code.code, code.language.as_deref(), theme);
+    if code.is_ansi() {
+        match code.code.into_text() {
+            Ok(text) => lines.extend(text.lines),
+            Err(_) => lines.extend(code.code.lines().map(|line| Line::from(line.to_string()))),
+        }
+        lines.push(Line::from(Span::styled("```".to_string(), fence_style)));
+        return;
+    }
+
+    let highlighted_lines = if code.is_diff_split() {
+        diff::diff_split(&code.code).side_by_side(theme)
+    } else {
+        highlighter::highlight_code(&code.code, code.language.as_deref(), theme)
+    };
 
     for tokens in highlighted_lines {
         let mut line_spans = Vec::new();
@@ -129,15 +364,11 @@ fn render_code_block(code: &CodeBlock, theme: &ThemeColors, lines: &mut Vec<Line
 }
 
 /// Render a list with bullets or numbers
-fn render_list(list: &List, theme: &ThemeColors, lines: &mut Vec<Line<'static>>, indent: usize) {
+fn render_list(list: &List, theme: &Theme, lines: &mut Vec<Line<'static>>, indent: usize, wrap: Option<usize>) {
     let marker_style = to_ratatui_style(&theme.list_marker, false);
 
     for (idx, item) in list.items.iter().enumerate() {
-        let prefix = if list.ordered {
-            format!("{}{}. ", "  ".repeat(indent), idx + 1)
-        } else {
-            format!("{}• ", "  ".repeat(indent))
-        };
+        let prefix = format!("{}{} ", "  ".repeat(indent), list.item_marker(idx, indent));
 
         let mut line_spans = vec![Span::styled(prefix, marker_style)];
 
@@ -147,75 +378,94 @@ fn render_list(list: &List, theme: &ThemeColors, lines: &mut Vec<Line<'static>>,
 
         lines.push(Line::from(line_spans));
 
+        for block in &item.blocks {
+            let block_wrap = wrap.map(|width| width.saturating_sub(2 * (indent + 1)));
+            let mut block_lines = render_blocks(std::slice::from_ref(block), theme, None, block_wrap)
+                .0
+                .lines;
+            block_lines.pop();
+            for mut line in block_lines {
+                line.spans.insert(0, Span::raw("  ".repeat(indent + 1)));
+                lines.push(line);
+            }
+        }
+
         if let Some(nested) = &item.nested {
-            render_list(nested, theme, lines, indent + 1);
+            render_list(nested, theme, lines, indent + 1, wrap);
         }
     }
 }
 
 /// Render a horizontal rule
-fn render_rule(theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
+fn render_rule(theme: &Theme, lines: &mut Vec<Line<'static>>, width: usize) {
     let rule_style = to_ratatui_style(&theme.rule, false);
-    lines.push(Line::from(Span::styled("─".repeat(60), rule_style)));
+    lines.push(Line::from(Span::styled("─".repeat(width), rule_style)));
 }
 
 /// Render a blockquote with indentation
-fn render_blockquote(blocks: &[Block], theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
-    let border_style = to_ratatui_style(&theme.blockquote_border, false);
+fn render_blockquote(blocks: &[Block], theme: &Theme, lines: &mut Vec<Line<'static>>) {
+    let (prefix, line_style) = match theme.settings.blockquote_style {
+        BlockquoteStyle::Border => (
+            Span::styled("│ ", to_ratatui_style(&theme.blockquote_border, false)),
+            Style::default(),
+        ),
+        BlockquoteStyle::Indent => (Span::raw("  "), Style::default()),
+        BlockquoteStyle::Background => {
+            let bg = theme.inline_code_bg;
+            (
+                Span::raw("  "),
+                Style::default().bg(ratatui::style::Color::Rgb(bg.r, bg.g, bg.b)),
+            )
+        }
+    };
 
     for block in blocks {
         if let Block::Paragraph { spans } = block {
-            let mut line_spans = vec![Span::styled("│ ".to_string(), border_style)];
+            let mut line_spans = vec![prefix.clone()];
 
             for span in spans {
                 line_spans.push(create_span(span, theme, false));
             }
 
-            lines.push(Line::from(line_spans));
+            lines.push(Line::from(line_spans).style(line_style));
         }
     }
 }
 
-/// Render an admonition with colored border and icon
+/// Render an admonition with colored border and icon, `width` columns wide including the border
 fn render_admonition(
-    admonition: &lantern_core::slide::Admonition, theme: &ThemeColors, lines: &mut Vec<Line<'static>>,
+    admonition: &lantern_core::slide::Admonition, theme: &Theme, lines: &mut Vec<Line<'static>>, width: usize,
 ) {
-    use lantern_core::slide::AdmonitionType;
-
-    let (icon, color, default_title) = match admonition.admonition_type {
-        AdmonitionType::Note => ("\u{24D8}", &theme.admonition_note, "Note"),
-        AdmonitionType::Tip => ("\u{1F4A1}", &theme.admonition_tip, "Tip"),
-        AdmonitionType::Important => ("\u{2757}", &theme.admonition_tip, "Important"),
-        AdmonitionType::Warning => ("\u{26A0}", &theme.admonition_warning, "Warning"),
-        AdmonitionType::Caution => ("\u{26A0}", &theme.admonition_warning, "Caution"),
-        AdmonitionType::Danger => ("\u{26D4}", &theme.admonition_danger, "Danger"),
-        AdmonitionType::Error => ("\u{2717}", &theme.admonition_danger, "Error"),
-        AdmonitionType::Info => ("\u{24D8}", &theme.admonition_info, "Info"),
-        AdmonitionType::Success => ("\u{2713}", &theme.admonition_success, "Success"),
-        AdmonitionType::Question => ("?", &theme.admonition_info, "Question"),
-        AdmonitionType::Example => ("\u{25B8}", &theme.admonition_success, "Example"),
-        AdmonitionType::Quote => ("\u{201C}", &theme.admonition_info, "Quote"),
-        AdmonitionType::Abstract => ("\u{00A7}", &theme.admonition_note, "Abstract"),
-        AdmonitionType::Todo => ("\u{2610}", &theme.admonition_info, "Todo"),
-        AdmonitionType::Bug => ("\u{1F41B}", &theme.admonition_danger, "Bug"),
-        AdmonitionType::Failure => ("\u{2717}", &theme.admonition_danger, "Failure"),
-    };
+    let (icon, color, default_title) = admonition.presentation(theme);
 
     let title = admonition.title.as_deref().unwrap_or(default_title);
-    let color_style = to_ratatui_style(color, false);
-    let bold_color_style = to_ratatui_style(color, true);
+    let color_style = to_ratatui_style(&color, false);
+    let (title_fg, title_bg) = admonition.title_colors(theme);
+    let title_style =
+        to_ratatui_style(&title_fg, true).bg(ratatui::style::Color::Rgb(title_bg.r, title_bg.g, title_bg.b));
+
+    if admonition.collapsed {
+        lines.push(Line::from(vec![
+            Span::styled("\u{25B8} ".to_string(), color_style),
+            Span::raw(format!("{icon} ")),
+            Span::styled(format!(" {title} "), title_style),
+        ]));
+        return;
+    }
 
-    let top_border = format!("\u{256D}{}\u{256E}", "\u{2500}".repeat(58));
+    let border_width = width.saturating_sub(2);
+    let content_width = width.saturating_sub(4);
+    let top_border = format!("\u{256D}{}\u{256E}", "\u{2500}".repeat(border_width));
     lines.push(Line::from(Span::styled(top_border, color_style)));
 
-    let icon_display_width = icon.chars().next().and_then(|c| c.width()).unwrap_or(1);
+    let icon_display_width = display_width(icon).max(1);
 
     let title_line = vec![
         Span::styled("\u{2502} ".to_string(), color_style),
         Span::raw(format!("{icon} ")),
-        Span::styled(title.to_string(), bold_color_style),
+        Span::styled(format!(" {title} "), title_style),
         Span::styled(
-            " ".repeat(56_usize.saturating_sub(icon_display_width + 1 + title.len())),
+            " ".repeat(content_width.saturating_sub(icon_display_width + 1 + display_width(title) + 2)),
             color_style,
         ),
         Span::styled(" \u{2502}".to_string(), color_style),
@@ -223,15 +473,13 @@ fn render_admonition(
     lines.push(Line::from(title_line));
 
     if !admonition.blocks.is_empty() {
-        let separator = format!("\u{251C}{}\u{2524}", "\u{2500}".repeat(58));
+        let separator = format!("\u{251C}{}\u{2524}", "\u{2500}".repeat(border_width));
         lines.push(Line::from(Span::styled(separator, color_style)));
 
         for block in &admonition.blocks {
             if let Block::Paragraph { spans } = block {
                 let text: String = spans.iter().map(|s| s.text.as_str()).collect();
                 let words: Vec<&str> = text.split_whitespace().collect();
-                let content_width = 56; // 60 total - 2 for borders - 2 for spaces
-
                 let mut current_line = String::new();
                 for word in words {
                     if current_line.is_empty() {
@@ -262,12 +510,12 @@ fn render_admonition(
         }
     }
 
-    let bottom_border = format!("\u{2570}{}\u{256F}", "\u{2500}".repeat(58));
+    let bottom_border = format!("\u{2570}{}\u{256F}", "\u{2500}".repeat(border_width));
     lines.push(Line::from(Span::styled(bottom_border, color_style)));
 }
 
-/// Render a table with basic formatting
-fn render_table(table: &Table, theme: &ThemeColors, lines: &mut Vec<Line<'static>>) {
+/// Render a table with basic formatting and a header separator `width` columns wide
+fn render_table(table: &Table, theme: &Theme, lines: &mut Vec<Line<'static>>, width: usize) {
     let border_style = to_ratatui_style(&theme.table_border, false);
 
     if !table.headers.is_empty() {
@@ -282,7 +530,7 @@ fn render_table(table: &Table, theme: &ThemeColors, lines: &mut Vec<Line<'static
         }
         lines.push(Line::from(header_line));
 
-        let separator = "─".repeat(60);
+        let separator = "─".repeat(width);
         lines.push(Line::from(Span::styled(separator, border_style)));
     }
 
@@ -298,20 +546,32 @@ fn render_table(table: &Table, theme: &ThemeColors, lines: &mut Vec<Line<'static
         }
         lines.push(Line::from(row_line));
     }
+
+    if let Some(caption) = &table.caption {
+        lines.push(caption_line(caption, theme));
+    }
+}
+
+/// A dimmed, centered caption line for a table or image
+pub fn caption_line(caption: &str, theme: &Theme) -> Line<'static> {
+    let style = to_ratatui_style(&theme.dimmed, false).add_modifier(Modifier::ITALIC);
+    Line::from(Span::styled(caption.to_string(), style)).centered()
 }
 
 /// Create a styled span from a TextSpan
-fn create_span(text_span: &TextSpan, theme: &ThemeColors, is_heading: bool) -> Span<'static> {
+fn create_span(text_span: &TextSpan, theme: &Theme, is_heading: bool) -> Span<'static> {
     let style = apply_theme_style(theme, &text_span.style, is_heading);
     Span::styled(text_span.text.clone(), style)
 }
 
 /// Apply theme colors and text styling
-fn apply_theme_style(theme: &ThemeColors, text_style: &TextStyle, is_heading: bool) -> Style {
-    let mut style = if is_heading {
-        to_ratatui_style(&theme.heading, theme.heading_bold)
-    } else if text_style.code {
+///
+/// Inline code keeps the code color inside headings and table headers.
+fn apply_theme_style(theme: &Theme, text_style: &TextStyle, is_heading: bool) -> Style {
+    let mut style = if text_style.code {
         to_ratatui_style(&theme.code, false)
+    } else if is_heading {
+        to_ratatui_style(&theme.heading, theme.heading_bold)
     } else {
         to_ratatui_style(&theme.body, false)
     };
@@ -344,13 +604,13 @@ fn to_ratatui_style(color: &lantern_core::theme::Color, bold: bool) -> Style {
 mod tests {
     use super::*;
 
-    use lantern_core::slide::ListItem;
+    use lantern_core::slide::{ListDelimiter, ListItem};
     use lantern_core::theme::Color;
 
     #[test]
     fn render_heading_basic() {
         let blocks = vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Test Heading")] }];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text = render_slide_content(&blocks, &theme);
         assert!(!text.lines.is_empty());
     }
@@ -358,7 +618,7 @@ mod tests {
     #[test]
     fn render_paragraph_basic() {
         let blocks = vec![Block::Paragraph { spans: vec![TextSpan::plain("Test paragraph")] }];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text = render_slide_content(&blocks, &theme);
         assert!(!text.lines.is_empty());
     }
@@ -366,22 +626,239 @@ mod tests {
     #[test]
     fn render_code_block() {
         let blocks = vec![Block::Code(CodeBlock::with_language("rust", "fn main() {}"))];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text = render_slide_content(&blocks, &theme);
         assert!(text.lines.len() > 2);
     }
 
+    #[test]
+    fn render_ansi_code_block_uses_escape_colors() {
+        let blocks = vec![Block::Code(CodeBlock::with_language(
+            "ansi",
+            "\x1b[31mred\x1b[0m plain",
+        ))];
+        let theme = Theme::test_default();
+        let text = render_slide_content(&blocks, &theme);
+
+        let body = &text.lines[1];
+        let red = body.spans.iter().find(|s| s.content == "red").expect("red span");
+        assert_eq!(red.style.fg, Some(ratatui::style::Color::Red));
+        assert!(body.spans.iter().all(|s| !s.content.contains('\x1b')));
+    }
+
+    #[test]
+    fn render_diff_split_code_block_side_by_side() {
+        let blocks = vec![Block::Code(CodeBlock::with_language(
+            "diff-split",
+            "let a = 1;\n---\nlet a = 2;",
+        ))];
+        let theme = Theme::test_default();
+        let text = render_slide_content(&blocks, &theme);
+
+        let body = &text.lines[1];
+        let content: String = body.spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(content, "let a = 1; │ let a = 2;");
+        let color = |text: &str| {
+            body.spans
+                .iter()
+                .find(|span| span.content == text)
+                .and_then(|span| span.style.fg)
+        };
+        let rgb = |c: lantern_core::theme::Color| Some(ratatui::style::Color::Rgb(c.r, c.g, c.b));
+        assert_eq!(color("1"), rgb(theme.admonition_danger));
+        assert_eq!(color("2"), rgb(theme.admonition_success));
+        assert_eq!(text.lines.len(), 4);
+    }
+
+    #[test]
+    fn content_height_grows_when_narrower() {
+        let text = "lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor";
+        let blocks = vec![Block::Paragraph { spans: vec![TextSpan::plain(text)] }];
+        let theme = Theme::test_default();
+
+        let wide = content_height(&blocks, &theme, 200);
+        let narrow = content_height(&blocks, &theme, 20);
+
+        assert_eq!(wide, 2);
+        assert!(narrow > wide);
+        assert_eq!(narrow, 5);
+    }
+
+    #[test]
+    fn render_slide_content_wrapped_adds_lines_when_narrower() {
+        let text = "lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor";
+        let blocks = vec![Block::Paragraph { spans: vec![TextSpan::plain(text)] }];
+        let theme = Theme::test_default();
+
+        let wide = render_slide_content_wrapped(&blocks, &theme, 200);
+        let narrow = render_slide_content_wrapped(&blocks, &theme, 20);
+
+        assert_eq!(wide.lines.len(), 2);
+        assert_eq!(narrow.lines.len(), 5);
+        assert!(narrow.lines.iter().all(|line| line.width() <= 20));
+        assert_eq!(narrow.lines[0].to_string(), "lorem ipsum dolor");
+    }
+
+    #[test]
+    fn render_slide_content_wrapped_keeps_span_styles() {
+        let blocks = vec![Block::Paragraph {
+            spans: vec![TextSpan::plain("plain words then "), TextSpan::bold("bold words here")],
+        }];
+        let text = render_slide_content_wrapped(&blocks, &Theme::test_default(), 12);
+
+        let lines: Vec<String> = text.lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(lines, ["plain words", "then bold", "words here", ""]);
+        let bold = text.lines[2]
+            .spans
+            .iter()
+            .find(|s| s.content == "words")
+            .expect("bold span");
+        assert!(bold.style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn render_slide_content_wrapped_sizes_admonitions_and_rules() {
+        use lantern_core::slide::{Admonition, AdmonitionType};
+
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Note,
+            title: None,
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("one two three four five six seven eight")] }],
+            custom: None,
+            collapsed: false,
+        };
+        let blocks = vec![Block::Admonition(admonition), Block::Rule];
+        let theme = Theme::test_default();
+
+        let wide = render_slide_content_wrapped(&blocks, &theme, 80);
+        let narrow = render_slide_content_wrapped(&blocks, &theme, 24);
+
+        assert_eq!(wide.lines[0].width(), 80);
+        assert_eq!(narrow.lines[0].width(), 24);
+        assert!(narrow.lines.len() > wide.lines.len());
+        assert!(
+            narrow
+                .lines
+                .iter()
+                .filter(|line| line.width() > 0)
+                .all(|line| line.width() == 24)
+        );
+        assert_eq!(render_slide_content(&blocks, &theme).lines[0].width(), 60);
+    }
+
+    #[test]
+    fn text_height_breaks_words_longer_than_width() {
+        let text = Text::from(vec![Line::from("abcdefghij"), Line::raw("")]);
+        assert_eq!(text_height(&text, 4), 4);
+        assert_eq!(text_height(&text, 0), 2);
+    }
+
+    #[test]
+    fn render_custom_admonition_uses_configured_presentation() {
+        use lantern_core::slide::{Admonition, AdmonitionType, CustomAdmonition};
+
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Custom,
+            title: None,
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("A term")] }],
+            custom: Some(CustomAdmonition {
+                icon: "📖".to_string(),
+                color: "#88aaff".to_string(),
+                title: Some("Glossary".to_string()),
+            }),
+            collapsed: false,
+        };
+        let theme = Theme::test_default();
+        let text = render_slide_content(&[Block::Admonition(admonition)], &theme);
+
+        let title_line = &text.lines[1];
+        assert!(title_line.spans.iter().any(|s| s.content.contains("📖")));
+        let title = title_line
+            .spans
+            .iter()
+            .find(|s| s.content == " Glossary ")
+            .expect("title span");
+        assert_eq!(title.style.bg, Some(ratatui::style::Color::Rgb(0x88, 0xaa, 0xff)));
+        assert_eq!(title.style.fg, Some(ratatui::style::Color::Rgb(20, 20, 20)));
+    }
+
+    #[test]
+    fn render_collapsed_admonition_as_title_line() {
+        use lantern_core::slide::{Admonition, AdmonitionType};
+
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Note,
+            title: Some("Details".to_string()),
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("Hidden body")] }],
+            custom: None,
+            collapsed: true,
+        };
+        let text = render_slide_content(&[Block::Admonition(admonition)], &Theme::test_default());
+
+        let content: Vec<String> = text.lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(content.iter().filter(|line| !line.is_empty()).count(), 1);
+        assert!(content[0].starts_with('\u{25B8}'));
+        assert!(content[0].contains(" Details "));
+        assert!(!content.iter().any(|line| line.contains("Hidden body")));
+    }
+
+    #[test]
+    fn render_callout_single_tinted_line() {
+        let theme = Theme::test_default();
+        let blocks = vec![Block::Callout {
+            icon: "🚀".to_string(),
+            spans: vec![TextSpan::plain("Ship "), TextSpan::code("main")],
+        }];
+        let text = render_slide_content(&blocks, &theme);
+
+        assert_eq!(text.lines.len(), 2);
+        let line = &text.lines[0];
+        let tip = ratatui::style::Color::Rgb(theme.admonition_tip.r, theme.admonition_tip.g, theme.admonition_tip.b);
+        assert_eq!(line.spans[0].content, "🚀 ");
+        assert_eq!(line.spans[0].style.fg, Some(tip));
+        assert_eq!(line.spans[1].content, "Ship ");
+        assert_eq!(line.spans[1].style.fg, Some(tip));
+        assert_ne!(line.spans[2].style.fg, Some(tip));
+        assert!(!line.spans.iter().any(|span| span.content.contains('│')));
+    }
+
+    #[test]
+    fn render_blockquote_styles() {
+        let quoted = Block::Paragraph { spans: vec![TextSpan::plain("Quoted")] };
+        let blocks = vec![Block::BlockQuote { blocks: vec![quoted] }];
+        let first_line = |style| {
+            let theme = Theme::test_default().with_blockquote_style(style);
+            render_slide_content(&blocks, &theme).lines[0].clone()
+        };
+
+        let border = first_line(BlockquoteStyle::Border);
+        assert_eq!(border.spans[0].content, "│ ");
+        assert_eq!(border.style.bg, None);
+
+        let indent = first_line(BlockquoteStyle::Indent);
+        assert_eq!(indent.spans[0].content, "  ");
+        assert_eq!(indent.style.bg, None);
+
+        let background = first_line(BlockquoteStyle::Background);
+        let bg = Theme::test_default().inline_code_bg;
+        assert_eq!(background.spans[0].content, "  ");
+        assert_eq!(background.style.bg, Some(ratatui::style::Color::Rgb(bg.r, bg.g, bg.b)));
+        assert_eq!(background.spans[1].content, "Quoted");
+    }
+
     #[test]
     fn render_list_unordered() {
         let list = List {
             ordered: false,
             items: vec![
-                ListItem { spans: vec![TextSpan::plain("Item 1")], nested: None },
-                ListItem { spans: vec![TextSpan::plain("Item 2")], nested: None },
+                ListItem { spans: vec![TextSpan::plain("Item 1")], nested: None, blocks: vec![] },
+                ListItem { spans: vec![TextSpan::plain("Item 2")], nested: None, blocks: vec![] },
             ],
+            marker: None,
+            delimiter: ListDelimiter::Period,
         };
         let blocks = vec![Block::List(list)];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text = render_slide_content(&blocks, &theme);
         assert!(text.lines.len() >= 2);
     }
@@ -397,7 +874,7 @@ mod tests {
                 TextSpan::code("code"),
             ],
         }];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text = render_slide_content(&blocks, &theme);
         assert!(!text.lines.is_empty());
     }
@@ -426,16 +903,61 @@ mod tests {
 
     #[test]
     fn render_heading_uses_theme_colors() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let blocks = vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Colored Heading")] }];
         let text = render_slide_content(&blocks, &theme);
         assert!(!text.lines.is_empty());
         assert!(!text.lines.is_empty());
     }
 
+    #[test]
+    fn render_heading_honors_custom_and_disabled_prefixes() {
+        let blocks = vec![
+            Block::Heading { level: 1, spans: vec![TextSpan::plain("Title")] },
+            Block::Heading { level: 4, spans: vec![TextSpan::plain("Deep")] },
+        ];
+
+        let theme = Theme::test_default().with_heading_prefixes(Some(vec!["# ".into(), "## ".into()]));
+        let text = render_slide_content(&blocks, &theme);
+        let headings: Vec<_> = text.lines.iter().filter(|line| !line.spans.is_empty()).collect();
+        assert_eq!(headings[0].spans[0].content, "# ");
+        assert_eq!(headings[1].spans[0].content, "## ");
+
+        let theme = Theme::test_default().with_heading_prefixes(Some(Vec::new()));
+        let text = render_slide_content(&blocks, &theme);
+        let line = text.lines.iter().find(|line| !line.spans.is_empty()).unwrap();
+        let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(content, "Title");
+    }
+
+    #[test]
+    fn render_section_divider_adds_accent_rule_and_subtitle() {
+        let theme = Theme::test_default();
+        let blocks = vec![
+            Block::Heading { level: 1, spans: vec![TextSpan::plain("Part Two")] },
+            Block::Paragraph { spans: vec![TextSpan::plain("Scaling up")] },
+        ];
+
+        let text = render_section_divider(&blocks, &theme, 80);
+        let content: Vec<String> = text
+            .lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(content, vec!["Part Two", "", &"━".repeat(14), "", "Scaling up"]);
+
+        let accent = ratatui::style::Color::Rgb(theme.accent.r, theme.accent.g, theme.accent.b);
+        assert_eq!(text.lines[2].spans[0].style.fg, Some(accent));
+        assert!(text.lines[4].spans[0].style.add_modifier.contains(Modifier::ITALIC));
+
+        let text = render_section_divider(&blocks[..1], &theme, 6);
+        assert_eq!(text.lines.len(), 3);
+        assert_eq!(text.lines[2].spans[0].content, "━".repeat(6));
+    }
+
     #[test]
     fn apply_theme_style_respects_heading_bold() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text_style = TextStyle::default();
         let style = apply_theme_style(&theme, &text_style, true);
         assert!(style.add_modifier.contains(Modifier::BOLD));
@@ -443,7 +965,7 @@ mod tests {
 
     #[test]
     fn apply_theme_style_uses_code_color_for_code() {
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let text_style = TextStyle { code: true, ..Default::default() };
         let style = apply_theme_style(&theme, &text_style, false);
 
@@ -453,11 +975,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_table_header_uses_code_color_for_inline_code() {
+        let table = Table {
+            headers: vec![vec![TextSpan::plain("Call "), TextSpan::code("len()")]],
+            rows: vec![vec![vec![TextSpan::plain("3")]]],
+            alignments: vec![lantern_core::slide::Alignment::Left],
+            widths: vec![],
+            caption: None,
+        };
+        let theme = Theme::test_default();
+        let mut lines = Vec::new();
+        render_table(&table, &theme, &mut lines, DEFAULT_BOX_WIDTH);
+
+        let header = &lines[0];
+        let code = header.spans.iter().find(|s| s.content == "len()").expect("code span");
+        let plain = header.spans.iter().find(|s| s.content == "Call ").expect("plain span");
+        assert_eq!(
+            code.style.fg,
+            Some(ratatui::style::Color::Rgb(theme.code.r, theme.code.g, theme.code.b))
+        );
+        assert_eq!(
+            plain.style.fg,
+            Some(ratatui::style::Color::Rgb(
+                theme.heading.r,
+                theme.heading.g,
+                theme.heading.b
+            ))
+        );
+    }
+
+    #[test]
+    fn render_table_caption_is_centered_below() {
+        let table = Table {
+            headers: vec![vec![TextSpan::plain("A")]],
+            rows: vec![vec![vec![TextSpan::plain("1")]]],
+            alignments: vec![lantern_core::slide::Alignment::Left],
+            widths: vec![],
+            caption: Some("Totals".to_string()),
+        };
+        let theme = Theme::test_default();
+        let mut lines = Vec::new();
+        render_table(&table, &theme, &mut lines, DEFAULT_BOX_WIDTH);
+
+        let caption = lines.last().unwrap();
+        assert_eq!(caption.spans[0].content, "Totals");
+        assert_eq!(caption.alignment, Some(ratatui::layout::Alignment::Center));
+        assert!(caption.spans[0].style.add_modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn render_slide_with_focus_dims_other_code_blocks() {
+        let blocks = vec![
+            Block::Code(CodeBlock::new("first")),
+            Block::Paragraph { spans: vec![TextSpan::plain("between")] },
+            Block::Code(CodeBlock::new("second")),
+        ];
+        let theme = Theme::test_default();
+        let (text, _) = render_slide_with_focus(&blocks, &theme, Some(1), None);
+
+        let line_with = |needle: &str| {
+            text.lines
+                .iter()
+                .find(|line| line.spans.iter().any(|s| s.content.contains(needle)))
+                .expect("line")
+        };
+        let is_dim = |line: &Line| line.spans.iter().all(|s| s.style.add_modifier.contains(Modifier::DIM));
+
+        assert!(is_dim(line_with("first")));
+        assert!(!is_dim(line_with("second")));
+        assert!(!is_dim(line_with("between")));
+
+        let (unfocused, _) = render_slide_with_focus(&blocks, &theme, None, None);
+        assert!(
+            unfocused
+                .lines
+                .iter()
+                .flat_map(|l| &l.spans)
+                .all(|s| !s.style.add_modifier.contains(Modifier::DIM))
+        );
+        assert_eq!(code_block_count(&blocks), 2);
+    }
+
+    #[test]
+    fn render_footnotes_dims_numbered_lines() {
+        let footnotes = vec![
+            Footnote { number: 1, spans: vec![TextSpan::plain("First source")] },
+            Footnote { number: 2, spans: vec![TextSpan::italic("Second")] },
+        ];
+        let lines = render_footnotes(&footnotes, &Theme::test_default());
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].to_string(), "¹ First source");
+        assert_eq!(lines[2].to_string(), "² Second");
+        assert!(
+            lines[1..]
+                .iter()
+                .flat_map(|line| &line.spans)
+                .all(|s| s.style.add_modifier.contains(Modifier::DIM))
+        );
+        assert!(render_footnotes(&[], &Theme::test_default()).is_empty());
+    }
+
     #[test]
     fn render_slide_with_images_extracts_image() {
-        let blocks =
-            vec![lantern_core::slide::Block::Image { path: "test.png".to_string(), alt: "Test Image".to_string() }];
-        let theme = ThemeColors::default();
+        let blocks = vec![lantern_core::slide::Block::Image {
+            path: "test.png".to_string(),
+            alt: "Test Image".to_string(),
+            caption: None,
+        }];
+        let theme = Theme::test_default();
         let (_text, images) = render_slide_with_images(&blocks, &theme);
 
         assert_eq!(images.len(), 1);
@@ -468,10 +1095,18 @@ mod tests {
     #[test]
     fn render_slide_with_images_extracts_multiple() {
         let blocks = vec![
-            lantern_core::slide::Block::Image { path: "image1.png".to_string(), alt: "First".to_string() },
-            lantern_core::slide::Block::Image { path: "image2.png".to_string(), alt: "Second".to_string() },
+            lantern_core::slide::Block::Image {
+                path: "image1.png".to_string(),
+                alt: "First".to_string(),
+                caption: None,
+            },
+            lantern_core::slide::Block::Image {
+                path: "image2.png".to_string(),
+                alt: "Second".to_string(),
+                caption: Some("Figure 2".to_string()),
+            },
         ];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let (_text, images) = render_slide_with_images(&blocks, &theme);
 
         assert_eq!(images.len(), 2);
@@ -479,16 +1114,22 @@ mod tests {
         assert_eq!(images[0].alt, "First");
         assert_eq!(images[1].path, "image2.png");
         assert_eq!(images[1].alt, "Second");
+        assert_eq!(images[0].caption, None);
+        assert_eq!(images[1].caption.as_deref(), Some("Figure 2"));
     }
 
     #[test]
     fn render_slide_with_mixed_content() {
         let blocks = vec![
             lantern_core::slide::Block::Heading { level: 1, spans: vec![TextSpan::plain("Title")] },
-            lantern_core::slide::Block::Image { path: "diagram.png".to_string(), alt: "Diagram".to_string() },
+            lantern_core::slide::Block::Image {
+                path: "diagram.png".to_string(),
+                alt: "Diagram".to_string(),
+                caption: None,
+            },
             lantern_core::slide::Block::Paragraph { spans: vec![TextSpan::plain("Description")] },
         ];
-        let theme = ThemeColors::default();
+        let theme = Theme::test_default();
         let (text, images) = render_slide_with_images(&blocks, &theme);
 
         assert!(!text.lines.is_empty());
@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Status bar text describing the deck's source file: last edit time and, with the `git` feature, the commit
+///
+/// Returns `None` when the file's modification time can't be read.
+pub fn source_status(path: &Path) -> Option<String> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let status = format!("Edited {}", format_modified(modified));
+
+    #[cfg(feature = "git")]
+    let status = match git_short_hash(path) {
+        Some(hash) => format!("{status} @ {hash}"),
+        None => status,
+    };
+
+    Some(status)
+}
+
+/// Format a timestamp as `YYYY-MM-DD HH:MM UTC`
+pub fn format_modified(time: SystemTime) -> String {
+    let secs = match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    };
+    let days = secs.div_euclid(86_400);
+    let minutes_of_day = secs.rem_euclid(86_400) / 60;
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        minutes_of_day / 60,
+        minutes_of_day % 60
+    )
+}
+
+/// Convert days since the Unix epoch to a proleptic Gregorian `(year, month, day)`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Short hash of the commit checked out in the repository containing `path`
+///
+/// The repository is found with [git2::Repository::discover], so worktrees, detached heads and packed refs all resolve.
+#[cfg(feature = "git")]
+fn git_short_hash(path: &Path) -> Option<String> {
+    let dir = std::fs::canonicalize(path).ok()?.parent()?.to_path_buf();
+    let repo = git2::Repository::discover(dir).ok()?;
+    let commit = repo.head().ok()?.peel_to_commit().ok()?;
+    let short_id = commit.as_object().short_id().ok()?;
+    short_id.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn format_modified_uses_utc_calendar_date() {
+        assert_eq!(format_modified(SystemTime::UNIX_EPOCH), "1970-01-01 00:00 UTC");
+
+        let leap_day = SystemTime::UNIX_EPOCH + Duration::from_secs(951_782_400 + 13 * 3600 + 45 * 60 + 30);
+        assert_eq!(format_modified(leap_day), "2000-02-29 13:45 UTC");
+
+        let new_year = SystemTime::UNIX_EPOCH + Duration::from_secs(1_735_689_599);
+        assert_eq!(format_modified(new_year), "2024-12-31 23:59 UTC");
+    }
+
+    #[test]
+    fn source_status_missing_file() {
+        assert_eq!(source_status(Path::new("/nonexistent/deck.md")), None);
+    }
+
+    #[cfg(feature = "git")]
+    #[test]
+    fn git_short_hash_reads_head_commit() {
+        let dir = std::env::temp_dir().join(format!("lantern_git_short_hash_{}", std::process::id()));
+        let repo = git2::Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("deck.md"), "# Deck").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("deck.md")).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("Lantern", "lantern@example.com").unwrap();
+        let commit = repo
+            .commit(Some("HEAD"), &signature, &signature, "Add deck", &tree, &[])
+            .unwrap();
+
+        let hash = git_short_hash(&dir.join("deck.md")).expect("deck is inside a repository");
+        assert!(hash.len() >= 7);
+        assert!(commit.to_string().starts_with(&hash));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
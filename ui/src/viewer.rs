@@ -1,24 +1,37 @@
-use lantern_core::{slide::Slide, theme::ThemeColors};
+use ansi_to_tui::IntoText;
+use lantern_core::{
+    metadata::{ImageFit, ImageMaxHeight},
+    printer,
+    slide::Slide,
+    term::{KeyCategory, KeyMap},
+    theme::{Theme, ThemeRegistry},
+    width::display_width,
+};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
 };
 use ratatui_image::{Resize, StatefulImage};
-use std::time::Instant;
-
-use crate::image::ImageManager;
-use crate::renderer::render_slide_with_images;
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
+use crate::image::{ImageManager, fit_size};
+use crate::layout::{OVERVIEW_CELL_HEIGHT, overview_columns};
+use crate::renderer::{
+    caption_line, code_block_count, render_footnotes, render_overview_cell, render_section_divider,
+    render_slide_content, render_slide_content_wrapped, render_slide_with_focus, text_height,
+};
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct Stylesheet {
-    theme: ThemeColors,
+    theme: Theme,
 }
 
 impl Stylesheet {
-    fn new(theme: ThemeColors) -> Self {
+    fn new(theme: Theme) -> Self {
         Self { theme }
     }
 
@@ -54,8 +67,8 @@ impl Stylesheet {
     }
 }
 
-impl From<ThemeColors> for Stylesheet {
-    fn from(value: ThemeColors) -> Self {
+impl From<Theme> for Stylesheet {
+    fn from(value: Theme) -> Self {
         Self::new(value)
     }
 }
@@ -71,12 +84,29 @@ pub struct SlideViewer {
     stylesheet: Stylesheet,
     theme_name: String,
     start_time: Option<Instant>,
+    slide_start: Instant,
     image_manager: ImageManager,
+    image_fit: ImageFit,
+    image_max_height: Option<ImageMaxHeight>,
+    show_preview: bool,
+    preview_scroll: u16,
+    show_overview: bool,
+    h_offset: u16,
+    author: Option<String>,
+    show_slide_number: bool,
+    end_slide: bool,
+    at_end: bool,
+    focused_code: Option<usize>,
+    loop_slides: bool,
+    page: usize,
+    folds_open: bool,
+    source_status: Option<String>,
+    notes_markdown: bool,
 }
 
 impl SlideViewer {
     /// Create a new slide viewer with slides and theme
-    pub fn new(slides: Vec<Slide>, theme: ThemeColors) -> Self {
+    pub fn new(slides: Vec<Slide>, theme: Theme) -> Self {
         Self {
             slides,
             current_index: 0,
@@ -85,14 +115,30 @@ impl SlideViewer {
             filename: None,
             theme_name: "oxocarbon-dark".to_string(),
             start_time: None,
+            slide_start: Instant::now(),
             image_manager: ImageManager::default(),
+            image_fit: ImageFit::default(),
+            image_max_height: None,
+            show_preview: false,
+            preview_scroll: 0,
+            show_overview: false,
+            h_offset: 0,
+            author: None,
+            show_slide_number: false,
+            end_slide: false,
+            at_end: false,
+            focused_code: None,
+            loop_slides: false,
+            page: 0,
+            folds_open: false,
+            source_status: None,
+            notes_markdown: false,
         }
     }
 
     /// Create a slide viewer with full presentation context
     pub fn with_context(
-        slides: Vec<Slide>, theme: ThemeColors, filename: Option<String>, theme_name: String,
-        start_time: Option<Instant>,
+        slides: Vec<Slide>, theme: Theme, filename: Option<String>, theme_name: String, start_time: Option<Instant>,
     ) -> Self {
         let mut image_manager = ImageManager::default();
         if let Some(ref path) = filename {
@@ -107,36 +153,243 @@ impl SlideViewer {
             filename,
             theme_name,
             start_time,
+            slide_start: Instant::now(),
             image_manager,
+            image_fit: ImageFit::default(),
+            image_max_height: None,
+            show_preview: false,
+            preview_scroll: 0,
+            show_overview: false,
+            h_offset: 0,
+            author: None,
+            show_slide_number: false,
+            end_slide: false,
+            at_end: false,
+            focused_code: None,
+            loop_slides: false,
+            page: 0,
+            folds_open: false,
+            source_status: None,
+            notes_markdown: false,
         }
     }
 
-    /// Navigate to the next slide
+    /// Set the author text shown in the status bar
+    pub fn set_author(&mut self, author: impl Into<String>) {
+        let author = author.into();
+        self.author = if author.is_empty() { None } else { Some(author) };
+    }
+
+    /// Show `current/total` in the bottom-right corner of the slide
+    pub fn set_show_slide_number(&mut self, show: bool) {
+        self.show_slide_number = show;
+    }
+
+    /// Show an end screen after the last slide instead of stopping there
+    pub fn set_end_slide(&mut self, enabled: bool) {
+        self.end_slide = enabled;
+        self.at_end &= enabled;
+    }
+
+    /// Wrap Next on the last slide to the first, and Previous on the first to the last
+    pub fn set_loop(&mut self, enabled: bool) {
+        self.loop_slides = enabled;
+    }
+
+    /// Set the source file segment of the status bar, e.g. `Edited 2025-01-31 09:30 UTC`
+    pub fn set_source_status(&mut self, status: Option<String>) {
+        self.source_status = status;
+    }
+
+    /// Format speaker notes as markdown, so bullets and emphasis display formatted
+    pub fn set_notes_markdown(&mut self, enabled: bool) {
+        self.notes_markdown = enabled;
+    }
+
+    /// Check if the end screen is showing
+    pub fn is_at_end(&self) -> bool {
+        self.at_end
+    }
+
+    /// Set how images are scaled into their area
+    pub fn set_image_fit(&mut self, image_fit: ImageFit) {
+        self.image_fit = image_fit;
+    }
+
+    /// Cap the image region so text keeps the rest of the slide
+    pub fn set_image_max_height(&mut self, cap: Option<ImageMaxHeight>) {
+        self.image_max_height = cap;
+    }
+
+    /// Navigate to the next page of the current slide, or the next slide
+    ///
+    /// Slides split by page breaks step through their pages first. On the last slide this wraps to the first when
+    /// looping (see [SlideViewer::set_loop]), or enters the end screen when enabled (see [SlideViewer::set_end_slide]).
     pub fn next(&mut self) {
-        if self.current_index < self.slides.len().saturating_sub(1) {
+        if self.page + 1 < self.page_count() {
+            self.page += 1;
+            self.h_offset = 0;
+            self.focused_code = None;
+        } else if self.current_index < self.slides.len().saturating_sub(1) {
             self.current_index += 1;
+            self.slide_start = Instant::now();
+            self.h_offset = 0;
+            self.focused_code = None;
+            self.page = 0;
+            self.folds_open = false;
+        } else if self.loop_slides && !self.slides.is_empty() {
+            self.jump_to(1);
+        } else if self.end_slide && !self.slides.is_empty() {
+            self.at_end = true;
         }
     }
 
-    /// Navigate to the previous slide
+    /// Navigate to the previous page or slide, or back to the last slide from the end screen
     pub fn previous(&mut self) {
-        if self.current_index > 0 {
+        if self.at_end {
+            self.at_end = false;
+        } else if self.page > 0 {
+            self.page -= 1;
+            self.h_offset = 0;
+            self.focused_code = None;
+        } else if self.current_index > 0 {
             self.current_index -= 1;
+            self.slide_start = Instant::now();
+            self.h_offset = 0;
+            self.focused_code = None;
+            self.page = 0;
+            self.folds_open = false;
+        } else if self.loop_slides {
+            self.jump_to(self.slides.len());
         }
     }
 
     /// Jump to a specific slide by number (1-based)
     pub fn jump_to(&mut self, slide_number: usize) {
         if slide_number > 0 && slide_number <= self.slides.len() {
+            self.at_end = false;
             self.current_index = slide_number - 1;
+            self.slide_start = Instant::now();
+            self.h_offset = 0;
+            self.focused_code = None;
+            self.page = 0;
+            self.folds_open = false;
         }
     }
 
+    /// Load every image on the current slide, returning `path: error` for each one that can't be loaded
+    pub fn image_errors(&mut self) -> Vec<String> {
+        let Some(slide) = self.current_slide() else {
+            return Vec::new();
+        };
+        let images = slide.blocks.iter().filter_map(|block| match block {
+            lantern_core::slide::Block::Image { path, .. } => Some(path.clone()),
+            _ => None,
+        });
+        let paths: Vec<String> = slide.bg_image.iter().cloned().chain(images).collect();
+
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                self.image_manager
+                    .load_image(&path)
+                    .err()
+                    .map(|err| format!("{path}: {err}"))
+            })
+            .collect()
+    }
+
+    /// Index of the visible page of the current slide (0-based)
+    pub fn current_page(&self) -> usize {
+        self.page
+    }
+
+    /// Number of pages the current slide is split into by page breaks
+    pub fn page_count(&self) -> usize {
+        self.current_slide().map_or(1, |slide| slide.pages().len())
+    }
+
+    /// Blocks on the visible page of the current slide
+    fn current_blocks(&self) -> &[lantern_core::slide::Block] {
+        self.current_slide()
+            .and_then(|slide| slide.pages().get(self.page).copied())
+            .unwrap_or_default()
+    }
+
+    /// Scroll wide content horizontally by `delta` columns
+    ///
+    /// The offset is clamped to the content width on the next render.
+    pub fn scroll_horizontal(&mut self, delta: i16) {
+        self.h_offset = self.h_offset.saturating_add_signed(delta);
+    }
+
+    /// Current horizontal scroll offset in columns
+    pub fn horizontal_offset(&self) -> u16 {
+        self.h_offset
+    }
+
+    /// Focus the next code block on the current slide, wrapping back to the first
+    pub fn cycle_code_focus(&mut self) {
+        let count = code_block_count(self.current_blocks());
+        self.focused_code = next_code_focus(self.focused_code, count);
+    }
+
+    /// Index of the focused code block on the current slide, if any
+    pub fn focused_code(&self) -> Option<usize> {
+        self.focused_code
+    }
+
+    /// Expand the collapsed admonitions on the current slide, or fold them again
+    pub fn toggle_folds(&mut self) {
+        self.folds_open = !self.folds_open;
+    }
+
+    /// Check if collapsed admonitions on the current slide are expanded
+    pub fn folds_open(&self) -> bool {
+        self.folds_open
+    }
+
     /// Toggle speaker notes visibility
     pub fn toggle_notes(&mut self) {
         self.show_notes = !self.show_notes;
     }
 
+    /// Toggle the print-preview overlay, resetting its scroll position
+    pub fn toggle_preview(&mut self) {
+        self.show_preview = !self.show_preview;
+        self.preview_scroll = 0;
+    }
+
+    /// Toggle the grid overview of every slide
+    pub fn toggle_overview(&mut self) {
+        self.show_overview = !self.show_overview;
+    }
+
+    /// Check if the slide overview grid is visible
+    pub fn is_showing_overview(&self) -> bool {
+        self.show_overview
+    }
+
+    /// Check if the print-preview overlay is visible
+    pub fn is_showing_preview(&self) -> bool {
+        self.show_preview
+    }
+
+    /// Scroll the print-preview overlay by `delta` lines
+    pub fn scroll_preview(&mut self, delta: i16) {
+        self.preview_scroll = self.preview_scroll.saturating_add_signed(delta);
+    }
+
+    /// Current slide as rendered by the printer at `width` columns
+    pub fn preview_text(&self, width: u16) -> Text<'static> {
+        let Some(slide) = self.current_slide() else {
+            return Text::default();
+        };
+
+        let output = printer::render_slide_to_string(slide, self.theme(), width.max(1) as usize);
+        output.into_text().unwrap_or_else(|_| Text::raw(output))
+    }
+
     /// Get the current slide
     pub fn current_slide(&self) -> Option<&Slide> {
         self.slides.get(self.current_index)
@@ -162,24 +415,131 @@ impl SlideViewer {
         self.slides.iter().any(|slide| slide.notes.is_some())
     }
 
+    /// Plain-text presenter view of the current slide, written by `present --speaker-file`
+    ///
+    /// Shows the current and next slide titles, the elapsed time (and slide timing) when the presentation is timed,
+    /// and the current slide's notes.
+    pub fn presenter_view(&self) -> String {
+        let title = |slide: &Slide| slide.title().unwrap_or_else(|| "Untitled".to_string());
+        let mut view = format!("Slide {}/{}", self.current_index + 1, self.total_slides());
+        if let Some(slide) = self.current_slide() {
+            view.push_str(&format!(": {}", title(slide)));
+        }
+        view.push('\n');
+
+        match self.slides.get(self.current_index + 1) {
+            Some(next) => view.push_str(&format!("Next: {}\n", title(next))),
+            None => view.push_str("Next: (end of deck)\n"),
+        }
+
+        if let Some(start) = self.start_time {
+            let secs = start.elapsed().as_secs();
+            let timing = self
+                .current_slide()
+                .and_then(|slide| slide.target_duration)
+                .map(|target| format_slide_timing(self.slide_start.elapsed(), target).0)
+                .unwrap_or_default();
+            view.push_str(&format!(
+                "Elapsed: {:02}:{:02}:{:02}{timing}\n",
+                secs / 3600,
+                (secs % 3600) / 60,
+                secs % 60
+            ));
+        }
+
+        view.push_str("\nNotes:\n");
+        match self.current_slide().and_then(|slide| slide.notes.as_deref()) {
+            Some(notes) => view.push_str(notes.trim_end()),
+            None => view.push_str("(no notes)"),
+        }
+        view.push('\n');
+        view
+    }
+
     /// Render the current slide to the frame
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        if let Some(slide) = self.current_slide() {
-            let (content, images) = render_slide_with_images(&slide.blocks, &self.theme());
-            let border_color = self.stylesheet.border_color();
-            let title_color = self.stylesheet.title_color();
+        let _span = tracing::debug_span!("render", slide = self.current_index + 1, page = self.page + 1).entered();
 
-            let block = Block::default()
+        if self.at_end {
+            self.render_end_screen(frame, area);
+            return;
+        }
+
+        if let Some(slide) = self.current_slide() {
+            let blocks = self.current_blocks();
+            let blocks = if self.folds_open { Cow::Owned(unfold_admonitions(blocks)) } else { Cow::Borrowed(blocks) };
+            let style = slide.style();
+            let stylesheet = match style.theme {
+                Some(name) => Stylesheet::new(ThemeRegistry::get(name)),
+                None => self.stylesheet.clone(),
+            };
+            let border_color = stylesheet.border_color();
+            let title_color = stylesheet.title_color();
+
+            let mut block = Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color))
                 .title(format!(" Slide {}/{} ", self.current_index + 1, self.total_slides()))
                 .title_style(Style::default().fg(title_color).add_modifier(Modifier::BOLD))
                 .padding(Stylesheet::slide_padding());
 
+            if self.show_slide_number {
+                let number = format!(" {}/{} ", self.current_index + 1, self.total_slides());
+                let number_style = Style::default().fg(stylesheet.text_color()).add_modifier(Modifier::DIM);
+                block = block.title_bottom(Line::from(Span::styled(number, number_style)).right_aligned());
+            }
+
+            if style.theme.is_some() {
+                let background = stylesheet.theme.ui_background;
+                let fill = Style::default().bg(Color::Rgb(background.r, background.g, background.b));
+                frame.render_widget(Block::default().style(fill), area);
+            }
+
             let inner_area = block.inner(area);
             frame.render_widget(block, area);
 
-            let text_height = content.height() as u16;
+            let (mut content, images) =
+                render_slide_with_focus(&blocks, &stylesheet.theme, self.focused_code, Some(inner_area.width));
+            if self.page + 1 >= self.page_count() {
+                content
+                    .lines
+                    .extend(render_footnotes(&slide.footnotes, &stylesheet.theme));
+            }
+            tracing::debug!(
+                blocks = blocks.len(),
+                lines = content.lines.len(),
+                images = images.len(),
+                "rendered slide"
+            );
+
+            let wide_width = wide_content_width(&blocks, &stylesheet.theme, inner_area.width);
+            let divider = slide
+                .is_section_divider()
+                .then(|| render_section_divider(&blocks, &stylesheet.theme, inner_area.width));
+            let bg_image = slide.bg_image.clone();
+
+            if let Some(path) = &bg_image
+                && self.image_manager.load_image(path).is_ok()
+                && let Some(protocol) = self.image_manager.get_protocol_mut(path)
+            {
+                let bg_widget = StatefulImage::default().resize(Resize::Scale(None));
+                frame.render_stateful_widget(bg_widget, inner_area, protocol);
+            }
+
+            if let Some(divider) = divider {
+                let [divider_area] = Layout::vertical([Constraint::Length(divider.lines.len() as u16)])
+                    .flex(Flex::Center)
+                    .areas(inner_area);
+                let paragraph = Paragraph::new(divider)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true });
+                frame.render_widget(paragraph, divider_area);
+                return;
+            }
+
+            let scrolls = wide_width > inner_area.width;
+            self.h_offset = clamp_h_offset(self.h_offset, wide_width, inner_area.width);
+            let text_height = if scrolls { content.height() as u16 } else { text_height(&content, inner_area.width) };
             let mut text_content = Some(content);
 
             if !images.is_empty() {
@@ -192,19 +552,23 @@ impl SlideViewer {
                 let min_images_height = total_images * min_height_per_image;
 
                 let available_height = inner_area.height;
-                let max_text_height = available_height.saturating_sub(min_images_height);
-                let text_area_height = text_height.min(max_text_height);
+                let cap = self
+                    .image_max_height
+                    .map_or(available_height, |cap| cap.rows(available_height));
+                let reserved_height = min_images_height.min(cap);
+                let text_area_height = text_height.min(available_height.saturating_sub(reserved_height));
+                let images_height = image_area_height(available_height, text_area_height, cap);
 
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
-                    .constraints([Constraint::Length(text_area_height), Constraint::Min(min_images_height)])
+                    .flex(Flex::Start)
+                    .constraints([Constraint::Length(text_area_height), Constraint::Length(images_height)])
                     .split(inner_area);
 
-                if chunks[0].height > 0 {
-                    if let Some(text) = text_content.take() {
-                        let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-                        frame.render_widget(paragraph, chunks[0]);
-                    }
+                if chunks[0].height > 0
+                    && let Some(text) = text_content.take()
+                {
+                    self.render_content(frame, text, chunks[0], scrolls, text_height);
                 }
 
                 let constraints: Vec<Constraint> = (0..total_images)
@@ -216,8 +580,12 @@ impl SlideViewer {
                     .constraints(constraints)
                     .split(chunks[1]);
 
+                let image_fit = self.image_fit;
+                let graphics = self.image_manager.graphics_supported();
                 for (idx, img_info) in images.iter().enumerate() {
-                    if let Ok(protocol) = self.image_manager.load_image(&img_info.path) {
+                    let loaded = self.image_manager.load_image(&img_info.path).is_ok();
+                    let native_size = self.image_manager.native_size(&img_info.path);
+                    if loaded && let Some(protocol) = self.image_manager.get_protocol_mut(&img_info.path) {
                         let image_area = image_chunks[idx];
 
                         let horizontal_chunks = Layout::default()
@@ -254,59 +622,262 @@ impl SlideViewer {
                             frame.render_widget(caption, content_chunks[0]);
                         }
 
-                        let resize = Resize::Fit(None);
-                        let image_size = protocol.size_for(resize, content_chunks[1]);
-
-                        let [centered_area] = Layout::horizontal([Constraint::Length(image_size.width)])
+                        let caption_rows = u16::from(img_info.caption.is_some());
+                        let [picture_area, _] =
+                            Layout::vertical([Constraint::Min(1), Constraint::Length(caption_rows)])
+                                .areas(content_chunks[1]);
+
+                        let (resize, (image_width, image_height)) = match native_size {
+                            Some(native) => {
+                                let resize = match image_fit {
+                                    ImageFit::Native => Resize::Crop(None),
+                                    ImageFit::Fit | ImageFit::Contain => Resize::Fit(None),
+                                };
+                                (resize, fit_size(native, picture_area, image_fit))
+                            }
+                            None => {
+                                let size = protocol.size_for(Resize::Fit(None), picture_area);
+                                (Resize::Fit(None), (size.width, size.height))
+                            }
+                        };
+
+                        let [centered_area] = Layout::horizontal([Constraint::Length(image_width)])
                             .flex(Flex::Center)
-                            .areas(content_chunks[1]);
-                        let [image_area] = Layout::vertical([Constraint::Length(image_size.height)])
+                            .areas(picture_area);
+                        let [image_area] = Layout::vertical([Constraint::Length(image_height)])
                             .flex(Flex::Center)
                             .areas(centered_area);
 
-                        let image_widget = StatefulImage::default();
-                        frame.render_stateful_widget(image_widget, image_area, protocol);
+                        if graphics {
+                            let image_widget = StatefulImage::default().resize(resize);
+                            frame.render_stateful_widget(image_widget, image_area, protocol);
+                        } else if let Ok(lines) =
+                            self.image_manager
+                                .halfblock_image(&img_info.path, image_area.width, image_area.height)
+                        {
+                            frame.render_widget(Paragraph::new(lines), image_area);
+                        }
+
+                        if let Some(caption) = &img_info.caption {
+                            let caption_area = Rect { y: image_area.bottom(), height: 1, ..content_chunks[1] };
+                            let caption = Paragraph::new(caption_line(caption, &stylesheet.theme));
+                            frame.render_widget(caption, caption_area);
+                        }
                     }
                 }
-            } else if let Some(text) = text_content.take() {
-                let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-                frame.render_widget(paragraph, inner_area);
+            } else if let Some(mut text) = text_content.take() {
+                let mut text_area = inner_area;
+                if style.center {
+                    for line in &mut text.lines {
+                        line.alignment = line.alignment.or(Some(Alignment::Center));
+                    }
+                    [text_area] = Layout::vertical([Constraint::Length(text_height.min(inner_area.height))])
+                        .flex(Flex::Center)
+                        .areas(inner_area);
+                }
+                self.render_content(frame, text, text_area, scrolls, text_height);
             }
         }
     }
 
+    /// Render slide text into `area`, reserving its last row for a dimmed `▼ more` when the text doesn't fit
+    fn render_content(&self, frame: &mut Frame, text: Text<'static>, area: Rect, scrolls: bool, text_height: u16) {
+        if !shows_overflow_indicator(text_height, area.height) {
+            frame.render_widget(content_paragraph(text, scrolls, self.h_offset), area);
+            return;
+        }
+
+        let [text_area, indicator_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+        frame.render_widget(content_paragraph(text, scrolls, self.h_offset), text_area);
+
+        let style = Style::default()
+            .fg(self.stylesheet.text_color())
+            .add_modifier(Modifier::DIM);
+        frame.render_widget(
+            Paragraph::new(Span::styled("▼ more", style)).alignment(Alignment::Right),
+            indicator_area,
+        );
+    }
+
+    /// Render the end-of-deck screen shown after the last slide
+    fn render_end_screen(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.stylesheet.border_color()))
+            .title(" End ")
+            .title_style(
+                Style::default()
+                    .fg(self.stylesheet.title_color())
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let lines = vec![
+            Line::from(Span::styled(
+                "End",
+                Style::default()
+                    .fg(self.stylesheet.title_color())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "press Q to quit / ← to go back",
+                Style::default()
+                    .fg(self.stylesheet.text_color())
+                    .add_modifier(Modifier::DIM),
+            )),
+        ];
+        let [message_area] = Layout::vertical([Constraint::Length(lines.len() as u16)])
+            .flex(Flex::Center)
+            .areas(inner_area);
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), message_area);
+    }
+
+    /// Render the print-preview overlay on top of `area` if visible
+    pub fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        if !self.show_preview {
+            return;
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.stylesheet.border_color()))
+            .title(" Print Preview ")
+            .title_style(
+                Style::default()
+                    .fg(self.stylesheet.title_color())
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::horizontal(1));
+
+        let inner_width = block.inner(area).width;
+        let paragraph = Paragraph::new(self.preview_text(inner_width))
+            .block(block)
+            .scroll((self.preview_scroll, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render every slide as a cell of an [overview_columns] wide grid over `area`, highlighting the current one
+    ///
+    /// Rows that don't fit scroll so the current slide stays in view.
+    pub fn render_overview(&mut self, frame: &mut Frame, area: Rect) {
+        let total = self.total_slides();
+        let columns = overview_columns(area.width, total);
+        let cell_width = area.width / columns as u16;
+        let visible_rows = usize::from((area.height / OVERVIEW_CELL_HEIGHT).max(1));
+        let first_row = (self.current_index / columns).saturating_sub(visible_rows - 1);
+
+        let first = first_row * columns;
+        let last = total.min(first + visible_rows * columns);
+        for (index, slide) in self.slides.iter().enumerate().take(last).skip(first) {
+            let row = (index / columns - first_row) as u16;
+            let column = (index % columns) as u16;
+            let cell = Rect::new(
+                area.x + column * cell_width,
+                area.y + row * OVERVIEW_CELL_HEIGHT,
+                cell_width,
+                OVERVIEW_CELL_HEIGHT,
+            )
+            .intersection(area);
+
+            let border_style = if index == self.current_index {
+                Style::default()
+                    .fg(self.stylesheet.title_color())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.stylesheet.border_color())
+            };
+            let block = Block::default().borders(Borders::ALL).border_style(border_style);
+            let inner = block.inner(cell);
+            let lines = render_overview_cell(
+                slide,
+                index + 1,
+                &mut self.image_manager,
+                &self.stylesheet.theme,
+                inner.width,
+                inner.height,
+            );
+
+            frame.render_widget(Paragraph::new(lines).block(block), cell);
+        }
+    }
+
+    /// Render the full keymap from [help_overlay_lines] over `area`
+    pub fn render_help_overlay(&self, frame: &mut Frame, area: Rect, keymap: &KeyMap) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.stylesheet.border_color()))
+            .title(" Keyboard Shortcuts ")
+            .title_style(
+                Style::default()
+                    .fg(self.stylesheet.title_color())
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::horizontal(1));
+
+        let title_style = Style::default()
+            .fg(self.stylesheet.title_color())
+            .add_modifier(Modifier::BOLD);
+        let text_style = Style::default().fg(self.stylesheet.text_color());
+        let lines: Vec<Line> = help_overlay_lines(keymap)
+            .into_iter()
+            .map(|line| {
+                let style = if line.starts_with(' ') { text_style } else { title_style };
+                Line::styled(line, style)
+            })
+            .collect();
+
+        let background = self.theme().ui_background;
+        let style = Style::default().bg(Color::Rgb(background.r, background.g, background.b));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block).style(style), area);
+    }
+
     /// Render speaker notes if available and visible
     pub fn render_notes(&self, frame: &mut Frame, area: Rect) {
         if !self.show_notes {
             return;
         }
 
-        if let Some(slide) = self.current_slide() {
-            if let Some(notes) = &slide.notes {
-                let border_color = self.stylesheet.border_color();
-                let title_color = self.stylesheet.title_color();
-                let text_color = self.stylesheet.text_color();
-
-                let block = Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color))
-                    .title(" Speaker Notes ")
-                    .title_style(Style::default().fg(title_color).add_modifier(Modifier::BOLD))
-                    .padding(Stylesheet::slide_padding());
-
-                let paragraph = Paragraph::new(notes.clone())
-                    .block(block)
-                    .wrap(Wrap { trim: false })
-                    .style(Style::default().fg(text_color));
-
-                frame.render_widget(paragraph, area);
-            }
+        if let Some(slide) = self.current_slide()
+            && let Some(notes) = &slide.notes
+        {
+            let border_color = self.stylesheet.border_color();
+            let title_color = self.stylesheet.title_color();
+            let text_color = self.stylesheet.text_color();
+
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color))
+                .title(" Speaker Notes ")
+                .title_style(Style::default().fg(title_color).add_modifier(Modifier::BOLD))
+                .padding(Stylesheet::slide_padding());
+
+            let paragraph = Paragraph::new(notes_text(notes, self.theme(), self.notes_markdown))
+                .block(block)
+                .wrap(Wrap { trim: false })
+                .style(Style::default().fg(text_color));
+
+            frame.render_widget(paragraph, area);
         }
     }
 
     /// Render status bar with navigation info
     pub fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let status = Paragraph::new(self.status_bar_line(area.width as usize));
+
+        frame.render_widget(status, area);
+    }
+
+    /// Status bar spans padded to `width` display columns so the background fills the whole row
+    fn status_bar_line(&self, width: usize) -> Line<'static> {
         let filename_part = self.filename.as_ref().map(|f| format!("{f} | ")).unwrap_or_default();
+        let author_part = self.author.as_ref().map(|a| format!("{a} | ")).unwrap_or_default();
 
         let elapsed = self
             .start_time
@@ -326,31 +897,61 @@ impl SlideViewer {
             String::new()
         };
 
+        let source_part = self
+            .source_status
+            .as_ref()
+            .map(|s| format!(" | {s}"))
+            .unwrap_or_default();
+
         let status_text = format!(
-            " {}{}/{} | Theme: {}{}{} | [?] Help ",
+            " {}{}{}/{} | Theme: {}{}{}{}",
             filename_part,
+            author_part,
             self.current_index + 1,
             self.total_slides(),
             self.theme_name,
+            source_part,
             notes_part,
             elapsed
         );
 
-        let width = area.width as usize;
-        let text_len = status_text.chars().count();
+        let timing = self
+            .current_slide()
+            .and_then(|slide| slide.target_duration)
+            .filter(|_| self.start_time.is_some())
+            .map(|target| format_slide_timing(self.slide_start.elapsed(), target));
+
+        let help_text = " | [?] Help ";
+        let timing_len = timing.as_ref().map(|(text, _)| display_width(text)).unwrap_or(0);
+        let text_len = display_width(&status_text) + timing_len + display_width(help_text);
         let padding = if text_len < width { " ".repeat(width - text_len) } else { String::new() };
 
-        let status = Paragraph::new(Line::from(vec![Span::styled(
-            format!("{status_text}{padding}"),
+        let mut spans = vec![Span::styled(status_text, self.stylesheet.status_bar())];
+        if let Some((text, over)) = timing {
+            let style = if over {
+                let danger = self.theme().admonition_danger;
+                self.stylesheet
+                    .status_bar()
+                    .fg(Color::Rgb(danger.r, danger.g, danger.b))
+            } else {
+                self.stylesheet.status_bar()
+            };
+            spans.push(Span::styled(text, style));
+        }
+        spans.push(Span::styled(
+            format!("{help_text}{padding}"),
             self.stylesheet.status_bar(),
-        )]));
+        ));
 
-        frame.render_widget(status, area);
+        Line::from(spans)
     }
 
     /// Render help line with keybinding reference
     pub fn render_help_line(&self, frame: &mut Frame, area: Rect) {
-        let help_text = " [j/→/Space] Next | [k/←] Previous | [N] Toggle notes | [Q/Esc] Quit ";
+        let help_text = concat!(
+            " [j/→/Space] Next | [k/←] Previous | [N] Toggle notes | [P] Print preview",
+            " | [Tab] Focus code | [Enter] Expand callouts | [Q/Esc] Quit "
+        );
 
         let width = area.width as usize;
         let text_len = help_text.chars().count();
@@ -369,16 +970,180 @@ impl SlideViewer {
         frame.render_widget(help_line, area);
     }
 
-    fn theme(&self) -> ThemeColors {
-        self.stylesheet.theme
+    fn theme(&self) -> &Theme {
+        &self.stylesheet.theme
+    }
+}
+
+/// Widest rendered table on the slide, the content that can't be wrapped to fit
+fn wide_content_width(blocks: &[lantern_core::slide::Block], theme: &Theme, width: u16) -> u16 {
+    blocks
+        .iter()
+        .filter(|block| matches!(block, lantern_core::slide::Block::Table(_)))
+        .map(|block| render_slide_content_wrapped(std::slice::from_ref(block), theme, width).width())
+        .max()
+        .unwrap_or(0)
+        .min(u16::MAX as usize) as u16
+}
+
+/// Help text listing every binding in `keymap`, grouped under a heading per [KeyCategory]
+///
+/// Binding lines are indented and their key lists padded to a common column; empty categories are skipped.
+pub fn help_overlay_lines(keymap: &KeyMap) -> Vec<String> {
+    let key_lists: Vec<String> = keymap
+        .bindings
+        .iter()
+        .map(|binding| {
+            let mut labels: Vec<String> = Vec::new();
+            for label in binding.keys.iter().map(|key| key.label()) {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            labels.join(", ")
+        })
+        .collect();
+    let keys_width = key_lists.iter().map(|keys| display_width(keys)).max().unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for category in KeyCategory::ALL {
+        let mut bindings = keymap
+            .bindings
+            .iter()
+            .zip(&key_lists)
+            .filter(|(binding, _)| binding.category == category);
+        let Some(first) = bindings.next() else { continue };
+
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(category.label().to_string());
+        for (binding, keys) in std::iter::once(first).chain(bindings) {
+            let padding = " ".repeat(keys_width - display_width(keys));
+            lines.push(format!("  {keys}{padding}  {}", binding.description));
+        }
+    }
+
+    lines
+}
+
+/// Speaker notes as shown in the notes panel, parsed and rendered like slide content when `markdown` is set
+///
+/// Notes that fail to parse are shown as raw text.
+fn notes_text(notes: &str, theme: &Theme, markdown: bool) -> Text<'static> {
+    if markdown && let Ok(slides) = lantern_core::parser::parse_slides(notes) {
+        let blocks: Vec<_> = slides.into_iter().flat_map(|slide| slide.blocks).collect();
+        return render_slide_content(&blocks, theme);
+    }
+
+    Text::raw(notes.to_string())
+}
+
+/// Copy of `blocks` with every top-level collapsed admonition expanded
+fn unfold_admonitions(blocks: &[lantern_core::slide::Block]) -> Vec<lantern_core::slide::Block> {
+    blocks
+        .iter()
+        .cloned()
+        .map(|mut block| {
+            if let lantern_core::slide::Block::Admonition(admonition) = &mut block {
+                admonition.collapsed = false;
+            }
+            block
+        })
+        .collect()
+}
+
+/// Code block focused after pressing `Tab`: the first one, then the next, wrapping around
+pub fn next_code_focus(current: Option<usize>, count: usize) -> Option<usize> {
+    match (current, count) {
+        (_, 0) => None,
+        (None, _) => Some(0),
+        (Some(idx), count) => Some((idx + 1) % count),
     }
 }
 
+/// Rows given to a slide's images: whatever the text leaves, up to `cap`
+pub fn image_area_height(available: u16, text_height: u16, cap: u16) -> u16 {
+    available.saturating_sub(text_height).min(cap)
+}
+
+/// Whether content `content_height` rows tall overflows an area `area_height` rows tall and needs a `▼ more` hint
+///
+/// Areas under two rows are left alone since the hint would replace the only visible line.
+pub fn shows_overflow_indicator(content_height: u16, area_height: u16) -> bool {
+    area_height >= 2 && content_height > area_height
+}
+
+/// Clamp a horizontal scroll offset so the content's right edge never scrolls past the area
+pub fn clamp_h_offset(offset: u16, content_width: u16, area_width: u16) -> u16 {
+    offset.min(content_width.saturating_sub(area_width))
+}
+
+/// Slide content paragraph; wide slides scroll horizontally instead of wrapping
+fn content_paragraph(text: Text<'static>, scrolls: bool, h_offset: u16) -> Paragraph<'static> {
+    if scrolls {
+        Paragraph::new(text).scroll((0, h_offset))
+    } else {
+        Paragraph::new(text).wrap(Wrap { trim: false })
+    }
+}
+
+/// Format per-slide elapsed time against its target as ` | MM:SS/MM:SS`
+///
+/// Returns the text and whether the slide has run over its target.
+pub fn format_slide_timing(elapsed: Duration, target: Duration) -> (String, bool) {
+    let format = |d: Duration| format!("{:02}:{:02}", d.as_secs() / 60, d.as_secs() % 60);
+    (format!(" | {}/{}", format(elapsed), format(target)), elapsed > target)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use lantern_core::slide::{Block, TextSpan};
 
+    #[test]
+    fn help_overlay_lines_group_keymap_bindings() {
+        let mut keymap = KeyMap::default();
+        keymap.bindings[1].keys = vec![lantern_core::term::Key::any(crossterm::event::KeyCode::Backspace)];
+        let lines = help_overlay_lines(&keymap);
+
+        for category in ["Navigation", "View", "Notes", "Search", "Quit"] {
+            assert!(lines.iter().any(|line| line == category), "missing {category}");
+        }
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.starts_with("  j, →, Space, n ") && line.ends_with("Next slide"))
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.starts_with("  Backspace ") && line.ends_with("Previous slide"))
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.starts_with("  N ") && line.ends_with("Toggle speaker notes"))
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.starts_with("  q, Esc, Ctrl+c ") && line.ends_with("Quit"))
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.starts_with("  P ") && line.ends_with("Toggle print preview"))
+        );
+
+        let columns: Vec<usize> = lines
+            .iter()
+            .filter(|line| line.starts_with("  "))
+            .map(|line| display_width(&line[..line.rfind("  ").unwrap()]))
+            .collect();
+        assert!(columns.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+
     fn create_test_slides() -> Vec<Slide> {
         vec![
             Slide::with_blocks(vec![Block::Heading {
@@ -399,7 +1164,7 @@ mod tests {
     #[test]
     fn viewer_creation() {
         let slides = create_test_slides();
-        let viewer = SlideViewer::new(slides, ThemeColors::default());
+        let viewer = SlideViewer::new(slides, Theme::test_default());
         assert_eq!(viewer.total_slides(), 3);
         assert_eq!(viewer.current_index(), 0);
     }
@@ -407,7 +1172,7 @@ mod tests {
     #[test]
     fn viewer_navigation_next() {
         let slides = create_test_slides();
-        let mut viewer = SlideViewer::new(slides, ThemeColors::default());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
 
         viewer.next();
         assert_eq!(viewer.current_index(), 1);
@@ -419,10 +1184,77 @@ mod tests {
         assert_eq!(viewer.current_index(), 2);
     }
 
+    #[test]
+    fn viewer_end_screen_after_last_slide() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.set_end_slide(true);
+
+        viewer.jump_to(3);
+        assert!(!viewer.is_at_end());
+
+        viewer.next();
+        assert!(viewer.is_at_end());
+        assert_eq!(viewer.current_index(), 2);
+
+        viewer.next();
+        assert!(viewer.is_at_end());
+
+        viewer.previous();
+        assert!(!viewer.is_at_end());
+        assert_eq!(viewer.current_index(), 2);
+
+        viewer.next();
+        viewer.jump_to(1);
+        assert!(!viewer.is_at_end());
+        assert_eq!(viewer.current_index(), 0);
+    }
+
+    #[test]
+    fn viewer_renders_end_screen() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.set_end_slide(true);
+        viewer.jump_to(3);
+        viewer.next();
+
+        let backend = ratatui::backend::TestBackend::new(50, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(rendered.contains("press Q to quit"));
+    }
+
+    #[test]
+    fn viewer_loop_wraps_at_both_ends() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.set_loop(true);
+
+        viewer.previous();
+        assert_eq!(viewer.current_index(), 2);
+
+        viewer.next();
+        assert_eq!(viewer.current_index(), 0);
+
+        viewer.set_loop(false);
+        viewer.previous();
+        assert_eq!(viewer.current_index(), 0);
+        viewer.jump_to(3);
+        viewer.next();
+        assert_eq!(viewer.current_index(), 2);
+    }
+
     #[test]
     fn viewer_navigation_previous() {
         let slides = create_test_slides();
-        let mut viewer = SlideViewer::new(slides, ThemeColors::default());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
 
         viewer.jump_to(3);
         assert_eq!(viewer.current_index(), 2);
@@ -440,7 +1272,7 @@ mod tests {
     #[test]
     fn viewer_jump_to() {
         let slides = create_test_slides();
-        let mut viewer = SlideViewer::new(slides, ThemeColors::default());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
 
         viewer.jump_to(3);
         assert_eq!(viewer.current_index(), 2);
@@ -458,7 +1290,7 @@ mod tests {
     #[test]
     fn viewer_toggle_notes() {
         let slides = create_test_slides();
-        let mut viewer = SlideViewer::new(slides, ThemeColors::default());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
 
         assert!(!viewer.is_showing_notes());
 
@@ -472,7 +1304,7 @@ mod tests {
     #[test]
     fn viewer_current_slide() {
         let slides = create_test_slides();
-        let mut viewer = SlideViewer::new(slides, ThemeColors::default());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
 
         assert!(viewer.current_slide().is_some());
 
@@ -483,7 +1315,7 @@ mod tests {
 
     #[test]
     fn viewer_empty_slides() {
-        let viewer = SlideViewer::new(Vec::new(), ThemeColors::default());
+        let viewer = SlideViewer::new(Vec::new(), Theme::test_default());
         assert_eq!(viewer.total_slides(), 0);
         assert!(viewer.current_slide().is_none());
     }
@@ -494,7 +1326,7 @@ mod tests {
         let start_time = Instant::now();
         let viewer = SlideViewer::with_context(
             slides,
-            ThemeColors::default(),
+            Theme::test_default(),
             Some("presentation.md".to_string()),
             "dark".to_string(),
             Some(start_time),
@@ -508,8 +1340,7 @@ mod tests {
     #[test]
     fn viewer_with_context_none_values() {
         let slides = create_test_slides();
-        let viewer =
-            SlideViewer::with_context(slides, ThemeColors::default(), None, "oxocarbon-dark".to_string(), None);
+        let viewer = SlideViewer::with_context(slides, Theme::test_default(), None, "oxocarbon-dark".to_string(), None);
 
         assert_eq!(viewer.filename, None);
         assert_eq!(viewer.theme_name, "oxocarbon-dark");
@@ -519,24 +1350,452 @@ mod tests {
     #[test]
     fn viewer_default_constructor() {
         let slides = create_test_slides();
-        let viewer = SlideViewer::new(slides, ThemeColors::default());
+        let viewer = SlideViewer::new(slides, Theme::test_default());
 
         assert_eq!(viewer.filename, None);
         assert_eq!(viewer.theme_name, "oxocarbon-dark");
         assert_eq!(viewer.start_time, None);
     }
 
+    #[test]
+    fn presenter_view_lists_current_next_and_notes() {
+        let mut slides = create_test_slides();
+        slides[0].notes = Some("Welcome everyone\n".to_string());
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
+
+        assert_eq!(
+            viewer.presenter_view(),
+            "Slide 1/3: Slide 1\nNext: Slide 2\n\nNotes:\nWelcome everyone\n"
+        );
+
+        viewer.jump_to(3);
+        assert_eq!(
+            viewer.presenter_view(),
+            "Slide 3/3: Slide 3\nNext: (end of deck)\n\nNotes:\n(no notes)\n"
+        );
+    }
+
+    #[test]
+    fn presenter_view_includes_elapsed_time_when_timed() {
+        let viewer = SlideViewer::with_context(
+            create_test_slides(),
+            Theme::test_default(),
+            None,
+            "nord".to_string(),
+            Some(Instant::now()),
+        );
+
+        assert!(viewer.presenter_view().contains("\nElapsed: 00:00:00\n"));
+    }
+
+    #[test]
+    fn notes_text_renders_markdown_lists() {
+        let notes = "- open the *demo*\n- take questions";
+        let theme = Theme::test_default();
+
+        let formatted = notes_text(notes, &theme, true);
+        let lines: Vec<String> = formatted.lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(lines[0], "• open the demo");
+        assert_eq!(lines[1], "• take questions");
+        assert!(!lines.iter().any(|line| line.starts_with("- ")));
+
+        let raw = notes_text(notes, &theme, false);
+        assert_eq!(raw.lines[0].to_string(), "- open the *demo*");
+    }
+
     #[test]
     fn viewer_has_notes() {
         let slides_without_notes = create_test_slides();
-        let viewer_no_notes = SlideViewer::new(slides_without_notes, ThemeColors::default());
+        let viewer_no_notes = SlideViewer::new(slides_without_notes, Theme::test_default());
         assert!(!viewer_no_notes.has_notes());
 
         let slides_with_notes = vec![Slide {
             blocks: vec![Block::Heading { level: 1, spans: vec![TextSpan::plain("Slide with notes")] }],
             notes: Some("These are speaker notes".to_string()),
+            target_duration: None,
+            bg_image: None,
+            footnotes: vec![],
+            transition: None,
+            classes: vec![],
         }];
-        let viewer_with_notes = SlideViewer::new(slides_with_notes, ThemeColors::default());
+        let viewer_with_notes = SlideViewer::new(slides_with_notes, Theme::test_default());
         assert!(viewer_with_notes.has_notes());
     }
+
+    #[test]
+    fn viewer_preview_contains_heading() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        let text = viewer.preview_text(40);
+        let content: String = text
+            .lines
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.as_ref()))
+            .collect();
+        assert!(content.contains("Slide 1"));
+        assert!(!content.contains('\x1b'));
+
+        viewer.toggle_preview();
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| viewer.render_preview(frame, frame.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Print Preview"));
+        assert!(rendered.contains("Slide 1"));
+    }
+
+    #[test]
+    fn viewer_status_bar_shows_authors() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.set_author("Ada Lovelace, Grace Hopper");
+
+        let backend = ratatui::backend::TestBackend::new(100, 1);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| viewer.render_status_bar(frame, frame.area()))
+            .unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Ada Lovelace, Grace Hopper | 1/3"));
+    }
+
+    #[test]
+    fn viewer_status_bar_pads_cjk_to_display_width() {
+        let viewer = SlideViewer::with_context(
+            create_test_slides(),
+            Theme::test_default(),
+            Some("発表資料.md".to_string()),
+            "テーマ".to_string(),
+            None,
+        );
+
+        let line = viewer.status_bar_line(80);
+        assert!(line.spans[0].content.contains("発表資料.md"));
+        assert_eq!(line.width(), 80);
+    }
+
+    #[test]
+    fn viewer_status_bar_shows_source_status() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        assert!(!viewer.status_bar_line(100).spans[0].content.contains("Edited"));
+
+        viewer.set_source_status(Some("Edited 2025-01-31 09:30 UTC".to_string()));
+        assert!(
+            viewer.status_bar_line(100).spans[0]
+                .content
+                .contains(" | Edited 2025-01-31 09:30 UTC")
+        );
+    }
+
+    #[test]
+    fn viewer_steps_through_pages_before_slides() {
+        let mut slides = create_test_slides();
+        slides[0].blocks.push(Block::PageBreak);
+        slides[0]
+            .blocks
+            .push(Block::Paragraph { spans: vec![TextSpan::plain("Second page")] });
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
+        assert_eq!(viewer.page_count(), 2);
+
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Slide 1"));
+        assert!(!rendered.contains("Second page"));
+
+        viewer.next();
+        assert_eq!((viewer.current_index(), viewer.current_page()), (0, 1));
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Second page"));
+        assert!(!rendered.contains("Slide 1 "), "first page heading should be hidden");
+
+        viewer.next();
+        assert_eq!((viewer.current_index(), viewer.current_page()), (1, 0));
+        viewer.previous();
+        assert_eq!((viewer.current_index(), viewer.current_page()), (0, 0));
+    }
+
+    #[test]
+    fn viewer_slide_classes_center_content_and_switch_theme() {
+        let paragraph = |text: &str| Block::Paragraph { spans: vec![TextSpan::plain(text)] };
+        let mut styled = Slide::with_blocks(vec![paragraph("Hi")]);
+        styled.classes = vec!["center".to_string(), "dark".to_string()];
+        let slides = vec![Slide::with_blocks(vec![paragraph("Hi")]), styled];
+        let mut viewer = SlideViewer::new(slides, ThemeRegistry::get("oxocarbon-light"));
+
+        let backend = ratatui::backend::TestBackend::new(40, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let find_text = |terminal: &ratatui::Terminal<ratatui::backend::TestBackend>| {
+            let buffer = terminal.backend().buffer();
+            let position = buffer.content().iter().position(|cell| cell.symbol() == "H").unwrap();
+            let width = buffer.area.width as usize;
+            (position % width, position / width, buffer.content()[position].fg)
+        };
+        let rgb = |color: lantern_core::theme::Color| Color::Rgb(color.r, color.g, color.b);
+
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let (plain_x, plain_y, plain_fg) = find_text(&terminal);
+        assert_eq!((plain_x, plain_y), (5, 3));
+        assert_eq!(plain_fg, rgb(ThemeRegistry::get("oxocarbon-light").body));
+
+        viewer.next();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let (x, y, fg) = find_text(&terminal);
+        assert_eq!((x, y), (19, 5));
+        assert_eq!(fg, rgb(ThemeRegistry::get("oxocarbon-dark").body));
+    }
+
+    #[test]
+    fn viewer_toggle_folds_expands_collapsed_admonitions() {
+        use lantern_core::slide::{Admonition, AdmonitionType};
+
+        let admonition = Admonition {
+            admonition_type: AdmonitionType::Tip,
+            title: None,
+            blocks: vec![Block::Paragraph { spans: vec![TextSpan::plain("Folded away")] }],
+            custom: None,
+            collapsed: true,
+        };
+        let mut slides = create_test_slides();
+        slides[0].blocks.push(Block::Admonition(admonition));
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
+
+        let backend = ratatui::backend::TestBackend::new(80, 16);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let render = |viewer: &mut SlideViewer, terminal: &mut ratatui::Terminal<ratatui::backend::TestBackend>| {
+            terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        assert!(!render(&mut viewer, &mut terminal).contains("Folded away"));
+
+        viewer.toggle_folds();
+        assert!(viewer.folds_open());
+        assert!(render(&mut viewer, &mut terminal).contains("Folded away"));
+
+        viewer.next();
+        assert!(!viewer.folds_open());
+    }
+
+    #[test]
+    fn viewer_loads_background_image() {
+        let path = std::env::temp_dir().join(format!("lantern-bg-{}.png", std::process::id()));
+        image::RgbImage::new(4, 4).save(&path).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let mut slide = Slide::with_blocks(vec![Block::Heading {
+            level: 1,
+            spans: vec![TextSpan::plain("Section")],
+        }]);
+        slide.bg_image = Some(path.clone());
+        let mut viewer = SlideViewer::new(vec![slide], Theme::test_default());
+        assert!(!viewer.image_manager.has_image(&path));
+
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(viewer.image_manager.has_image(&path));
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Section"));
+    }
+
+    #[test]
+    fn viewer_overview_grid_draws_image_thumbnails() {
+        let dir = std::env::temp_dir().join(format!("lantern-overview-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        image::RgbImage::from_pixel(8, 8, image::Rgb([0, 200, 0]))
+            .save(dir.join("chart.png"))
+            .unwrap();
+
+        let heading = |text: &str| Block::Heading { level: 2, spans: vec![TextSpan::plain(text)] };
+        let image = Block::Image { path: "chart.png".to_string(), alt: String::new(), caption: None };
+        let slides = vec![
+            Slide::with_blocks(vec![image, heading("Chart")]),
+            Slide::with_blocks(vec![heading("Plain")]),
+        ];
+        let deck = dir.join("deck.md").to_string_lossy().to_string();
+        let mut viewer = SlideViewer::with_context(slides, Theme::test_default(), Some(deck), "test".to_string(), None);
+        viewer.toggle_overview();
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 8)).unwrap();
+        terminal
+            .draw(|frame| viewer.render_overview(frame, frame.area()))
+            .unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16, xs: std::ops::Range<u16>| xs.map(|x| buffer[(x, y)].symbol()).collect::<String>();
+        for y in 1..6 {
+            assert_eq!(buffer[(1, y)].symbol(), "▀");
+            assert_eq!(buffer[(1, y)].fg, Color::Rgb(0, 200, 0));
+        }
+        assert!(row(6, 0..30).contains("1. Chart"));
+        assert!(row(1, 30..60).contains("2. Plain"));
+        assert_eq!(buffer[(31, 2)].symbol(), " ");
+    }
+
+    #[test]
+    fn viewer_renders_slide_number_in_corner() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.set_show_slide_number(true);
+        viewer.next();
+
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let corner: String = (34..39).map(|x| buffer[(x, 9)].symbol()).collect();
+        assert_eq!(corner, " 2/3 ");
+        assert!(buffer[(35, 9)].modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn image_area_height_fills_remaining_space_up_to_cap() {
+        assert_eq!(image_area_height(30, 10, 30), 20);
+        assert_eq!(image_area_height(30, 10, 12), 12);
+        assert_eq!(image_area_height(30, 25, 12), 5);
+        assert_eq!(image_area_height(30, 40, 12), 0);
+    }
+
+    #[test]
+    fn overflow_indicator_only_when_content_exceeds_area() {
+        assert!(!shows_overflow_indicator(10, 20));
+        assert!(!shows_overflow_indicator(20, 20));
+        assert!(shows_overflow_indicator(21, 20));
+        assert!(!shows_overflow_indicator(5, 1));
+        assert!(!shows_overflow_indicator(5, 0));
+    }
+
+    #[test]
+    fn viewer_renders_overflow_indicator() {
+        let line = |n: usize| Block::Paragraph { spans: vec![TextSpan::plain(format!("Line {n}"))] };
+        let lines: Vec<_> = (1..=20).map(line).collect();
+        let mut viewer = SlideViewer::new(vec![Slide::with_blocks(lines)], Theme::test_default());
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 12)).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("▼ more"));
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 60)).unwrap();
+        terminal.draw(|frame| viewer.render(frame, frame.area())).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(!rendered.contains("▼ more"));
+    }
+
+    #[test]
+    fn format_slide_timing_under_and_over() {
+        let target = Duration::from_secs(90);
+
+        let (text, over) = format_slide_timing(Duration::from_secs(45), target);
+        assert_eq!(text, " | 00:45/01:30");
+        assert!(!over);
+
+        let (text, over) = format_slide_timing(Duration::from_secs(125), target);
+        assert_eq!(text, " | 02:05/01:30");
+        assert!(over);
+    }
+
+    #[test]
+    fn next_code_focus_wraps() {
+        assert_eq!(next_code_focus(None, 0), None);
+        assert_eq!(next_code_focus(Some(1), 0), None);
+        assert_eq!(next_code_focus(None, 3), Some(0));
+        assert_eq!(next_code_focus(Some(0), 3), Some(1));
+        assert_eq!(next_code_focus(Some(2), 3), Some(0));
+        assert_eq!(next_code_focus(Some(0), 1), Some(0));
+    }
+
+    #[test]
+    fn viewer_code_focus_resets_on_navigation() {
+        use lantern_core::slide::{Block, CodeBlock};
+
+        let slides = vec![
+            Slide::with_blocks(vec![Block::Code(CodeBlock::new("a")), Block::Code(CodeBlock::new("b"))]),
+            Slide::with_blocks(vec![]),
+        ];
+        let mut viewer = SlideViewer::new(slides, Theme::test_default());
+
+        viewer.cycle_code_focus();
+        assert_eq!(viewer.focused_code(), Some(0));
+        viewer.cycle_code_focus();
+        viewer.cycle_code_focus();
+        assert_eq!(viewer.focused_code(), Some(0));
+
+        viewer.next();
+        assert_eq!(viewer.focused_code(), None);
+        viewer.cycle_code_focus();
+        assert_eq!(viewer.focused_code(), None);
+    }
+
+    #[test]
+    fn clamp_h_offset_limits_to_overflow() {
+        assert_eq!(clamp_h_offset(0, 120, 80), 0);
+        assert_eq!(clamp_h_offset(30, 120, 80), 30);
+        assert_eq!(clamp_h_offset(100, 120, 80), 40);
+        assert_eq!(clamp_h_offset(10, 60, 80), 0);
+    }
+
+    #[test]
+    fn viewer_horizontal_offset_resets_on_navigation() {
+        let mut viewer = SlideViewer::new(create_test_slides(), Theme::test_default());
+        viewer.scroll_horizontal(8);
+        assert_eq!(viewer.horizontal_offset(), 8);
+        viewer.scroll_horizontal(-12);
+        assert_eq!(viewer.horizontal_offset(), 0);
+
+        viewer.scroll_horizontal(8);
+        viewer.next();
+        assert_eq!(viewer.horizontal_offset(), 0);
+    }
 }